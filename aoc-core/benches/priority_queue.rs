@@ -0,0 +1,84 @@
+// Benchmark aoc::PriorityQueue against std::collections::BinaryHeap with Reverse,
+// on Dijkstra over a grid with pseudo-random weights
+
+use aoc::{Grid, Int, PriorityQueue};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+const SIZE: usize = 150;
+
+type Point = (usize, usize);
+
+fn weights() -> Grid<Int> {
+    (0..SIZE)
+        .map(|y| {
+            (0..SIZE)
+                .map(|x| ((x * 7919 + y * 104729) % 9 + 1) as Int)
+                .collect()
+        })
+        .collect()
+}
+
+fn neighbours((x, y): Point) -> impl Iterator<Item = Point> {
+    [(0, 1), (2, 1), (1, 0), (1, 2)]
+        .into_iter()
+        .map(move |(dx, dy)| ((x + dx).wrapping_sub(1), (y + dy).wrapping_sub(1)))
+        .filter(|&(x, y)| x < SIZE && y < SIZE)
+}
+
+fn dijkstra_priority_queue(weights: &Grid<Int>) -> Int {
+    let mut distances = vec![vec![Int::MAX; SIZE]; SIZE];
+    let mut queue: PriorityQueue<Point, Int> = PriorityQueue::new();
+    distances[0][0] = 0;
+    queue.push((0, 0), 0);
+    while let Some(((x, y), distance)) = queue.pop() {
+        for (nx, ny) in neighbours((x, y)) {
+            let next = distance + weights[ny][nx];
+            if next < distances[ny][nx] {
+                distances[ny][nx] = next;
+                queue.push((nx, ny), next);
+            }
+        }
+    }
+    distances[SIZE - 1][SIZE - 1]
+}
+
+fn dijkstra_binary_heap(weights: &Grid<Int>) -> Int {
+    let mut distances = vec![vec![Int::MAX; SIZE]; SIZE];
+    let mut heap = BinaryHeap::new();
+    distances[0][0] = 0;
+    heap.push(Reverse((0, (0, 0))));
+    while let Some(Reverse((distance, (x, y)))) = heap.pop() {
+        if distance > distances[y][x] {
+            continue;
+        }
+        for (nx, ny) in neighbours((x, y)) {
+            let next = distance + weights[ny][nx];
+            if next < distances[ny][nx] {
+                distances[ny][nx] = next;
+                heap.push(Reverse((next, (nx, ny))));
+            }
+        }
+    }
+    distances[SIZE - 1][SIZE - 1]
+}
+
+fn bench_dijkstra(c: &mut Criterion) {
+    let weights = weights();
+    assert_eq!(
+        dijkstra_priority_queue(&weights),
+        dijkstra_binary_heap(&weights)
+    );
+    let mut group = c.benchmark_group("dijkstra");
+    group.bench_function("PriorityQueue", |b| {
+        b.iter(|| dijkstra_priority_queue(black_box(&weights)))
+    });
+    group.bench_function("BinaryHeap<Reverse>", |b| {
+        b.iter(|| dijkstra_binary_heap(black_box(&weights)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_dijkstra);
+criterion_main!(benches);
@@ -0,0 +1,186 @@
+#![allow(dead_code)]
+// This module contains dynamic programming over the recurring shapes of puzzles, like packing items with weights into
+// a limited capacity, and comparing two sequences, so a day does not have to write out the DP table again
+
+use crate::Int;
+use std::cmp::{max, min};
+
+/// How many copies of an item can be packed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Supply {
+    Bounded(usize),
+    Unbounded,
+}
+
+/// An item that can be packed into a knapsack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item {
+    pub weight: usize,
+    pub value: Int,
+    pub supply: Supply,
+}
+
+/// The largest total value of items that can be packed with a total weight of at most `capacity`. `item` maps an
+/// element of `items` to its Item, use `Supply::Bounded(1)` for the 0/1 knapsack.
+///
+/// Bounded items are split into 0/1 items of 1, 2, 4, ... copies, so it runs in `O(capacity * sum(log(count)))`.
+/// Panics on an unbounded item with weight 0 and a positive value, of which any number of copies fits.
+pub fn knapsack<T>(items: &[T], capacity: usize, item: impl Fn(&T) -> Item) -> Int {
+    // best[w] is the largest value with a total weight of at most w
+    let mut best: Vec<Int> = vec![0; capacity + 1];
+    for Item {
+        weight,
+        value,
+        supply,
+    } in items.iter().map(item)
+    {
+        match supply {
+            Supply::Unbounded => {
+                assert!(
+                    weight > 0 || value <= 0,
+                    "Unbounded item without weight has an unbounded value: {}.",
+                    value
+                );
+                // Going up, best[w - weight] may already contain copies of this item
+                for w in weight..=capacity {
+                    best[w] = max(best[w], best[w - weight] + value);
+                }
+            }
+            Supply::Bounded(mut count) => {
+                let mut copies = 1;
+                while count > 0 {
+                    let copies_now = min(copies, count);
+                    count -= copies_now;
+                    copies *= 2;
+                    let (weight, value) = (weight * copies_now, value * copies_now as Int);
+                    // Going down, best[w - weight] does not contain these copies yet
+                    for w in (weight..=capacity).rev() {
+                        best[w] = max(best[w], best[w - weight] + value);
+                    }
+                }
+            }
+        }
+    }
+    best[capacity]
+}
+
+/// The longest sequence of elements that occur in both `a` and `b` in the same order, not necessarily adjacent. Of
+/// multiple longest sequences, the one that skips elements of `a` rather than `b` is returned.
+pub fn longest_common_subsequence<T: PartialEq + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+    // length[i][j] is the length of the longest common subsequence of a[i..] and b[j..]
+    let mut length = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            length[i][j] = match a[i] == b[j] {
+                true => length[i + 1][j + 1] + 1,
+                false => max(length[i + 1][j], length[i][j + 1]),
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut subsequence = Vec::with_capacity(length[0][0]);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            subsequence.push(a[i].clone());
+            (i, j) = (i + 1, j + 1);
+        } else if length[i + 1][j] >= length[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    subsequence
+}
+
+/// The Levenshtein distance between `a` and `b`: the fewest insertions, deletions and substitutions of single
+/// elements that turn `a` into `b`
+pub fn edit_distance<T: PartialEq>(a: &[T], b: &[T]) -> Int {
+    // distance[j] is the edit distance between the current prefix of a and b[..j], one row of the DP table at a time
+    let mut distance: Vec<Int> = (0..=b.len() as Int).collect();
+    for (i, x) in a.iter().enumerate() {
+        let mut diagonal = distance[0];
+        distance[0] = i as Int + 1;
+        for (j, y) in b.iter().enumerate() {
+            let substitution = diagonal + Int::from(x != y);
+            diagonal = distance[j + 1];
+            distance[j + 1] = min(substitution, min(distance[j], distance[j + 1]) + 1);
+        }
+    }
+    distance[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_knapsack() {
+        // (weight, value)
+        let items = [(1, 1), (3, 4), (4, 5), (5, 7)];
+        let with = |supply| {
+            move |&(weight, value): &(usize, Int)| Item {
+                weight,
+                value,
+                supply,
+            }
+        };
+        test!(9 as Int, knapsack(&items, 7, with(Supply::Bounded(1))));
+        test!(9 as Int, knapsack(&items, 7, with(Supply::Unbounded)));
+        test!(14 as Int, knapsack(&items, 10, with(Supply::Unbounded)));
+        test!(13 as Int, knapsack(&items, 10, with(Supply::Bounded(1))));
+        test!(14 as Int, knapsack(&items, 10, with(Supply::Bounded(2))));
+        test!(0 as Int, knapsack(&items, 0, with(Supply::Unbounded)));
+        // A single item of which 10 copies fit, but only 6 are available
+        let coin = Item {
+            weight: 3,
+            value: 2,
+            supply: Supply::Bounded(6),
+        };
+        test!(12 as Int, knapsack(&[coin], 30, |&item| item));
+        // Items without weight are always packed, if they are worth it
+        test!(
+            20 as Int,
+            knapsack(
+                &[(0, 5), (1, 1), (3, 4), (0, -1)],
+                10,
+                with(Supply::Bounded(2))
+            )
+        );
+        test!(
+            13 as Int,
+            knapsack(&[(0, 0), (1, 1), (3, 4)], 10, with(Supply::Unbounded))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "unbounded value")]
+    fn test_knapsack_without_weight() {
+        let free = Item {
+            weight: 0,
+            value: 1,
+            supply: Supply::Unbounded,
+        };
+        knapsack(&[free], 10, |&item| item);
+    }
+
+    #[test]
+    fn test_longest_common_subsequence() {
+        let lcs = longest_common_subsequence(b"ABCBDAB", b"BDCABA");
+        // BCBA and BCAB are as long, ties skip an element of `a` first
+        test!(b"BDAB".to_vec(), lcs);
+        test!(b"".to_vec(), longest_common_subsequence(b"ABC", b"XYZ"));
+        test!(
+            vec![2, 3],
+            longest_common_subsequence(&[1, 2, 3], &[2, 3, 4])
+        );
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        test!(3 as Int, edit_distance(b"kitten", b"sitting"));
+        test!(2 as Int, edit_distance(b"flaw", b"lawn"));
+        test!(3 as Int, edit_distance(b"", b"abc"));
+        test!(0 as Int, edit_distance(b"same", b"same"));
+    }
+}
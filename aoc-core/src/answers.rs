@@ -0,0 +1,141 @@
+#![allow(dead_code)]
+// This module stores the expected answers of solutions in answers.toml, keyed by the input they belong to,
+// so personal answers stay out of the source files, and a day can have answers for multiple inputs
+
+use crate::runner::Part;
+use crate::solution::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Default location of the expected answers
+pub const ANSWERS_PATH: &str = "answers.toml";
+
+/// Expected answer of a part of a Solution, for the input with hash `input`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub input: String,
+    pub answer: Answer,
+}
+
+/// Expected answers of all solutions, as stored in answers.toml
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip)]
+    path: PathBuf,
+    #[serde(default, rename = "answer")]
+    entries: Vec<Entry>,
+}
+
+/// Stable hash of a puzzle input, as 16 hexadecimal digits (64-bit FNV-1a)
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+impl Answers {
+    /// Load the Answers at `path`, a missing file results in no Answers
+    pub fn load(path: impl AsRef<Path>) -> AocResult<Answers> {
+        let path = path.as_ref().to_path_buf();
+        let mut answers: Answers = match fs::read_to_string(&path) {
+            Ok(toml) => {
+                toml::from_str(&toml).map_err(AocError::parse(path.display().to_string()))?
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Answers::default(),
+            Err(error) => return Err(AocError::io(&path)(error)),
+        };
+        answers.path = path;
+        Ok(answers)
+    }
+
+    /// Write the Answers back to the path they were loaded from, sorted by year, day and part
+    pub fn save(&mut self) -> AocResult<()> {
+        self.entries.sort_by(|a, b| {
+            (a.year, a.day, a.part, &a.input).cmp(&(b.year, b.day, b.part, &b.input))
+        });
+        let toml = toml::to_string(self).expect("Answers are always serializable.");
+        fs::write(&self.path, toml).map_err(AocError::io(&self.path))
+    }
+
+    /// The expected answer of `part` of AoC(`year`, `day`), for `input`
+    pub fn get(&self, year: Year, day: Day, part: Part, input: &str) -> Option<&Answer> {
+        let input = input_hash(input);
+        self.entries
+            .iter()
+            .find(|entry| {
+                (entry.year, entry.day, entry.part) == (year, day, part.number())
+                    && entry.input == input
+            })
+            .map(|entry| &entry.answer)
+    }
+
+    /// Set the expected answer of `part` of AoC(`year`, `day`) for `input`, e.g. after a successful submission
+    pub fn insert(&mut self, year: Year, day: Day, part: Part, input: &str, answer: Answer) {
+        let input = input_hash(input);
+        let part = part.number();
+        self.entries.retain(|entry| {
+            (entry.year, entry.day, entry.part, &entry.input) != (year, day, part, &input)
+        });
+        self.entries.push(Entry {
+            year,
+            day,
+            part,
+            input,
+            answer,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_input_hash() {
+        test!("cbf29ce484222325", input_hash(""));
+        test!("af63df4c8601f1a5", input_hash("b"));
+        test!(input_hash("1abc2") != input_hash("1abc3"));
+    }
+
+    #[test]
+    fn test_answers() {
+        let mut answers: Answers = toml::from_str(
+            r#"
+            [[answer]]
+            year = 2023
+            day = 1
+            part = 1
+            input = "cbf29ce484222325"
+            answer = 142
+
+            [[answer]]
+            year = 2023
+            day = 1
+            part = 2
+            input = "cbf29ce484222325"
+            answer = "EFEKZRJB"
+            "#,
+        )
+        .unwrap();
+        test!(Some(&Answer::Int(142)), answers.get(2023, 1, Part::One, ""));
+        test!(
+            Some(&Answer::from("EFEKZRJB")),
+            answers.get(2023, 1, Part::Two, "")
+        );
+        test!(answers.get(2023, 1, Part::One, "other input").is_none());
+        answers.insert(2023, 1, Part::One, "", 281.into());
+        answers.insert(2023, 1, Part::One, "other input", 77.into());
+        test!(Some(&Answer::Int(281)), answers.get(2023, 1, Part::One, ""));
+        test!(
+            Some(&Answer::Int(77)),
+            answers.get(2023, 1, Part::One, "other input")
+        );
+        test!(3, answers.entries.len());
+    }
+}
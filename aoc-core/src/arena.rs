@@ -0,0 +1,217 @@
+// This module contains an Arena with generational indices, so graphs and trees can refer to their
+// nodes by a Copy handle, instead of sharing them with Rc<RefCell<T>>
+
+use std::fmt::Debug;
+use std::ops::{Index, IndexMut};
+
+/// Stable handle to a value in an Arena.
+///
+/// A NodeId of a removed value is never valid again, even when its slot is reused.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId {
+    index: u32,
+    generation: u32,
+}
+
+impl NodeId {
+    /// Index of the slot of this NodeId, useful to index a Vec or BitSet alongside the Arena
+    pub fn index(&self) -> usize {
+        self.index as usize
+    }
+}
+
+impl Debug for NodeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "NodeId({}v{})", self.index, self.generation)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Slot<T> {
+    generation: u32,
+    value: Option<T>,
+}
+
+/// Slab of values addressed by NodeId, removed slots are reused
+#[derive(Clone, Debug)]
+pub struct Arena<T> {
+    slots: Vec<Slot<T>>,
+    free: Vec<u32>,
+    /// NodeIds in insertion order, including removed ones that have not been cleaned up yet
+    order: Vec<NodeId>,
+    len: usize,
+}
+
+impl<T> Arena<T> {
+    pub fn new() -> Arena<T> {
+        Arena {
+            slots: Vec::new(),
+            free: Vec::new(),
+            order: Vec::new(),
+            len: 0,
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> Arena<T> {
+        Arena {
+            slots: Vec::with_capacity(capacity),
+            free: Vec::new(),
+            order: Vec::with_capacity(capacity),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Move `value` into the Arena, and return its NodeId
+    pub fn insert(&mut self, value: T) -> NodeId {
+        let id = match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                slot.value = Some(value);
+                NodeId {
+                    index,
+                    generation: slot.generation,
+                }
+            }
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    value: Some(value),
+                });
+                NodeId {
+                    index: (self.slots.len() - 1) as u32,
+                    generation: 0,
+                }
+            }
+        };
+        self.order.push(id);
+        self.len += 1;
+        id
+    }
+
+    /// Remove and return the value of `id`, which invalidates `id`
+    pub fn remove(&mut self, id: NodeId) -> Option<T> {
+        let slot = self.slots.get_mut(id.index())?;
+        if slot.generation != id.generation {
+            return None;
+        }
+        let value = slot.value.take()?;
+        slot.generation += 1;
+        self.free.push(id.index);
+        self.len -= 1;
+        // Clean up the insertion order once it mostly contains removed NodeIds
+        if self.order.len() > 2 * self.len + 16 {
+            let slots = &self.slots;
+            self.order
+                .retain(|id| slots[id.index()].generation == id.generation);
+        }
+        Some(value)
+    }
+
+    pub fn contains(&self, id: NodeId) -> bool {
+        self.get(id).is_some()
+    }
+
+    pub fn get(&self, id: NodeId) -> Option<&T> {
+        self.slots
+            .get(id.index())
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.value.as_ref())
+    }
+
+    pub fn get_mut(&mut self, id: NodeId) -> Option<&mut T> {
+        self.slots
+            .get_mut(id.index())
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.value.as_mut())
+    }
+
+    /// Iterate over the NodeIds of all values, in insertion order
+    pub fn ids(&self) -> impl Iterator<Item = NodeId> + '_ {
+        self.order.iter().copied().filter(|&id| self.contains(id))
+    }
+
+    /// Iterate over all values and their NodeIds, in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (NodeId, &T)> + '_ {
+        self.order
+            .iter()
+            .filter_map(|&id| self.get(id).map(|value| (id, value)))
+    }
+
+    /// Iterate over all values, in insertion order
+    pub fn values(&self) -> impl Iterator<Item = &T> + '_ {
+        self.iter().map(|(_, value)| value)
+    }
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Arena::new()
+    }
+}
+
+impl<T> Index<NodeId> for Arena<T> {
+    type Output = T;
+
+    fn index(&self, id: NodeId) -> &T {
+        self.get(id)
+            .unwrap_or_else(|| panic!("Invalid {:?}, the value has been removed.", id))
+    }
+}
+
+impl<T> IndexMut<NodeId> for Arena<T> {
+    fn index_mut(&mut self, id: NodeId) -> &mut T {
+        self.get_mut(id)
+            .unwrap_or_else(|| panic!("Invalid {:?}, the value has been removed.", id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_arena() {
+        let mut arena: Arena<&str> = Arena::new();
+        let a = arena.insert("a");
+        let b = arena.insert("b");
+        let c = arena.insert("c");
+        test!(3, arena.len());
+        test!("b", arena[b]);
+        test!(Some("b"), arena.remove(b));
+        test!(arena.get(b).is_none());
+        test!(arena.remove(b).is_none());
+        // The slot of b is reused, but b stays invalid
+        let d = arena.insert("d");
+        test!(b.index(), d.index());
+        test!(!arena.contains(b));
+        arena[d] = "e";
+        test!(
+            vec![(a, "a"), (c, "c"), (d, "e")],
+            arena
+                .iter()
+                .map(|(id, &value)| (id, value))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_insertion_order_after_cleanup() {
+        let mut arena: Arena<usize> = Arena::new();
+        let ids: Vec<NodeId> = (0..100).map(|i| arena.insert(i)).collect();
+        for &id in ids.iter().filter(|id| id.index() % 3 != 0) {
+            arena.remove(id);
+        }
+        arena.insert(100);
+        let expected: Vec<usize> = (0..100).filter(|i| i % 3 == 0).chain([100]).collect();
+        test!(expected, arena.values().copied().collect::<Vec<_>>());
+    }
+}
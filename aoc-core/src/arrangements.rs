@@ -0,0 +1,84 @@
+#![allow(dead_code)]
+// This module counts the ways to fill in the wildcards of a pattern, such that its runs of filled cells have given
+// lengths, like the condition records of 2023-12 or the rows of a nonogram, so a day only maps its symbols to Cells
+
+use crate::Int;
+
+/// What a position of a pattern is known to be
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Filled,
+    Empty,
+    /// Wildcard, either Filled or Empty
+    Unknown,
+}
+
+/// Count the ways to make every Unknown cell of `pattern` Filled or Empty, such that the runs of consecutive Filled
+/// cells have the lengths of `runs`, in order. `cell` maps a symbol of the pattern to its Cell.
+///
+/// Dynamic programming over the suffixes of the pattern and of `runs`, in `O(pattern.len() * runs.len())`.
+pub fn count_arrangements<T>(pattern: &[T], runs: &[usize], cell: impl Fn(&T) -> Cell) -> Int {
+    let cells: Vec<Cell> = pattern.iter().map(cell).collect();
+    let (n, m) = (cells.len(), runs.len());
+    // empty_before[i] is the number of Empty cells in cells[..i], to check whether a run fits in constant time
+    let mut empty_before = vec![0; n + 1];
+    for (i, &cell) in cells.iter().enumerate() {
+        empty_before[i + 1] = empty_before[i] + usize::from(cell == Cell::Empty);
+    }
+    // ways[i][j] is the number of arrangements of cells[i..] with the runs[j..]
+    let mut ways: Vec<Vec<Int>> = vec![vec![0; m + 1]; n + 2];
+    ways[n][m] = 1;
+    ways[n + 1][m] = 1;
+    for i in (0..n).rev() {
+        for j in 0..=m {
+            let mut count = 0;
+            if cells[i] != Cell::Filled {
+                count += ways[i + 1][j];
+            }
+            if cells[i] != Cell::Empty && j < m {
+                let end = i + runs[j];
+                let fits = end <= n
+                    && empty_before[end] == empty_before[i]
+                    && cells.get(end) != Some(&Cell::Filled);
+                if fits {
+                    // The run is followed by an Empty cell, unless it ends the pattern
+                    count += ways[end + 1][j + 1];
+                }
+            }
+            ways[i][j] = count;
+        }
+    }
+    ways[0][0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    fn spring(c: &u8) -> Cell {
+        match c {
+            b'#' => Cell::Filled,
+            b'.' => Cell::Empty,
+            _ => Cell::Unknown,
+        }
+    }
+
+    #[test]
+    fn test_count_arrangements() {
+        test!(1, count_arrangements(b"???.###", &[1, 1, 3], spring));
+        test!(4, count_arrangements(b".??..??...?##.", &[1, 1, 3], spring));
+        test!(10, count_arrangements(b"?###????????", &[3, 2, 1], spring));
+        test!(1, count_arrangements(b"....", &[], spring));
+        test!(0, count_arrangements(b"..#.", &[], spring));
+        test!(0, count_arrangements(b"???", &[2, 1, 1], spring));
+        // A nonogram row of 5 cells with runs 2 and 1, of which the second cell is known to be filled
+        let row = [None, Some(true), None, None, None];
+        let nonogram = |cell: &Option<bool>| match cell {
+            Some(true) => Cell::Filled,
+            Some(false) => Cell::Empty,
+            None => Cell::Unknown,
+        };
+        test!(3, count_arrangements(&row, &[2, 1], nonogram));
+    }
+}
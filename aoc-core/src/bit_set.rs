@@ -0,0 +1,504 @@
+// This module contains sets of small integers stored as bits, because visited-sets over grid points
+// are a lot smaller and faster as a bit per point than as a HashSet of points
+
+use crate::Grid;
+use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+fn word_and_mask(value: usize) -> (usize, u64) {
+    (value / WORD_BITS, 1 << (value % WORD_BITS))
+}
+
+/// Iterate over the indices of the set bits of `words`, in ascending order
+fn iter_words(words: &[u64]) -> impl Iterator<Item = usize> + '_ {
+    words.iter().enumerate().flat_map(|(i, &word)| {
+        let mut word = word;
+        std::iter::from_fn(move || {
+            if word == 0 {
+                return None;
+            }
+            let bit = word.trailing_zeros() as usize;
+            word &= word - 1;
+            Some(i * WORD_BITS + bit)
+        })
+    })
+}
+
+/// Growable set of `usize` values, which uses a bit for every value up to the largest one
+#[derive(Clone, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> BitSet {
+        BitSet { words: Vec::new() }
+    }
+
+    /// BitSet that can hold the values `0..capacity` without growing
+    pub fn with_capacity(capacity: usize) -> BitSet {
+        BitSet {
+            words: vec![0; capacity.div_ceil(WORD_BITS)],
+        }
+    }
+
+    /// Insert `value`, returns true iff it was not in the set yet
+    pub fn insert(&mut self, value: usize) -> bool {
+        let (word, mask) = word_and_mask(value);
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        let is_new = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        is_new
+    }
+
+    /// Remove `value`, returns true iff it was in the set
+    pub fn remove(&mut self, value: usize) -> bool {
+        let (word, mask) = word_and_mask(value);
+        match self.words.get_mut(word) {
+            Some(bits) if *bits & mask != 0 => {
+                *bits &= !mask;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        let (word, mask) = word_and_mask(value);
+        self.words.get(word).is_some_and(|bits| bits & mask != 0)
+    }
+
+    /// Number of values in the set
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Remove all values, but keep the capacity
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Add all values of `other` to this set
+    pub fn union_with(&mut self, other: &BitSet) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (word, &other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    /// Only keep the values that are also in `other`
+    pub fn intersect_with(&mut self, other: &BitSet) {
+        for (i, word) in self.words.iter_mut().enumerate() {
+            *word &= other.words.get(i).copied().unwrap_or(0);
+        }
+    }
+
+    /// Remove all values of `other` from this set
+    pub fn difference_with(&mut self, other: &BitSet) {
+        for (word, &other) in self.words.iter_mut().zip(&other.words) {
+            *word &= !other;
+        }
+    }
+
+    /// Number of values in both sets, without building the intersection
+    pub fn intersection_len(&self, other: &BitSet) -> usize {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| (a & b).count_ones() as usize)
+            .sum()
+    }
+
+    /// Iterate over the values in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        iter_words(&self.words)
+    }
+
+    /// The words up to the last one that has a value, which sets with the same values have in common whatever their
+    /// capacity
+    fn used_words(&self) -> &[u64] {
+        let len = self
+            .words
+            .iter()
+            .rposition(|&word| word != 0)
+            .map_or(0, |i| i + 1);
+        &self.words[..len]
+    }
+}
+
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.used_words() == other.used_words()
+    }
+}
+
+impl Eq for BitSet {}
+
+impl Hash for BitSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.used_words().hash(state);
+    }
+}
+
+impl Debug for BitSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = BitSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<usize> for BitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+/// Set of the values `0..64 * WORDS`, stored inline without allocating
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedBitSet<const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> FixedBitSet<WORDS> {
+    /// The values `0..CAPACITY` fit in this set
+    pub const CAPACITY: usize = WORDS * WORD_BITS;
+
+    pub const fn new() -> Self {
+        FixedBitSet { words: [0; WORDS] }
+    }
+
+    /// Insert `value`, returns true iff it was not in the set yet.
+    ///
+    /// Panics if `value` is not below `CAPACITY`.
+    pub fn insert(&mut self, value: usize) -> bool {
+        let (word, mask) = word_and_mask(value);
+        let is_new = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        is_new
+    }
+
+    /// Remove `value`, returns true iff it was in the set
+    pub fn remove(&mut self, value: usize) -> bool {
+        let (word, mask) = word_and_mask(value);
+        match self.words.get_mut(word) {
+            Some(bits) if *bits & mask != 0 => {
+                *bits &= !mask;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        let (word, mask) = word_and_mask(value);
+        self.words.get(word).is_some_and(|bits| bits & mask != 0)
+    }
+
+    /// Number of values in the set
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    pub fn clear(&mut self) {
+        self.words = [0; WORDS];
+    }
+
+    /// Set with the values of both sets
+    pub fn union(&self, other: &Self) -> Self {
+        let mut words = self.words;
+        words.iter_mut().zip(other.words).for_each(|(a, b)| *a |= b);
+        FixedBitSet { words }
+    }
+
+    /// Set with the values that are in both sets
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut words = self.words;
+        words.iter_mut().zip(other.words).for_each(|(a, b)| *a &= b);
+        FixedBitSet { words }
+    }
+
+    /// Set with the values of this set that are not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut words = self.words;
+        words
+            .iter_mut()
+            .zip(other.words)
+            .for_each(|(a, b)| *a &= !b);
+        FixedBitSet { words }
+    }
+
+    /// Iterate over the values in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        iter_words(&self.words)
+    }
+}
+
+impl<const WORDS: usize> Default for FixedBitSet<WORDS> {
+    fn default() -> Self {
+        FixedBitSet::new()
+    }
+}
+
+impl<const WORDS: usize> Debug for FixedBitSet<WORDS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<const WORDS: usize> FromIterator<usize> for FixedBitSet<WORDS> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = FixedBitSet::new();
+        for value in iter {
+            set.insert(value);
+        }
+        set
+    }
+}
+
+/// Boolean mask over a `width` by `height` grid, stored as a BitSet of `y * width + x`
+#[derive(Clone)]
+pub struct GridMask {
+    pub width: usize,
+    pub height: usize,
+    bits: BitSet,
+}
+
+impl GridMask {
+    pub fn new(width: usize, height: usize) -> GridMask {
+        GridMask {
+            width,
+            height,
+            bits: BitSet::with_capacity(width * height),
+        }
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "Point ({}, {}) is outside of the {}x{} GridMask.",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        y * self.width + x
+    }
+
+    /// Set the point at (`x`, `y`), returns true iff it was not set yet
+    pub fn insert(&mut self, x: usize, y: usize) -> bool {
+        let index = self.index(x, y);
+        self.bits.insert(index)
+    }
+
+    pub fn remove(&mut self, x: usize, y: usize) -> bool {
+        let index = self.index(x, y);
+        self.bits.remove(index)
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.bits.contains(y * self.width + x)
+    }
+
+    /// Number of set points
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear();
+    }
+
+    /// The underlying BitSet, to combine masks of the same size
+    pub fn bits(&self) -> &BitSet {
+        &self.bits
+    }
+
+    pub fn bits_mut(&mut self) -> &mut BitSet {
+        &mut self.bits
+    }
+
+    /// Iterate over the set points as (x, y), row by row
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.bits
+            .iter()
+            .map(|index| (index % self.width, index / self.width))
+    }
+
+    pub fn to_grid(&self) -> Grid<bool> {
+        (0..self.height)
+            .map(|y| (0..self.width).map(|x| self.contains(x, y)).collect())
+            .collect()
+    }
+}
+
+impl PartialEq for GridMask {
+    fn eq(&self, other: &Self) -> bool {
+        (self.width, self.height) == (other.width, other.height) && self.bits == other.bits
+    }
+}
+
+impl Eq for GridMask {}
+
+impl Hash for GridMask {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.width, self.height).hash(state);
+        self.bits.hash(state);
+    }
+}
+
+impl From<&Grid<bool>> for GridMask {
+    fn from(grid: &Grid<bool>) -> Self {
+        let height = grid.len();
+        let width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+        let mut mask = GridMask::new(width, height);
+        for (y, row) in grid.iter().enumerate() {
+            for (x, &is_set) in row.iter().enumerate() {
+                if is_set {
+                    mask.insert(x, y);
+                }
+            }
+        }
+        mask
+    }
+}
+
+impl Debug for GridMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+/// One line per row, with `#` for the points that are set
+impl Display for GridMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            let row: String = (0..self.width)
+                .map(|x| if self.contains(x, y) { '#' } else { '.' })
+                .collect();
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_bit_set() {
+        let mut set = BitSet::new();
+        test!(set.insert(3));
+        test!(set.insert(200));
+        test!(!set.insert(3));
+        test!(2, set.len());
+        test!(set.contains(200));
+        test!(!set.contains(64));
+        test!(!set.contains(100000));
+        test!(set.remove(3));
+        test!(!set.remove(3));
+        test!(vec![200], set.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_bit_set_operations() {
+        let a: BitSet = [1, 2, 3, 64, 130].into_iter().collect();
+        let b: BitSet = [2, 3, 4, 130].into_iter().collect();
+        let mut union = a.clone();
+        union.union_with(&b);
+        test!(vec![1, 2, 3, 4, 64, 130], union.iter().collect::<Vec<_>>());
+        let mut intersection = a.clone();
+        intersection.intersect_with(&b);
+        test!(vec![2, 3, 130], intersection.iter().collect::<Vec<_>>());
+        test!(3, a.intersection_len(&b));
+        let mut difference = a.clone();
+        difference.difference_with(&b);
+        test!(vec![1, 64], difference.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_bit_set_eq() {
+        let hash = |set: &BitSet| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            set.hash(&mut hasher);
+            hasher.finish()
+        };
+        let mut a = BitSet::with_capacity(1000);
+        let mut b = BitSet::new();
+        test!(&a, &b);
+        a.insert(3);
+        b.insert(3);
+        b.insert(500);
+        test!(a != b);
+        b.remove(500);
+        test!(&a, &b);
+        test!(hash(&a), hash(&b));
+        let mut mask = GridMask::new(10, 10);
+        mask.insert(1, 1);
+        mask.remove(1, 1);
+        test!(&mask, &GridMask::new(10, 10));
+        test!(mask != GridMask::new(10, 9));
+    }
+
+    #[test]
+    fn test_fixed_bit_set() {
+        let a: FixedBitSet<2> = [0, 63, 64, 127].into_iter().collect();
+        let b: FixedBitSet<2> = [63, 100].into_iter().collect();
+        test!(128, FixedBitSet::<2>::CAPACITY);
+        test!(5, a.union(&b).len());
+        test!(vec![63], a.intersection(&b).iter().collect::<Vec<_>>());
+        test!(
+            vec![0, 64, 127],
+            a.difference(&b).iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_grid_mask() {
+        let grid: Grid<bool> = vec![vec![true, false, false], vec![false, true, true]];
+        let mask = GridMask::from(&grid);
+        test!(3, mask.len());
+        test!(mask.contains(0, 0));
+        test!(!mask.contains(1, 0));
+        test!(!mask.contains(3, 0));
+        test!(
+            vec![(0, 0), (1, 1), (2, 1)],
+            mask.iter().collect::<Vec<_>>()
+        );
+        test!(grid, mask.to_grid());
+    }
+}
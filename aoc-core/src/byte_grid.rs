@@ -0,0 +1,219 @@
+// This module contains a flat grid of bytes, and byte-slice primitives that scan 8 bytes at a time (SWAR),
+// for very hot grid puzzles where a Grid<char> or Grid<enum> spends most of its time chasing pointers
+
+use crate::Parse;
+use std::fmt::{Debug, Display};
+
+const LOW_BITS: u64 = 0x7f7f_7f7f_7f7f_7f7f;
+const ONES: u64 = 0x0101_0101_0101_0101;
+
+/// Mask with the high bit of every byte of `word` that equals `byte` set, and no other bits set
+fn equal_bytes(word: u64, byte: u8) -> u64 {
+    let zeroed = word ^ (ONES * byte as u64);
+    // The high bit of a byte is set iff the byte is zero, without borrows between bytes
+    !(((zeroed & LOW_BITS) + LOW_BITS) | zeroed | LOW_BITS)
+}
+
+fn load(chunk: &[u8]) -> u64 {
+    u64::from_le_bytes(chunk.try_into().unwrap())
+}
+
+/// Number of bytes in `bytes` that equal `byte`
+pub fn count_byte(bytes: &[u8], byte: u8) -> usize {
+    let chunks = bytes.chunks_exact(8);
+    let remainder = chunks.remainder();
+    let counted: usize = chunks
+        .map(|chunk| equal_bytes(load(chunk), byte).count_ones() as usize)
+        .sum();
+    counted + remainder.iter().filter(|&&b| b == byte).count()
+}
+
+/// Index of the first byte in `bytes` that equals `byte`
+pub fn find_byte(bytes: &[u8], byte: u8) -> Option<usize> {
+    let chunks = bytes.chunks_exact(8);
+    let remainder_start = bytes.len() - chunks.remainder().len();
+    for (i, chunk) in chunks.enumerate() {
+        let mask = equal_bytes(load(chunk), byte);
+        if mask != 0 {
+            return Some(i * 8 + mask.trailing_zeros() as usize / 8);
+        }
+    }
+    bytes[remainder_start..]
+        .iter()
+        .position(|&b| b == byte)
+        .map(|i| remainder_start + i)
+}
+
+/// Grid of bytes in a single row-major buffer, like a puzzle input without its newlines
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct ByteGrid {
+    width: usize,
+    height: usize,
+    bytes: Vec<u8>,
+}
+
+impl ByteGrid {
+    pub fn new(width: usize, height: usize, byte: u8) -> ByteGrid {
+        ByteGrid {
+            width,
+            height,
+            bytes: vec![byte; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// All bytes, row after row
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<u8> {
+        (x < self.width && y < self.height).then(|| self.bytes[y * self.width + x])
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, byte: u8) {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside of a {}x{} ByteGrid.",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        self.bytes[y * self.width + x] = byte;
+    }
+
+    pub fn row(&self, y: usize) -> &[u8] {
+        &self.bytes[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [u8] {
+        &mut self.bytes[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Iterate over the bytes of column `x`, from top to bottom
+    pub fn column(&self, x: usize) -> impl Iterator<Item = u8> + '_ {
+        self.bytes[x..].iter().step_by(self.width).copied()
+    }
+
+    /// Copy column `x` into `buffer`, which is reused to avoid an allocation per column
+    pub fn read_column(&self, x: usize, buffer: &mut Vec<u8>) {
+        buffer.clear();
+        buffer.extend(self.column(x));
+    }
+
+    /// Overwrite column `x` with `column`, from top to bottom
+    pub fn write_column(&mut self, x: usize, column: &[u8]) {
+        assert_eq!(self.height, column.len(), "Column has the wrong height.");
+        for (byte, &new) in self.bytes[x..].iter_mut().step_by(self.width).zip(column) {
+            *byte = new;
+        }
+    }
+
+    /// Number of bytes in row `y` that equal `byte`
+    pub fn count_in_row(&self, y: usize, byte: u8) -> usize {
+        count_byte(self.row(y), byte)
+    }
+
+    /// Number of bytes in the whole grid that equal `byte`
+    pub fn count(&self, byte: u8) -> usize {
+        count_byte(&self.bytes, byte)
+    }
+
+    /// First x at or after `x` in row `y` of which the byte equals `byte`, e.g. the next obstacle
+    pub fn find_in_row(&self, x: usize, y: usize, byte: u8) -> Option<usize> {
+        find_byte(&self.row(y)[x..], byte).map(|i| x + i)
+    }
+
+    /// First y at or after `y` in column `x` of which the byte equals `byte`, e.g. the next obstacle
+    pub fn find_in_column(&self, x: usize, y: usize, byte: u8) -> Option<usize> {
+        self.column(x)
+            .skip(y)
+            .position(|b| b == byte)
+            .map(|i| y + i)
+    }
+}
+
+/// Parse the non-empty lines of a puzzle input, which must all have the same length
+impl From<&str> for ByteGrid {
+    fn from(input: &str) -> Self {
+        let mut lines = input.lines().filter(|line| !line.is_empty()).peekable();
+        let width = lines.peek().map_or(0, |line| line.len());
+        let mut bytes = Vec::with_capacity(input.len());
+        let mut height = 0;
+        for line in lines {
+            assert_eq!(width, line.len(), "Line {} has the wrong width.", height);
+            bytes.extend_from_slice(line.as_bytes());
+            height += 1;
+        }
+        ByteGrid {
+            width,
+            height,
+            bytes,
+        }
+    }
+}
+
+impl Parse for ByteGrid {
+    fn parse(input: &str) -> Self {
+        ByteGrid::from(input)
+    }
+}
+
+impl Display for ByteGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            writeln!(f, "{}", String::from_utf8_lossy(self.row(y)))?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for ByteGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ByteGrid({}x{}):\n{}", self.width, self.height, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_primitives() {
+        let bytes = b"..#.O...#O..OO#.#O\x80\xff";
+        for byte in [b'#', b'O', b'.', b'x', 0x80, 0xff] {
+            let expected_count = bytes.iter().filter(|&&b| b == byte).count();
+            test!(expected_count, count_byte(bytes, byte), "count {}", byte);
+            let expected_position = bytes.iter().position(|&b| b == byte);
+            test!(expected_position, find_byte(bytes, byte), "find {}", byte);
+        }
+        test!(0, count_byte(b"", b'#'));
+    }
+
+    #[test]
+    fn test_byte_grid() {
+        let mut grid = ByteGrid::from("O.#.\n.#O.\n..O#\n");
+        test!(4, grid.width());
+        test!(3, grid.height());
+        test!(Some(b'#'), grid.get(1, 1));
+        test!(grid.get(4, 0).is_none());
+        test!(b".#O.", grid.row(1));
+        test!(vec![b'#', b'O', b'O'], grid.column(2).collect::<Vec<_>>());
+        test!(2, grid.count_in_row(1, b'.'));
+        test!(3, grid.count(b'O'));
+        test!(Some(3), grid.find_in_row(0, 2, b'#'));
+        test!(Some(1), grid.find_in_column(2, 1, b'O'));
+        test!(grid.find_in_column(0, 1, b'O').is_none());
+        grid.write_column(0, b"..O");
+        test!("..#.\n.#O.\nO.O#\n", grid.to_string());
+    }
+}
@@ -0,0 +1,258 @@
+#![allow(dead_code)]
+// This module caches the answers of slow, deterministic parts in .aoc/cache.json, keyed by the cache version
+// of the part and the input, so iterating on one part of a day does not have to wait for the other part. It also
+// remembers which parts ran to their expected answer, keyed by the input and the source of the day, so
+// `--skip-verified` only runs the days that changed, or were never verified
+
+use crate::answers::input_hash;
+use crate::solution::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Default location of the cache
+pub const CACHE_PATH: &str = ".aoc/cache.json";
+
+/// Cached answer of a part of a Solution, for the input with hash `input`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Entry {
+    year: Year,
+    day: Day,
+    part: u8,
+    version: String,
+    input: String,
+    answer: Answer,
+}
+
+/// A part of a Solution that ran to its expected answer for the input with hash `input`, when the source of the day
+/// had hash `source`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Verification {
+    year: Year,
+    day: Day,
+    part: u8,
+    input: String,
+    source: String,
+}
+
+/// Cached answers of the parts that opted in with `Solution::cache_version`, and the parts that ran to their expected
+/// answer, with the input and the source they did it with
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Cache {
+    #[serde(skip)]
+    path: PathBuf,
+    entries: Vec<Entry>,
+    #[serde(default)]
+    verified: Vec<Verification>,
+}
+
+impl Cache {
+    /// Load the Cache at `path`, a missing or corrupt file results in an empty Cache
+    pub fn load(path: impl AsRef<Path>) -> Cache {
+        let path = path.as_ref().to_path_buf();
+        let mut cache: Cache = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        cache.path = path;
+        cache
+    }
+
+    /// Write the Cache back to the path it was loaded from
+    pub fn save(&self) -> AocResult<()> {
+        if let Some(directory) = self.path.parent() {
+            fs::create_dir_all(directory).map_err(AocError::io(directory))?;
+        }
+        let json = serde_json::to_string_pretty(self).expect("A Cache is always serializable.");
+        fs::write(&self.path, json).map_err(AocError::io(&self.path))
+    }
+
+    /// The cached answer of `part` of `solution` for `input`, if the part opted in and its version did not change
+    pub fn get(&self, solution: &SolutionBox, part: Part, input: &str) -> Option<&Answer> {
+        let version = solution.cache_version(part)?;
+        let input = input_hash(input);
+        self.entries
+            .iter()
+            .find(|entry| {
+                (entry.year, entry.day, entry.part)
+                    == (solution.year(), solution.day(), part.number())
+                    && (entry.version.as_str(), &entry.input) == (version, &input)
+            })
+            .map(|entry| &entry.answer)
+    }
+
+    /// Cache the answer of `part` of `solution` for `input`, replacing the answer of any other version.
+    ///
+    /// Does nothing if the part did not opt in.
+    pub fn insert(&mut self, solution: &SolutionBox, part: Part, input: &str, answer: Answer) {
+        let Some(version) = solution.cache_version(part) else {
+            return;
+        };
+        let (year, day, part) = (solution.year(), solution.day(), part.number());
+        let input = input_hash(input);
+        self.entries.retain(|entry| {
+            (entry.year, entry.day, entry.part, &entry.input) != (year, day, part, &input)
+        });
+        self.entries.push(Entry {
+            year,
+            day,
+            part,
+            version: version.to_string(),
+            input,
+            answer,
+        });
+    }
+
+    /// Returns true iff `part` of AoC(`year`, `day`) ran to its expected answer for `input`, with `source` as the
+    /// source of the day
+    pub fn is_part_verified(
+        &self,
+        year: Year,
+        day: Day,
+        part: Part,
+        input: &str,
+        source: &str,
+    ) -> bool {
+        let (input, source) = (input_hash(input), input_hash(source));
+        self.verified.iter().any(|verification| {
+            (verification.year, verification.day, verification.part) == (year, day, part.number())
+                && (&verification.input, &verification.source) == (&input, &source)
+        })
+    }
+
+    /// Returns true iff both parts of AoC(`year`, `day`) are verified, see `is_part_verified`
+    pub fn is_verified(&self, year: Year, day: Day, input: &str, source: &str) -> bool {
+        [Part::One, Part::Two]
+            .into_iter()
+            .all(|part| self.is_part_verified(year, day, part, input, source))
+    }
+
+    /// Remember that `part` of AoC(`year`, `day`) ran to its expected answer for `input`, with `source` as the
+    /// source of the day, replacing what was remembered for any other source
+    pub fn verify(&mut self, year: Year, day: Day, part: Part, input: &str, source: &str) {
+        self.unverify(year, day, part, input);
+        self.verified.push(Verification {
+            year,
+            day,
+            part: part.number(),
+            input: input_hash(input),
+            source: input_hash(source),
+        });
+    }
+
+    /// Forget that `part` of AoC(`year`, `day`) ran to its expected answer for `input`, with any source
+    fn unverify(&mut self, year: Year, day: Day, part: Part, input: &str) {
+        let (part, input) = (part.number(), input_hash(input));
+        self.verified.retain(|verification| {
+            (
+                verification.year,
+                verification.day,
+                verification.part,
+                &verification.input,
+            ) != (year, day, part, &input)
+        });
+    }
+
+    /// Remember the parts of `test_result` that ran to their expected answer for `input`, and forget the parts that
+    /// ran to anything else. A part that did not run, or of which the answer was cached, is left as it was.
+    pub fn record_verified(&mut self, test_result: &TestResult, input: &str, source: &str) {
+        let (year, day) = (test_result.year, test_result.day);
+        for part in [Part::One, Part::Two] {
+            match test_result.part(part) {
+                TestStatus::Success(_, _) => self.verify(year, day, part, input, source),
+                TestStatus::Cached(_) | TestStatus::Unknown => {}
+                _ => self.unverify(year, day, part, input),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    /// Solution of which only part two opted in to the Cache, with a version set by `VERSION`
+    #[derive(Default)]
+    struct Slow<const VERSION: u8> {}
+
+    impl<const VERSION: u8> Solution for Slow<VERSION> {
+        no_parse_step!();
+
+        fn solve_part_one(&self, _input: &String, _params: &Params) -> AocResult<Answer> {
+            Ok(1.into())
+        }
+        fn solve_part_two(&self, _input: &String, _params: &Params) -> AocResult<Answer> {
+            Ok(2.into())
+        }
+        fn year(&self) -> Year {
+            2023
+        }
+        fn day(&self) -> Day {
+            0
+        }
+        fn cache_version(&self, part: Part) -> Option<&'static str> {
+            match (part, VERSION) {
+                (Part::One, _) => None,
+                (Part::Two, 1) => Some("1"),
+                (Part::Two, _) => Some("2"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_cache() {
+        let mut cache = Cache::default();
+        let (v1, v2) = (Slow::<1>::create_box(), Slow::<2>::create_box());
+        cache.insert(&v1, Part::One, "input", 1.into());
+        cache.insert(&v1, Part::Two, "input", 2.into());
+        test!(cache.get(&v1, Part::One, "input").is_none());
+        test!(Some(&Answer::Int(2)), cache.get(&v1, Part::Two, "input"));
+        test!(cache.get(&v1, Part::Two, "other input").is_none());
+        test!(cache.get(&v2, Part::Two, "input").is_none());
+        cache.insert(&v2, Part::Two, "input", 3.into());
+        test!(Some(&Answer::Int(3)), cache.get(&v2, Part::Two, "input"));
+        test!(cache.get(&v1, Part::Two, "input").is_none());
+        test!(1, cache.entries.len());
+    }
+
+    fn test_result(p1: TestStatus, p2: TestStatus) -> TestResult {
+        TestResult {
+            year: 2023,
+            day: 5,
+            parse: None,
+            examples: TestStatus::Unknown,
+            p1,
+            p2,
+            p1_memory: None,
+            p2_memory: None,
+        }
+    }
+
+    #[test]
+    fn test_verified() {
+        let mut cache = Cache::default();
+        let success = || TestStatus::Success(Duration::ZERO, 1.into());
+        cache.record_verified(&test_result(success(), success()), "input", "source");
+        test!(cache.is_verified(2023, 5, "input", "source"));
+        test!(!cache.is_verified(2023, 5, "input", "changed source"));
+        test!(!cache.is_verified(2023, 5, "other input", "source"));
+        test!(!cache.is_verified(2023, 6, "input", "source"));
+        // A part of which the answer was cached is still verified
+        let cached = || TestStatus::Cached(1.into());
+        cache.record_verified(&test_result(cached(), cached()), "input", "source");
+        test!(cache.is_verified(2023, 5, "input", "source"));
+        // A part that no longer runs to its expected answer is forgotten
+        let failed = TestStatus::Failed(Duration::ZERO, 2.into());
+        cache.record_verified(&test_result(success(), failed), "input", "source");
+        test!(cache.is_part_verified(2023, 5, Part::One, "input", "source"));
+        test!(!cache.is_verified(2023, 5, "input", "source"));
+        cache.record_verified(
+            &test_result(success(), success()),
+            "input",
+            "changed source",
+        );
+        test!(cache.is_verified(2023, 5, "input", "changed source"));
+        test!(2, cache.verified.len());
+    }
+}
@@ -0,0 +1,268 @@
+// This module contains a Counter (multiset), because counting occurrences is in a lot of
+// Advent of Code puzzles, and the HashMap<T, Int> boilerplate obscures what is being counted, and the frequency
+// helpers that are built on it
+
+use crate::Int;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// Multiset that counts how often every item occurs, items with a count of 0 or less are not kept
+#[derive(Clone, PartialEq, Eq)]
+pub struct Counter<T: Hash + Eq> {
+    counts: HashMap<T, Int>,
+}
+
+impl<T: Hash + Eq> Counter<T> {
+    pub fn new() -> Counter<T> {
+        Counter {
+            counts: HashMap::new(),
+        }
+    }
+
+    /// Count a single occurrence of `item`
+    pub fn add(&mut self, item: T) {
+        self.add_n(item, 1);
+    }
+
+    /// Count `n` occurrences of `item`, a negative `n` removes occurrences
+    pub fn add_n(&mut self, item: T, n: Int) {
+        match self.counts.entry(item) {
+            Entry::Occupied(mut entry) => {
+                *entry.get_mut() += n;
+                if *entry.get() <= 0 {
+                    entry.remove();
+                }
+            }
+            Entry::Vacant(entry) => {
+                if n > 0 {
+                    entry.insert(n);
+                }
+            }
+        }
+    }
+
+    /// Remove `item` entirely, and return how often it occurred
+    pub fn remove(&mut self, item: &T) -> Int {
+        self.counts.remove(item).unwrap_or(0)
+    }
+
+    /// How often `item` occurs, 0 if it does not occur
+    pub fn get(&self, item: &T) -> Int {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    /// Number of distinct items
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Total number of occurrences of all items
+    pub fn total(&self) -> Int {
+        self.counts.values().sum()
+    }
+
+    /// Items and their counts, in arbitrary order
+    pub fn iter(&self) -> impl Iterator<Item = (&T, Int)> + '_ {
+        self.counts.iter().map(|(item, &count)| (item, count))
+    }
+
+    /// The counts of all items, in ascending order
+    pub fn sorted_counts(&self) -> Vec<Int> {
+        let mut counts: Vec<Int> = self.counts.values().copied().collect();
+        counts.sort();
+        counts
+    }
+
+    /// Items and their counts, by descending count, the order of equal counts is arbitrary
+    pub fn most_common(&self) -> Vec<(&T, Int)> {
+        let mut most_common: Vec<(&T, Int)> = self.iter().collect();
+        most_common.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        most_common
+    }
+
+    /// An item with the highest count, if there is any
+    pub fn most_common_item(&self) -> Option<(&T, Int)> {
+        self.iter().max_by_key(|&(_, count)| count)
+    }
+
+    /// An item with the lowest count, if there is any
+    pub fn least_common_item(&self) -> Option<(&T, Int)> {
+        self.iter().min_by_key(|&(_, count)| count)
+    }
+}
+
+impl Counter<char> {
+    /// How often every character of `text` occurs
+    pub fn of_chars(text: &str) -> Counter<char> {
+        text.chars().collect()
+    }
+}
+
+/// The item that occurs most often in `items`, of equal counts the smallest item, like the letters of a checksum
+pub fn most_common_item_of<T: Hash + Eq + Ord + Clone>(
+    items: impl IntoIterator<Item = T>,
+) -> Option<T> {
+    let counter: Counter<T> = items.into_iter().collect();
+    counter
+        .iter()
+        .max_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then_with(|| b.cmp(a)))
+        .map(|(item, _)| item.clone())
+}
+
+/// The item that occurs least often in `items`, of equal counts the smallest item
+pub fn least_common_item_of<T: Hash + Eq + Ord + Clone>(
+    items: impl IntoIterator<Item = T>,
+) -> Option<T> {
+    let counter: Counter<T> = items.into_iter().collect();
+    counter
+        .iter()
+        .min_by(|(a, a_count), (b, b_count)| a_count.cmp(b_count).then_with(|| a.cmp(b)))
+        .map(|(item, _)| item.clone())
+}
+
+/// The `items` grouped by their `key`, every group keeps the order of `items`
+pub fn group_by<T, K: Hash + Eq>(
+    items: impl IntoIterator<Item = T>,
+    key: impl Fn(&T) -> K,
+) -> HashMap<K, Vec<T>> {
+    let mut groups: HashMap<K, Vec<T>> = HashMap::new();
+    for item in items {
+        groups.entry(key(&item)).or_default().push(item);
+    }
+    groups
+}
+
+impl<T: Hash + Eq> Default for Counter<T> {
+    fn default() -> Self {
+        Counter::new()
+    }
+}
+
+impl<T: Hash + Eq + Debug> Debug for Counter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.most_common()).finish()
+    }
+}
+
+impl<T: Hash + Eq> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Counter::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T: Hash + Eq> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+/// Count every item of an iterator of (item, count) pairs
+impl<T: Hash + Eq> FromIterator<(T, Int)> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = (T, Int)>>(iter: I) -> Self {
+        let mut counter = Counter::new();
+        for (item, n) in iter {
+            counter.add_n(item, n);
+        }
+        counter
+    }
+}
+
+impl<T: Hash + Eq> AddAssign for Counter<T> {
+    fn add_assign(&mut self, other: Counter<T>) {
+        for (item, count) in other.counts {
+            self.add_n(item, count);
+        }
+    }
+}
+
+impl<T: Hash + Eq> SubAssign for Counter<T> {
+    fn sub_assign(&mut self, other: Counter<T>) {
+        for (item, count) in other.counts {
+            self.add_n(item, -count);
+        }
+    }
+}
+
+impl<T: Hash + Eq> Add for Counter<T> {
+    type Output = Counter<T>;
+
+    fn add(mut self, other: Counter<T>) -> Counter<T> {
+        self += other;
+        self
+    }
+}
+
+/// Subtract counts, items of which the count drops to 0 or less are removed
+impl<T: Hash + Eq> Sub for Counter<T> {
+    type Output = Counter<T>;
+
+    fn sub(mut self, other: Counter<T>) -> Counter<T> {
+        self -= other;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_counter() {
+        let mut counter: Counter<char> = "abracadabra".chars().collect();
+        test!(5, counter.get(&'a'));
+        test!(0, counter.get(&'z'));
+        test!(5, counter.len());
+        test!(11, counter.total());
+        test!(vec![1, 1, 2, 2, 5], counter.sorted_counts());
+        test!(Some((&'a', 5)), counter.most_common_item());
+        test!((&'a', 5), counter.most_common()[0]);
+        counter.add_n('c', -1);
+        test!(4, counter.len());
+        test!(2, counter.remove(&'b'));
+    }
+
+    #[test]
+    fn test_counter_arithmetic() {
+        let a: Counter<char> = "aabbc".chars().collect();
+        let b: Counter<char> = "abd".chars().collect();
+        let sum = a.clone() + b.clone();
+        test!(3, sum.get(&'a'));
+        test!(1, sum.get(&'d'));
+        let difference = a - b;
+        test!(1, difference.get(&'a'));
+        test!(0, difference.get(&'d'));
+        test!(vec![1, 1, 1], difference.sorted_counts());
+        let populations: Counter<Int> = [(8, 3), (6, 2), (8, 1)].into_iter().collect();
+        test!(4, populations.get(&8));
+    }
+
+    #[test]
+    fn test_frequencies() {
+        let counter = Counter::of_chars("aaaaa-bbb-z-y-x");
+        test!(5, counter.get(&'a'));
+        test!(Some((&'a', 5)), counter.most_common_item());
+        test!(1, counter.least_common_item().unwrap().1);
+        // Ties are broken by the smallest item
+        test!(Some('a'), most_common_item_of("abab".chars()));
+        test!(Some('x'), least_common_item_of("aaaaa-bbb-z-y-x".chars()));
+        test!(most_common_item_of(Vec::<char>::new()).is_none());
+        let lines = ["apple", "avocado", "banana", "blueberry", "cherry"];
+        let groups = group_by(lines, |line| line.len());
+        test!(vec!["banana", "cherry"], groups[&6]);
+        let groups = group_by(lines, |line| line.chars().next().unwrap());
+        test!(3, groups.len());
+        test!(vec!["apple", "avocado"], groups[&'a']);
+    }
+}
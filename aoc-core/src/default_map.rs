@@ -0,0 +1,201 @@
+// This module contains a DefaultMap, a HashMap of which missing keys have a default value,
+// because building adjacency lists and tallies is a lot of `.entry(key).or_default()`
+
+use crate::hash::FastHashMap;
+use std::borrow::Borrow;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Index, IndexMut};
+
+/// HashMap of which every missing key has the `default` value.
+///
+/// Reading a missing key, with `get` or `map[key]`, does not insert it,
+/// mutating it, with `entry` or `map[key]`, inserts the `default` value first.
+///
+/// Two DefaultMaps are equal if they have the same value for every key, whether or not it was inserted.
+#[derive(Clone)]
+pub struct DefaultMap<K: Hash + Eq, V: Clone> {
+    map: FastHashMap<K, V>,
+    default: V,
+}
+
+impl<K: Hash + Eq, V: Clone + Default> DefaultMap<K, V> {
+    /// DefaultMap with `V::default()` as the value of missing keys
+    pub fn new() -> DefaultMap<K, V> {
+        DefaultMap::with_default(V::default())
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> DefaultMap<K, V> {
+    /// DefaultMap with `default` as the value of missing keys
+    pub fn with_default(default: V) -> DefaultMap<K, V> {
+        DefaultMap {
+            map: FastHashMap::default(),
+            default,
+        }
+    }
+
+    /// The value of `key`, or the default value if it is missing
+    pub fn get<Q>(&self, key: &Q) -> &V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).unwrap_or(&self.default)
+    }
+
+    /// Mutable value of `key`, the default value is inserted first if it is missing
+    pub fn entry(&mut self, key: K) -> &mut V {
+        self.map.entry(key).or_insert_with(|| self.default.clone())
+    }
+
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.map.insert(key, value)
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(key)
+    }
+
+    /// Returns true iff `key` has been inserted, its value may still equal the default value
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Number of inserted keys
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        self.map.iter()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> + '_ {
+        self.map.keys()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.map.values()
+    }
+
+    pub fn into_map(self) -> FastHashMap<K, V> {
+        self.map
+    }
+}
+
+impl<K: Hash + Eq, V: Clone + Default> Default for DefaultMap<K, V> {
+    fn default() -> Self {
+        DefaultMap::new()
+    }
+}
+
+impl<K: Hash + Eq, V: Clone + PartialEq> PartialEq for DefaultMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        // An inserted key of which the value equals the default value is the same as a missing key
+        self.default == other.default
+            && self.map.iter().all(|(key, value)| other.get(key) == value)
+            && other.map.iter().all(|(key, value)| self.get(key) == value)
+    }
+}
+
+impl<K: Hash + Eq, V: Clone + Eq> Eq for DefaultMap<K, V> {}
+
+impl<K: Hash + Eq + Debug, V: Clone + Debug> Debug for DefaultMap<K, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.map.iter()).finish()
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Index<K> for DefaultMap<K, V> {
+    type Output = V;
+
+    fn index(&self, key: K) -> &V {
+        self.get(&key)
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> IndexMut<K> for DefaultMap<K, V> {
+    fn index_mut(&mut self, key: K) -> &mut V {
+        self.entry(key)
+    }
+}
+
+impl<K: Hash + Eq, V: Clone + Default> FromIterator<(K, V)> for DefaultMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = DefaultMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Extend<(K, V)> for DefaultMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.map.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_default_map() {
+        let mut tally: DefaultMap<char, i64> = DefaultMap::new();
+        for c in "hello".chars() {
+            tally[c] += 1;
+        }
+        test!(2, tally['l']);
+        test!(0, tally['z']);
+        test!(!tally.contains_key(&'z'));
+        test!(4, tally.len());
+    }
+
+    #[test]
+    fn test_adjacency_list() {
+        let mut adjacency: DefaultMap<&str, Vec<&str>> = DefaultMap::new();
+        for (from, to) in [("a", "b"), ("a", "c"), ("b", "c")] {
+            adjacency.entry(from).push(to);
+            adjacency.entry(to);
+        }
+        test!(vec!["b", "c"], adjacency["a"]);
+        test!(adjacency["c"].is_empty());
+        test!(adjacency["d"].is_empty());
+        test!(3, adjacency.len());
+    }
+
+    #[test]
+    fn test_with_default() {
+        let mut copies: DefaultMap<i64, i64> = DefaultMap::with_default(1);
+        copies[2] += 3;
+        test!(4, copies[2]);
+        test!(1, copies[5]);
+    }
+
+    #[test]
+    fn test_eq() {
+        let mut a: DefaultMap<char, i64> = DefaultMap::new();
+        let mut b: DefaultMap<char, i64> = DefaultMap::new();
+        a['x'] += 1;
+        b['x'] += 1;
+        // `entry` inserts the default value of a missing key
+        b.entry('y');
+        test!(a == b);
+        b['y'] += 1;
+        test!(a != b);
+        test!(DefaultMap::<char, i64>::with_default(1) != DefaultMap::new());
+    }
+}
@@ -0,0 +1,148 @@
+// This module compares two Maps cell by cell, and renders them side by side with the cells that differ highlighted,
+// to see where a simulation diverges from an expected state, like the grids after every cycle of 2023-14
+
+use crate::grid::{Map, Point};
+use crate::hash::FastHashSet;
+use crate::Int;
+use colored::Colorize;
+use std::fmt::{self, Display};
+
+/// A cell that differs between two Maps, None in a Map that is too small to have it
+#[derive(Debug, PartialEq, Eq)]
+pub struct Difference<'a, T> {
+    pub point: Point,
+    pub expected: Option<&'a T>,
+    pub actual: Option<&'a T>,
+}
+
+/// The cells that differ between an expected and an actual Map, see `Map::diff`
+pub struct GridDiff<'a, T> {
+    expected: &'a Map<T>,
+    actual: &'a Map<T>,
+    /// The cells that differ, row by row
+    pub differences: Vec<Difference<'a, T>>,
+}
+
+impl<T: PartialEq> Map<T> {
+    /// The cells that differ between this expected Map and the `actual` Map, including the cells that only one of
+    /// them has if their sizes differ
+    pub fn diff<'a>(&'a self, actual: &'a Map<T>) -> GridDiff<'a, T> {
+        let rows = self.get_rows().max(actual.get_rows());
+        let columns = self.get_columns().max(actual.get_columns());
+        let differences = (0..rows)
+            .flat_map(|y| (0..columns).map(move |x| Point::new(x as Int, y as Int)))
+            .filter_map(|point| {
+                let (expected, actual) = (self.point_get(&point), actual.point_get(&point));
+                (expected != actual).then_some(Difference {
+                    point,
+                    expected,
+                    actual,
+                })
+            })
+            .collect();
+        GridDiff {
+            expected: self,
+            actual,
+            differences,
+        }
+    }
+}
+
+impl<T> GridDiff<'_, T> {
+    /// Returns true iff the Maps are equal
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// Number of cells that differ
+    pub fn len(&self) -> usize {
+        self.differences.len()
+    }
+
+    /// The expected and the actual Map side by side, one line per row, of which every cell is rendered with `cell`,
+    /// and the cells of the actual Map that differ are highlighted
+    pub fn render_with(&self, cell: impl Fn(&T) -> String) -> String {
+        let differs: FastHashSet<Point> = self.differences.iter().map(|d| d.point).collect();
+        let expected: Vec<String> = self
+            .expected
+            .iter()
+            .map(|row| row.iter().map(&cell).collect())
+            .collect();
+        let width = expected
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let rows = expected.len().max(self.actual.get_rows());
+        let mut lines = Vec::with_capacity(rows);
+        for y in 0..rows {
+            let mut line = format!("{:<width$} | ", expected.get(y).map_or("", String::as_str));
+            for (x, value) in self.actual.grid.get(y).into_iter().flatten().enumerate() {
+                let value = cell(value);
+                match differs.contains(&Point::new(x as Int, y as Int)) {
+                    true => line += &value.red().bold().to_string(),
+                    false => line += &value,
+                }
+            }
+            lines.push(line.trim_end().to_string());
+        }
+        lines.join("\n")
+    }
+}
+
+impl<T: Display> Display for GridDiff<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} cells differ, expected | actual:", self.len())?;
+        write!(f, "{}", self.render_with(|value| value.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    fn map(rows: &str) -> Map<char> {
+        rows.lines().map(|row| row.chars().collect()).collect()
+    }
+
+    #[test]
+    fn test_diff() {
+        let (expected, actual) = (map("O..\n.#.\n..O"), map("O..\n.#O\n..."));
+        let diff = expected.diff(&actual);
+        test!(
+            vec![
+                Difference {
+                    point: Point::new(2, 1),
+                    expected: Some(&'.'),
+                    actual: Some(&'O')
+                },
+                Difference {
+                    point: Point::new(2, 2),
+                    expected: Some(&'O'),
+                    actual: Some(&'.')
+                }
+            ],
+            diff.differences
+        );
+        let highlight = |value: &str| value.red().bold().to_string();
+        test!(
+            format!(
+                "2 cells differ, expected | actual:\nO.. | O..\n.#. | .#{}\n..O | ..{}",
+                highlight("O"),
+                highlight(".")
+            ),
+            diff.to_string()
+        );
+        test!(expected.diff(&expected).is_empty());
+    }
+
+    #[test]
+    fn test_diff_sizes() {
+        let (expected, actual) = (map("ab\ncd"), map("ab"));
+        let diff = expected.diff(&actual);
+        test!(2, diff.len());
+        test!(diff.differences[0].actual.is_none());
+        test!("ab | ab\ncd |", diff.render_with(|value| value.to_string()));
+    }
+}
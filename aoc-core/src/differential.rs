@@ -0,0 +1,167 @@
+#![allow(dead_code)]
+// This module runs a brute-force reference implementation and an optimized one on the same inputs, which can be
+// generated from a seed, and reports the first input on which they disagree, so a rewrite is validated on more inputs
+// than the examples
+
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fmt::{Debug, Display};
+
+/// Environment variable with the seed of a single generated input to check, to reproduce a Disagreement
+pub const SEED_ENV: &str = "AOC_DIFFERENTIAL_SEED";
+
+/// An input on which the reference and the optimized implementation disagree
+#[derive(Debug, Clone, PartialEq)]
+pub struct Disagreement<I, O> {
+    /// Seed from which the input was generated, None for a given input
+    pub seed: Option<u64>,
+    pub input: I,
+    pub reference: O,
+    pub optimized: O,
+}
+
+impl<I: Debug, O: Debug> Display for Disagreement<I, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "[Differential] ❌ The implementations disagree on {:?}",
+            self.input
+        )?;
+        writeln!(f, "  reference: {:?}", self.reference)?;
+        write!(f, "  optimized: {:?}", self.optimized)?;
+        if let Some(seed) = self.seed {
+            write!(f, "\n  reproduce with {}={}", SEED_ENV, seed)?;
+        }
+        Ok(())
+    }
+}
+
+/// A brute-force reference implementation and an optimized implementation of the same function, e.g. tilting a
+/// platform of 2023-14 one step at a time, and by counting the rocks between cube-shaped rocks
+pub struct Differential<'a, I, O> {
+    reference: Box<dyn Fn(&I) -> O + 'a>,
+    optimized: Box<dyn Fn(&I) -> O + 'a>,
+}
+
+impl<'a, I, O: PartialEq> Differential<'a, I, O> {
+    pub fn new(
+        reference: impl Fn(&I) -> O + 'a,
+        optimized: impl Fn(&I) -> O + 'a,
+    ) -> Differential<'a, I, O> {
+        Differential {
+            reference: Box::new(reference),
+            optimized: Box::new(optimized),
+        }
+    }
+
+    /// Run both implementations on `input`
+    pub fn check(&self, input: I) -> Result<(), Disagreement<I, O>> {
+        self.check_seeded(None, input)
+    }
+
+    fn check_seeded(&self, seed: Option<u64>, input: I) -> Result<(), Disagreement<I, O>> {
+        let (reference, optimized) = ((self.reference)(&input), (self.optimized)(&input));
+        match reference == optimized {
+            true => Ok(()),
+            false => Err(Disagreement {
+                seed,
+                input,
+                reference,
+                optimized,
+            }),
+        }
+    }
+
+    /// Run both implementations on every input, until they disagree
+    pub fn check_all(&self, inputs: impl IntoIterator<Item = I>) -> Result<(), Disagreement<I, O>> {
+        inputs.into_iter().try_for_each(|input| self.check(input))
+    }
+
+    /// Run both implementations on `cases` inputs of `generate`, the input of case `i` is generated from the seed
+    /// `seed + i`, until they disagree. Only the seed of SEED_ENV is checked if it is set.
+    pub fn check_generated(
+        &self,
+        seed: u64,
+        cases: u64,
+        mut generate: impl FnMut(&mut StdRng) -> I,
+    ) -> Result<(), Disagreement<I, O>> {
+        let seeds = match std::env::var(SEED_ENV)
+            .ok()
+            .and_then(|seed| seed.parse().ok())
+        {
+            Some(seed) => seed..seed + 1,
+            None => seed..seed + cases,
+        };
+        seeds.into_iter().try_for_each(|seed| {
+            let input = generate(&mut StdRng::seed_from_u64(seed));
+            self.check_seeded(Some(seed), input)
+        })
+    }
+}
+
+impl<I: Debug, O: PartialEq + Debug> Differential<'_, I, O> {
+    /// Assert that both implementations agree on `cases` generated inputs, see `check_generated`
+    pub fn assert_generated(&self, seed: u64, cases: u64, generate: impl FnMut(&mut StdRng) -> I) {
+        if let Err(disagreement) = self.check_generated(seed, cases, generate) {
+            panic!("{}", disagreement);
+        }
+        println!("[Differential] ✅ {} generated inputs", cases);
+    }
+}
+
+/// Generate a grid of `width` by `height` of which every cell is a random one of `symbols`, with a newline after every
+/// row, e.g. a random platform of 2023-14
+pub fn grid(rng: &mut StdRng, width: usize, height: usize, symbols: &[u8]) -> String {
+    let mut grid = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        grid.extend((0..width).map(|_| symbols[rng.gen_range(0..symbols.len())] as char));
+        grid.push('\n');
+    }
+    grid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use crate::Int;
+
+    fn sum_of_range(&(a, b): &(Int, Int)) -> Int {
+        (a..=b).sum()
+    }
+
+    #[test]
+    fn test_agree() {
+        let differential = Differential::new(sum_of_range, |&(a, b): &(Int, Int)| {
+            (b - a + 1).max(0) * (a + b) / 2
+        });
+        test!(differential.check_all([(1, 10), (5, 4), (-3, 3)]).is_ok());
+        differential.assert_generated(0, 100, |rng| {
+            (rng.gen_range(-50..50), rng.gen_range(-50..50))
+        });
+    }
+
+    #[test]
+    fn test_disagree() {
+        // Forgets that the range is inclusive
+        let differential = Differential::new(sum_of_range, |&(a, b): &(Int, Int)| (a..b).sum());
+        test!(differential.check((3, 3)).is_err());
+        let disagreement = differential.check_generated(0, 100, |rng| (rng.gen_range(0..10), 10));
+        test!(
+            Some::<Int>(10),
+            disagreement
+                .as_ref()
+                .err()
+                .map(|d| d.reference - d.optimized)
+        );
+        test!(disagreement.unwrap_err().to_string().contains(SEED_ENV));
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = grid(&mut StdRng::seed_from_u64(1), 4, 3, b".#");
+        test!(3, grid.lines().count());
+        test!(grid.lines().all(|line| line.len() == 4));
+        test!(grid.bytes().all(|b| b"\n.#".contains(&b)));
+    }
+}
@@ -0,0 +1,66 @@
+// This module contains the error type of the framework, so a missing session cookie or an
+// unreachable adventofcode.com is reported, instead of crashing the whole run
+
+use std::path::{Path, PathBuf};
+
+/// Error of the Advent of Code framework, panics are reserved for bugs
+#[derive(Debug, thiserror::Error)]
+pub enum AocError {
+    /// There is no session cookie to download puzzle input with
+    #[error("No AoC session cookie at '{}', store the `session` cookie of adventofcode.com there.", .path.display())]
+    MissingSession { path: PathBuf },
+
+    /// adventofcode.com did not accept the session cookie, and returned a login page instead of the puzzle input
+    #[error("The AoC session cookie at '{}' has expired, store a fresh `session` cookie of adventofcode.com there.", .path.display())]
+    SessionExpired { path: PathBuf },
+
+    /// Downloading from adventofcode.com failed
+    #[error("Failed to download AoC {year}-{day:02}: {message}.")]
+    Network {
+        year: i32,
+        day: u32,
+        message: String,
+    },
+
+    /// Reading or writing a cached or stored file failed
+    #[error("Failed to access '{}': {source}.", .path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A file or input is not in the expected format
+    #[error("Failed to parse {what}: {message}.")]
+    Parse { what: String, message: String },
+
+    /// The puzzle input has no answer that the Solution can find, e.g. cycles that never align
+    #[error("No answer: {reason}.")]
+    NoAnswer { reason: String },
+}
+
+impl AocError {
+    /// Wrap an IO error on `path`, for use with `map_err`
+    pub fn io(path: impl AsRef<Path>) -> impl FnOnce(std::io::Error) -> AocError {
+        let path = path.as_ref().to_path_buf();
+        move |source| AocError::Io { path, source }
+    }
+
+    /// A Parse error of `what`, for use with `map_err`
+    pub fn parse<E: std::fmt::Display>(what: impl Into<String>) -> impl FnOnce(E) -> AocError {
+        let what = what.into();
+        move |error| AocError::Parse {
+            what,
+            message: error.to_string(),
+        }
+    }
+
+    /// A NoAnswer error because of `reason`, for use with `ok_or_else`
+    pub fn no_answer(reason: impl Into<String>) -> impl FnOnce() -> AocError {
+        let reason = reason.into();
+        move || AocError::NoAnswer { reason }
+    }
+}
+
+/// Result of the Advent of Code framework
+pub type AocResult<T> = Result<T, AocError>;
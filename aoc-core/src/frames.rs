@@ -0,0 +1,116 @@
+#![allow(dead_code)]
+// This module dumps the steps of simulations as numbered text files when `--dump-frames` is given,
+// so a simulation can be stepped through offline, instead of scrolling back through debug output
+
+use crate::output::OutputDirectory;
+use crate::solution::Params;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory that frames are dumped to, frames are not dumped if it is not set
+static DIRECTORY: OutputDirectory = OutputDirectory::new();
+
+/// Dump frames to `directory`, can only be set once
+pub fn init(directory: impl AsRef<Path>) {
+    DIRECTORY.init(directory);
+}
+
+/// State of a simulation that can be rendered as a text frame
+pub trait Frame {
+    fn write_frame(&self, out: &mut String) -> fmt::Result;
+}
+
+/// Numbered frames of a single simulation, written to `<directory>/<name>/000000.txt`, `000001.txt`, ...
+///
+/// `Frames::default()` writes no frames, e.g. for a part that repeats the simulation of the other part.
+#[derive(Default)]
+pub struct Frames {
+    /// None if frames are not dumped, or writing a frame failed
+    directory: Option<PathBuf>,
+    count: usize,
+    buffer: String,
+}
+
+impl Frames {
+    /// Frames of the simulation `name`, of which the directory gets an `-example` suffix for examples.
+    ///
+    /// Frames of a run of the same simulation by an earlier process are removed.
+    pub fn new(params: &Params, name: &str) -> Frames {
+        Frames {
+            directory: DIRECTORY.subdirectory(params, name, true),
+            count: 0,
+            buffer: String::new(),
+        }
+    }
+
+    /// Returns true iff frames are written, so a caller can skip building a frame
+    pub fn is_enabled(&self) -> bool {
+        self.directory.is_some()
+    }
+
+    /// Number of frames that were written
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Write `state` as the next frame
+    pub fn record(&mut self, state: &impl Frame) {
+        let Some(directory) = &self.directory else {
+            return;
+        };
+        self.buffer.clear();
+        state
+            .write_frame(&mut self.buffer)
+            .expect("Writing to a String does not fail.");
+        self.buffer.push('\n');
+        let path = directory.join(format!("{:06}.txt", self.count));
+        match fs::write(&path, &self.buffer) {
+            Ok(()) => self.count += 1,
+            Err(error) => {
+                tracing::warn!("Stopped dumping frames, '{}': {}.", path.display(), error);
+                self.directory = None;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    struct Step(usize);
+
+    impl Frame for Step {
+        fn write_frame(&self, out: &mut String) -> fmt::Result {
+            use std::fmt::Write;
+            write!(out, "step {}", self.0)
+        }
+    }
+
+    #[test]
+    fn test_frames() {
+        let root = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let mut frames = Frames {
+            directory: Some(root.clone()),
+            ..Frames::default()
+        };
+        fs::create_dir_all(&root).unwrap();
+        frames.record(&Step(0));
+        frames.record(&Step(1));
+        test!(2, frames.len());
+        test!(
+            "step 1\n",
+            fs::read_to_string(root.join("000001.txt")).unwrap()
+        );
+        fs::remove_dir_all(&root).unwrap();
+        let mut disabled = Frames::new(&Params::INPUT, "disabled");
+        disabled.record(&Step(0));
+        test!(!disabled.is_enabled() && disabled.is_empty());
+    }
+}
@@ -0,0 +1,378 @@
+#![allow(dead_code)]
+// This module contains 2D computational geometry on lattice points, like segment intersection, axis-aligned
+// bounding boxes, convex hulls and closest pairs, so geometry days do not each reimplement the special cases
+
+use crate::grid::Point;
+use crate::Int;
+use std::cmp::{max, min};
+use std::collections::BTreeSet;
+use std::ops::AddAssign;
+
+/// A line segment between two lattice points, which may be equal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub head: Point,
+    pub tail: Point,
+}
+
+/// A lattice point in 3D, e.g. a beacon or a point of a constellation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: Int,
+    pub y: Int,
+    pub z: Int,
+}
+
+impl Point3 {
+    pub fn new(x: Int, y: Int, z: Int) -> Point3 {
+        Point3 { x, y, z }
+    }
+}
+
+/// Translate a Point3 by another, e.g. let a brick fall by a distance along z
+impl AddAssign for Point3 {
+    fn add_assign(&mut self, other: Point3) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+/// How two Segments intersect
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Intersection {
+    Disjoint,
+    /// The Segments cross or touch in a single point, which need not be a lattice point
+    Point(f64, f64),
+    /// The Segments are collinear, and share this Segment of more than a single point
+    Overlap(Segment),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Orientation {
+    Collinear,
+    Clockwise,
+    Counterclockwise,
+}
+
+/// Orientation of the turn from `p` via `q` to `r`
+fn orientation(p: &Point, q: &Point, r: &Point) -> Orientation {
+    let value =
+        (q.y - p.y) as i128 * (r.x - q.x) as i128 - (q.x - p.x) as i128 * (r.y - q.y) as i128;
+    match value.signum() {
+        0 => Orientation::Collinear,
+        1 => Orientation::Clockwise,
+        _ => Orientation::Counterclockwise,
+    }
+}
+
+/// Whether `q` lies in the bounding box of `p` and `r`, so on the segment `pr` if the three are collinear
+fn on_segment(p: &Point, q: &Point, r: &Point) -> bool {
+    Aabb::new(*p, *r).contains(q)
+}
+
+/// The cross product of the vectors `a` and `b`
+fn cross(a: (i128, i128), b: (i128, i128)) -> i128 {
+    a.0 * b.1 - a.1 * b.0
+}
+
+impl Segment {
+    pub fn new(head: Point, tail: Point) -> Segment {
+        Segment { head, tail }
+    }
+
+    pub fn bounding_box(&self) -> Aabb {
+        Aabb::new(self.head, self.tail)
+    }
+
+    fn direction(&self) -> (i128, i128) {
+        (
+            (self.tail.x - self.head.x) as i128,
+            (self.tail.y - self.head.y) as i128,
+        )
+    }
+
+    /// Returns true iff self and other share at least one point
+    pub fn intersects(&self, other: &Segment) -> bool {
+        let (p1, q1, p2, q2) = (&self.head, &self.tail, &other.head, &other.tail);
+
+        // Find the four orientations needed for the general and special cases
+        let o1 = orientation(p1, q1, p2);
+        let o2 = orientation(p1, q1, q2);
+        let o3 = orientation(p2, q2, p1);
+        let o4 = orientation(p2, q2, q1);
+
+        // General case
+        if o1 != o2 && o3 != o4 {
+            return true;
+        }
+
+        // Special cases, in which an end point of one segment is collinear with, and lies on, the other segment
+        use Orientation::Collinear;
+        (o1 == Collinear && on_segment(p1, p2, q1))
+            || (o2 == Collinear && on_segment(p1, q2, q1))
+            || (o3 == Collinear && on_segment(p2, p1, q2))
+            || (o4 == Collinear && on_segment(p2, q1, q2))
+    }
+
+    /// How self and other intersect, collinear Segments intersect in the Segment that they share
+    pub fn intersection(&self, other: &Segment) -> Intersection {
+        if !self.intersects(other) {
+            return Intersection::Disjoint;
+        }
+        let (direction, other_direction) = (self.direction(), other.direction());
+        let denominator = cross(direction, other_direction);
+        if denominator != 0 {
+            let offset = (
+                (other.head.x - self.head.x) as i128,
+                (other.head.y - self.head.y) as i128,
+            );
+            let t = cross(offset, other_direction) as f64 / denominator as f64;
+            return Intersection::Point(
+                self.head.x as f64 + t * direction.0 as f64,
+                self.head.y as f64 + t * direction.1 as f64,
+            );
+        }
+        // Collinear, the order of (x, y) is the order along the line
+        let start = max(min(self.head, self.tail), min(other.head, other.tail));
+        let end = min(max(self.head, self.tail), max(other.head, other.tail));
+        match start == end {
+            true => Intersection::Point(start.x as f64, start.y as f64),
+            false => Intersection::Overlap(Segment::new(start, end)),
+        }
+    }
+}
+
+/// Axis-aligned bounding box, of which `min` and `max` are the inclusive corners
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Aabb {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Aabb {
+    /// The Aabb with opposite corners `a` and `b`
+    pub fn new(a: Point, b: Point) -> Aabb {
+        Aabb {
+            min: Point::new(min(a.x, b.x), min(a.y, b.y)),
+            max: Point::new(max(a.x, b.x), max(a.y, b.y)),
+        }
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    /// Whether self and other share at least one point, touching boxes overlap
+    pub fn overlaps(&self, other: &Aabb) -> bool {
+        self.min.x <= other.max.x
+            && other.min.x <= self.max.x
+            && self.min.y <= other.max.y
+            && other.min.y <= self.max.y
+    }
+
+    /// The Aabb that self and other share, if they overlap
+    pub fn intersection(&self, other: &Aabb) -> Option<Aabb> {
+        self.overlaps(other).then(|| Aabb {
+            min: Point::new(max(self.min.x, other.min.x), max(self.min.y, other.min.y)),
+            max: Point::new(min(self.max.x, other.max.x), min(self.max.y, other.max.y)),
+        })
+    }
+}
+
+/// The vertices of the convex hull of `points`, counterclockwise if the y-axis points up, starting at the smallest
+/// point. Points on the edges of the hull are left out.
+///
+/// Andrew's monotone chain, in `O(n log n)`.
+pub fn convex_hull(points: &[Point]) -> Vec<Point> {
+    let mut points = points.to_vec();
+    points.sort();
+    points.dedup();
+    if points.len() < 3 {
+        return points;
+    }
+    // The lower hull from left to right, and the upper hull from right to left, which only turn counterclockwise
+    let half_hull = |points: &mut dyn Iterator<Item = &Point>| {
+        let mut hull: Vec<Point> = Vec::new();
+        for &point in points {
+            while hull.len() >= 2
+                && orientation(&hull[hull.len() - 2], &hull[hull.len() - 1], &point)
+                    != Orientation::Counterclockwise
+            {
+                hull.pop();
+            }
+            hull.push(point);
+        }
+        // The last point is the first point of the other half
+        hull.pop();
+        hull
+    };
+    let mut hull = half_hull(&mut points.iter());
+    hull.extend(half_hull(&mut points.iter().rev()));
+    hull
+}
+
+/// The squared Euclidean distance between `a` and `b`
+pub fn squared_distance(a: &Point, b: &Point) -> Int {
+    (a.x - b.x).pow(2) + (a.y - b.y).pow(2)
+}
+
+/// The two points of `points` that are closest to each other, None if there are fewer than two points.
+///
+/// A sweep line over the points by x, which only compares a point with the points within the closest distance so far
+/// in both x and y, in `O(n log n)`.
+pub fn closest_pair(points: &[Point]) -> Option<(Point, Point)> {
+    let mut sorted = points.to_vec();
+    sorted.sort();
+    let mut best: Option<(Int, Point, Point)> = None;
+    // The points within the closest distance in x of the sweep line, by (y, x)
+    let mut active: BTreeSet<(Int, Int)> = BTreeSet::new();
+    let mut left = 0;
+    for &point in &sorted {
+        if let Some((distance, _, _)) = best {
+            let width = distance.isqrt() + 1;
+            while sorted[left].x < point.x - width {
+                active.remove(&(sorted[left].y, sorted[left].x));
+                left += 1;
+            }
+            let range = (point.y - width, Int::MIN)..=(point.y + width, Int::MAX);
+            for &(y, x) in active.range(range) {
+                let other = Point::new(x, y);
+                let distance = squared_distance(&point, &other);
+                if best.is_none_or(|(best, _, _)| distance < best) {
+                    best = Some((distance, other, point));
+                }
+            }
+        } else if let Some(&(y, x)) = active.first() {
+            best = Some((
+                squared_distance(&point, &Point::new(x, y)),
+                Point::new(x, y),
+                point,
+            ));
+        }
+        if !active.insert((point.y, point.x)) {
+            // A duplicate point, at distance 0
+            return Some((point, point));
+        }
+    }
+    best.map(|(_, a, b)| (a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use crate::testing::random_coordinates;
+
+    fn segment(x1: i64, y1: i64, x2: i64, y2: i64) -> Segment {
+        Segment::new(Point::new(x1, y1), Point::new(x2, y2))
+    }
+
+    #[test]
+    fn test_intersection() {
+        use Intersection::*;
+        test!(
+            Point(1.0, 1.0),
+            segment(0, 0, 2, 2).intersection(&segment(0, 2, 2, 0))
+        );
+        test!(
+            Point(0.5, 0.5),
+            segment(0, 0, 1, 1).intersection(&segment(0, 1, 1, 0))
+        );
+        test!(
+            Point(2.0, 2.0),
+            segment(0, 0, 2, 2).intersection(&segment(2, 2, 5, 0))
+        );
+        test!(
+            Disjoint,
+            segment(0, 0, 2, 2).intersection(&segment(3, 0, 5, 0))
+        );
+        // Collinear
+        test!(
+            Disjoint,
+            segment(0, 0, 2, 0).intersection(&segment(3, 0, 5, 0))
+        );
+        test!(
+            Point(2.0, 0.0),
+            segment(0, 0, 2, 0).intersection(&segment(5, 0, 2, 0))
+        );
+        test!(
+            Overlap(segment(1, 1, 2, 2)),
+            segment(3, 3, 0, 0).intersection(&segment(1, 1, 2, 2))
+        );
+        test!(
+            Overlap(segment(2, 0, 4, 0)),
+            segment(0, 0, 4, 0).intersection(&segment(6, 0, 2, 0))
+        );
+        test!(segment(1, 1, 1, 1).intersects(&segment(0, 0, 2, 2)));
+        test!(!segment(1, 1, 1, 1).intersects(&segment(0, 0, 2, 3)));
+    }
+
+    #[test]
+    fn test_aabb() {
+        let a = Aabb::new(Point::new(2, 0), Point::new(0, 2));
+        test!(Point::new(0, 0), a.min);
+        test!(a.contains(&Point::new(1, 2)));
+        test!(!a.contains(&Point::new(3, 2)));
+        let touching = Aabb::new(Point::new(2, 2), Point::new(4, 5));
+        test!(a.overlaps(&touching) && touching.overlaps(&a));
+        test!(
+            Some(Aabb::new(Point::new(2, 2), Point::new(2, 2))),
+            a.intersection(&touching)
+        );
+        let apart = Aabb::new(Point::new(3, 0), Point::new(4, 5));
+        test!(!a.overlaps(&apart));
+        test!(a.intersection(&apart).is_none());
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        let points: Vec<Point> = [
+            (0, 0),
+            (2, 0),
+            (4, 0),
+            (1, 1),
+            (2, 2),
+            (4, 4),
+            (0, 4),
+            (0, 2),
+            (3, 1),
+        ]
+        .iter()
+        .map(|&(x, y)| Point::new(x, y))
+        .collect();
+        let hull: Vec<(i64, i64)> = convex_hull(&points).iter().map(|p| (p.x, p.y)).collect();
+        test!(vec![(0, 0), (4, 0), (4, 4), (0, 4)], hull);
+        test!(
+            vec![Point::new(1, 1)],
+            convex_hull(&[Point::new(1, 1), Point::new(1, 1)])
+        );
+        // Collinear points only have the end points as their hull
+        let line = [Point::new(0, 0), Point::new(1, 1), Point::new(2, 2)];
+        test!(vec![Point::new(0, 0), Point::new(2, 2)], convex_hull(&line));
+    }
+
+    #[test]
+    fn test_closest_pair() {
+        let points: Vec<Point> = [(0, 0), (10, 10), (3, 4), (7, 1), (9, 7), (-5, 2), (8, 8)]
+            .iter()
+            .map(|&(x, y)| Point::new(x, y))
+            .collect();
+        test!(
+            Some((Point::new(8, 8), Point::new(9, 7))),
+            closest_pair(&points)
+        );
+        test!(closest_pair(&points[..1]).is_none());
+        // Compare with a brute force search of random points
+        let points: Vec<Point> = random_coordinates(600)
+            .chunks(2)
+            .map(|c| Point::new(c[0], c[1]))
+            .collect();
+        let brute_force = (0..points.len())
+            .flat_map(|i| (i + 1..points.len()).map(move |j| (i, j)))
+            .map(|(i, j)| squared_distance(&points[i], &points[j]))
+            .min();
+        let (a, b) = closest_pair(&points).unwrap();
+        test!(brute_force, Some(squared_distance(&a, &b)));
+    }
+}
@@ -0,0 +1,94 @@
+// This module compares large expected outputs of tests, like the grids of a simulation, with golden files, so they
+// do not have to be typed out in the tests, and a failing test shows which lines differ
+
+use crate::grid::Map;
+use std::fs;
+use std::path::Path;
+
+/// Directory of the golden files, relative to the root of the crate of the test
+pub const GOLDEN_DIRECTORY: &str = "tests/golden";
+
+/// Environment variable that rewrites the golden files with the actual output when it is set, e.g. after a change
+/// that is meant to change the output
+pub const UPDATE_ENV: &str = "AOC_UPDATE_GOLDEN";
+
+/// Assert that `actual` is the content of the golden file `path` in the GOLDEN_DIRECTORY of `crate_root`, or write
+/// `actual` to it if UPDATE_ENV is set. Use `assert_matches_golden!` instead, which knows the root of the crate.
+pub fn assert_matches(crate_root: &str, path: &str, actual: &str) {
+    let file = Path::new(crate_root).join(GOLDEN_DIRECTORY).join(path);
+    if std::env::var_os(UPDATE_ENV).is_some_and(|value| !value.is_empty()) {
+        if let Some(directory) = file.parent() {
+            fs::create_dir_all(directory).unwrap();
+        }
+        fs::write(&file, actual)
+            .unwrap_or_else(|error| panic!("Failed to write golden file '{}': {}.", path, error));
+        println!("[Golden] Updated '{}'.", path);
+        return;
+    }
+    let expected = fs::read_to_string(&file).unwrap_or_else(|error| {
+        panic!(
+            "Failed to read golden file '{}': {}, set {}=1 to create it.",
+            path, error, UPDATE_ENV
+        )
+    });
+    assert!(
+        expected == actual,
+        "[Golden] ❌ '{}' differs, set {}=1 to update it:\n{}",
+        path,
+        UPDATE_ENV,
+        diff(&expected, actual)
+    );
+    println!("[Golden] ✅ '{}'", path);
+}
+
+/// The grid of `text` if it has more than one line, and all of its lines are equally long and longer than one
+fn grid(text: &str) -> Option<Map<char>> {
+    let map: Map<char> = text.lines().map(|line| line.chars().collect()).collect();
+    let rectangular = map.iter().all(|row| row.len() == map.get_columns());
+    (map.get_rows() > 1 && map.get_columns() > 1 && rectangular).then_some(map)
+}
+
+/// The cells that differ between `expected` and `actual` if both are grids, or the lines that differ by line number
+fn diff(expected: &str, actual: &str) -> String {
+    if let (Some(expected), Some(actual)) = (grid(expected), grid(actual)) {
+        let diff = expected.diff(&actual);
+        if !diff.is_empty() {
+            return format!("{}\n", diff);
+        }
+    }
+    let (expected, actual): (Vec<&str>, Vec<&str>) =
+        (expected.lines().collect(), actual.lines().collect());
+    let mut differences = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        let (expected, actual) = (expected.get(i), actual.get(i));
+        if expected != actual {
+            differences += &format!(
+                "line {}:\n  - {}\n  + {}\n",
+                i + 1,
+                expected.unwrap_or(&"<missing>"),
+                actual.unwrap_or(&"<missing>")
+            );
+        }
+    }
+    if differences.is_empty() {
+        differences += "only the line endings differ\n";
+    }
+    differences
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_diff() {
+        test!(
+            "line 2:\n  - b\n  + x\nline 4:\n  - <missing>\n  + d\n",
+            diff("a\nb\nc\n", "a\nx\nc\nd\n")
+        );
+        test!("only the line endings differ\n", diff("a\n", "a"));
+        test!(diff("ab\ncd\n", "ab\nce\n").starts_with("1 cells differ"));
+        test!("only the line endings differ\n", diff("ab\ncd\n", "ab\ncd"));
+    }
+}
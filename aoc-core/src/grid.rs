@@ -0,0 +1,421 @@
+#![allow(dead_code)]
+use crate::Grid;
+use crate::Int;
+use crate::WriteGrid;
+use std::fmt::Debug;
+use std::fmt::Display;
+
+/// Generic 2D grid with helper functions to move around
+#[derive(Clone)]
+pub struct Map<T> {
+    pub grid: Grid<T>,
+}
+
+impl<T> Map<T> {
+    pub fn get_rows(&self) -> usize {
+        self.grid.len()
+    }
+
+    pub fn get_columns(&self) -> usize {
+        self.grid.first().unwrap().len()
+    }
+
+    pub fn point_within_grid(&self, point: &Point) -> bool {
+        self.within(point.x, point.y)
+    }
+
+    pub fn within(&self, x: Int, y: Int) -> bool {
+        x >= 0 && x < self.get_columns() as Int && y >= 0 && y < self.get_rows() as Int
+    }
+
+    pub fn point_get(&self, point: &Point) -> Option<&T> {
+        self.get(point.x, point.y)
+    }
+
+    pub fn get(&self, x: Int, y: Int) -> Option<&T> {
+        return if self.within(x, y) {
+            Some(&self.grid[y as usize][x as usize])
+        } else {
+            None
+        };
+    }
+
+    /// Return a list of Points for which the Predicate on T hold
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Vec<Point> {
+        let mut result = Vec::new();
+        for y in 0..self.get_rows() {
+            for x in 0..self.get_columns() {
+                let point = Point::new(x as Int, y as Int);
+                let value = self.point_get(&point).unwrap();
+                if predicate(value) {
+                    result.push(point);
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns a list of Points within the Map that are adjacent to point
+    pub fn get_adjacent(&self, point: &Point) -> Vec<Point> {
+        if !self.point_within_grid(point) {
+            Vec::new()
+        } else {
+            vec![North, East, South, West]
+                .iter()
+                .map(|d| point.move_to(d))
+                .filter(|p| self.point_within_grid(p))
+                .collect()
+        }
+    }
+
+    /// The cells in `direction` from `from`, without `from` itself, until the edge of the Map. Stop it earlier with
+    /// `take_while`, or find the first cell that blocks the line of sight with `first_hit`.
+    pub fn ray(&self, from: Point, direction: Direction) -> impl Iterator<Item = (Point, &T)> {
+        let step = Point::new(0, 0).move_to(&direction);
+        self.ray_step(from, step.x, step.y)
+    }
+
+    /// The cells from `from` in steps of (`dx`, `dy`), without `from` itself, until the edge of the Map, e.g.
+    /// along a diagonal
+    pub fn ray_step(&self, from: Point, dx: Int, dy: Int) -> impl Iterator<Item = (Point, &T)> {
+        assert!(dx != 0 || dy != 0, "A ray needs a non-zero step.");
+        std::iter::successors(Some(from.translate(dx, dy)), move |point| {
+            Some(point.translate(dx, dy))
+        })
+        .map_while(|point| self.point_get(&point).map(|value| (point, value)))
+    }
+
+    /// The first cell in `direction` from `from` that is blocking, None if the ray reaches the edge of the Map
+    pub fn first_hit(
+        &self,
+        from: Point,
+        direction: Direction,
+        is_blocking: impl Fn(&T) -> bool,
+    ) -> Option<(Point, &T)> {
+        self.ray(from, direction)
+            .find(|(_, value)| is_blocking(value))
+    }
+
+    /// Every `width` by `height` Window that fits within the Map, row by row, e.g. to find a pattern
+    pub fn windows(&self, width: usize, height: usize) -> impl Iterator<Item = Window<'_, T>> {
+        let (rows, columns) = match self.grid.is_empty() {
+            true => (0, 0),
+            false => (self.get_rows(), self.get_columns()),
+        };
+        let ys = 0..(rows + 1).saturating_sub(height);
+        ys.flat_map(move |y| {
+            (0..(columns + 1).saturating_sub(width)).map(move |x| Window {
+                map: self,
+                origin: Point::new(x as Int, y as Int),
+                width,
+                height,
+            })
+        })
+    }
+
+    /// Map every cell to `f` of its neighborhood, the Window of `radius` cells around it in every direction, like
+    /// a step of a cellular automaton. Windows at the border of the Map extend beyond it.
+    pub fn map_neighborhood<U>(&self, radius: usize, f: impl Fn(&Window<T>) -> U) -> Map<U> {
+        let size = 2 * radius + 1;
+        let radius = radius as Int;
+        (0..self.get_rows())
+            .map(|y| {
+                (0..self.get_columns())
+                    .map(|x| {
+                        f(&Window {
+                            map: self,
+                            origin: Point::new(x as Int - radius, y as Int - radius),
+                            width: size,
+                            height: size,
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// View of a rectangle of a Map, of which `origin` is the top-left Point, which may lie outside the Map
+#[derive(Clone, Copy)]
+pub struct Window<'a, T> {
+    map: &'a Map<T>,
+    pub origin: Point,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<'a, T> Window<'a, T> {
+    /// The cell at (`x`, `y`) relative to the origin, None if it is outside the Window or the Map
+    pub fn get(&self, x: Int, y: Int) -> Option<&'a T> {
+        let within = x >= 0 && x < self.width as Int && y >= 0 && y < self.height as Int;
+        within
+            .then(|| self.map.get(self.origin.x + x, self.origin.y + y))
+            .flatten()
+    }
+
+    /// The cell in the middle of the Window, the cell of which it is the neighborhood
+    pub fn center(&self) -> Option<&'a T> {
+        self.get(self.width as Int / 2, self.height as Int / 2)
+    }
+
+    /// The cells of the Window within the Map, with their Point relative to the origin, row by row
+    pub fn cells(&self) -> impl Iterator<Item = (Point, &'a T)> + '_ {
+        (0..self.height as Int).flat_map(move |y| {
+            (0..self.width as Int)
+                .filter_map(move |x| self.get(x, y).map(|value| (Point::new(x, y), value)))
+        })
+    }
+
+    /// Number of cells of the Window within the Map for which the predicate holds
+    pub fn count(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        self.cells().filter(|(_, value)| predicate(value)).count()
+    }
+}
+
+/// One line per row, every cell with its Display implementation
+impl<T: Display> Display for Map<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.grid.write_to(f)
+    }
+}
+
+// Deref and DerefMut allow us to extend a Grid<T> in Grid2D<T>
+impl<T> Deref for Map<T> {
+    type Target = Vec<Vec<T>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.grid
+    }
+}
+
+impl<T> DerefMut for Map<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.grid
+    }
+}
+
+// Implement FromIterator for Grid2D
+impl<T> FromIterator<Vec<T>> for Map<T> {
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
+        let grid: Vec<Vec<T>> = iter.into_iter().collect();
+        Map { grid }
+    }
+}
+
+impl Map<Int> {
+    /// Parse a Map of which every cell is a single digit, like a heightmap, empty lines and whitespace around the
+    /// lines are skipped, panics on a cell that is not a digit
+    pub fn parse_digits(input: &str) -> Map<Int> {
+        Map::parse_cells(input, |line| {
+            line.chars()
+                .map(|c| match c.to_digit(10) {
+                    Some(digit) => digit as Int,
+                    None => panic!("Not a digit: '{}'.", c),
+                })
+                .collect()
+        })
+    }
+
+    /// Parse a Map of which the cells are numbers separated by whitespace, empty lines are skipped, panics on a cell
+    /// that is not a number
+    pub fn parse_numbers(input: &str) -> Map<Int> {
+        Map::parse_cells(input, |line| {
+            line.split_whitespace()
+                .map(|number| {
+                    number
+                        .parse()
+                        .unwrap_or_else(|_| panic!("Not a number: '{}'.", number))
+                })
+                .collect()
+        })
+    }
+
+    fn parse_cells(input: &str, row: impl Fn(&str) -> Vec<Int>) -> Map<Int> {
+        input
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(row)
+            .collect()
+    }
+}
+
+use std::ops::{Deref, DerefMut};
+
+use crate::define_convertable_enum;
+pub use Direction::*;
+
+define_convertable_enum! {
+    Direction {
+        North => '^',
+        East => '>',
+        South => 'v',
+        West => '<',
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[derive(PartialEq, Clone, Eq, Hash, Copy, PartialOrd, Ord)]
+pub struct Point {
+    pub x: Int,
+    pub y: Int,
+}
+
+impl Debug for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Point({}, {})", self.x, self.y)
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl Point {
+    pub fn move_to(&self, direction: &Direction) -> Point {
+        self.move_distance(direction, 1)
+    }
+
+    pub fn move_distance(&self, direction: &Direction, distance: Int) -> Point {
+        match direction {
+            North => Point {
+                x: self.x,
+                y: self.y - distance,
+            },
+            East => Point {
+                x: self.x + distance,
+                y: self.y,
+            },
+            South => Point {
+                x: self.x,
+                y: self.y + distance,
+            },
+            West => Point {
+                x: self.x - distance,
+                y: self.y,
+            },
+        }
+    }
+
+    pub fn new(x: Int, y: Int) -> Point {
+        Point { x, y }
+    }
+
+    pub fn translate(mut self, x: Int, y: Int) -> Point {
+        self.x += x;
+        self.y += y;
+        self
+    }
+
+    pub fn distance_to(&self, other: &Point) -> f64 {
+        (((self.x - other.x).pow(2) + (self.y - other.y).pow(2)) as f64).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    fn map(rows: &str) -> Map<char> {
+        rows.lines().map(|row| row.chars().collect()).collect()
+    }
+
+    #[test]
+    fn test_ray() {
+        let map = map("#.L.\n....\n.L.#");
+        let from = Point::new(0, 0);
+        test!(
+            vec![Point::new(1, 0), Point::new(2, 0), Point::new(3, 0)],
+            map.ray(from, East).map(|(p, _)| p).collect::<Vec<_>>()
+        );
+        test!(0, map.ray(from, North).count());
+        test!(
+            "L.",
+            map.ray(Point::new(3, 0), West)
+                .take_while(|(_, &c)| c != '#')
+                .map(|(_, c)| c)
+                .collect::<String>()
+        );
+        test!(
+            Some((Point::new(2, 0), &'L')),
+            map.first_hit(from, East, |&c| c != '.')
+        );
+        test!(map
+            .first_hit(Point::new(2, 0), South, |&c| c != '.')
+            .is_none());
+        test!(
+            Some((Point::new(3, 2), &'#')),
+            map.ray_step(Point::new(1, 0), 1, 1).last()
+        );
+        // Seat visibility: the first seat in each of the 8 directions
+        let visible = |from: Point| {
+            let steps = (-1..=1).flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)));
+            steps
+                .filter(|&step| step != (0, 0))
+                .filter_map(|(dx, dy)| map.ray_step(from, dx, dy).find(|(_, &c)| c != '.'))
+                .count()
+        };
+        test!(3, visible(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn test_windows() {
+        let map = map("abcd\nefgh\nijkl");
+        let windows: Vec<Window<char>> = map.windows(3, 2).collect();
+        test!(4, windows.len());
+        test!(Point::new(1, 1), windows[3].origin);
+        test!(Some(&'g'), windows[3].get(1, 0));
+        test!(windows[3].get(3, 0).is_none());
+        test!(
+            "bcdfgh",
+            windows[1].cells().map(|(_, c)| c).collect::<String>()
+        );
+        test!(0, map.windows(5, 1).count());
+        // Find a pattern
+        let pattern = map
+            .windows(2, 2)
+            .find(|window| window.get(0, 0) == Some(&'g'));
+        test!(Some(Point::new(2, 1)), pattern.map(|window| window.origin));
+    }
+
+    #[test]
+    fn test_map_neighborhood() {
+        // A step of Conway's Game of Life turns a blinker from horizontal to vertical
+        let blinker = map(".....\n.....\n.###.\n.....\n.....");
+        let step = |map: &Map<char>| {
+            map.map_neighborhood(1, |window| {
+                let alive = window.center() == Some(&'#');
+                match window.count(|&c| c == '#') - alive as usize {
+                    3 => '#',
+                    2 if alive => '#',
+                    _ => '.',
+                }
+            })
+        };
+        let vertical = step(&blinker);
+        test!(".....\n..#..\n..#..\n..#..\n.....", vertical.to_string());
+        test!(blinker.to_string(), step(&vertical).to_string());
+        // The neighborhood of a corner extends beyond the Map
+        let corner = blinker.map_neighborhood(1, |window| window.cells().count());
+        test!(4, corner[0][0]);
+        test!(9, corner[2][2]);
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        let digits = Map::parse_digits("\n  2413\n  3215\n");
+        test!(vec![vec![2, 4, 1, 3], vec![3, 2, 1, 5]], digits.grid);
+        test!(Some(&5), digits.get(3, 1));
+        let numbers = Map::parse_numbers("22 13 17\n 8  2 23\n");
+        test!(vec![vec![22, 13, 17], vec![8, 2, 23]], numbers.grid);
+        test!(Map::parse_digits("").grid.is_empty());
+    }
+}
@@ -0,0 +1,91 @@
+// This module contains a fast, non-cryptographic Hasher (FxHash, as used by rustc), because the
+// default SipHash is made to resist HashDoS, which Advent of Code inputs never attempt
+
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasherDefault, Hasher};
+
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+/// Hasher that multiplies and rotates every word into its state, fast for small keys like points
+#[derive(Default, Clone, Copy)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    fn add_to_hash(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add_to_hash(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        let mut rest = [0u8; 8];
+        let remainder = chunks.remainder();
+        rest[..remainder.len()].copy_from_slice(remainder);
+        self.add_to_hash(u64::from_le_bytes(rest) ^ remainder.len() as u64);
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add_to_hash(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add_to_hash(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// BuildHasher of FxHasher
+pub type FastBuildHasher = BuildHasherDefault<FxHasher>;
+
+/// HashMap with the fast FxHasher, create it with `FastHashMap::default()`
+pub type FastHashMap<K, V> = HashMap<K, V, FastBuildHasher>;
+
+/// HashSet with the fast FxHasher, create it with `FastHashSet::default()`
+pub type FastHashSet<T> = HashSet<T, FastBuildHasher>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::hash::{BuildHasher, Hash};
+
+    fn hash<T: Hash>(value: T) -> u64 {
+        FastBuildHasher::default().hash_one(value)
+    }
+
+    #[test]
+    fn test_fx_hasher() {
+        test!(hash((1, 2)) == hash((1, 2)));
+        test!(hash((1, 2)) != hash((2, 1)));
+        test!(hash("ab") != hash("abc"));
+        test!(hash("") != hash("\0"));
+        let mut set: FastHashSet<(i64, i64)> = FastHashSet::default();
+        for x in -50..50 {
+            for y in -50..50 {
+                set.insert((x, y));
+            }
+        }
+        test!(10000, set.len());
+    }
+}
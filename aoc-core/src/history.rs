@@ -0,0 +1,261 @@
+#![allow(dead_code)]
+// This module stores how long every part of every solution took, in .aoc/history.json,
+// so later runs can be ordered and compared by their timings, and outliers stand out
+
+use crate::runner::Part;
+use crate::solution::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Default location of the timing history
+pub const HISTORY_PATH: &str = ".aoc/history.json";
+
+/// Number of recent durations of every part that are kept
+pub const RECENT_RUNS: usize = 20;
+
+/// Number of recent durations that a part needs before it can be an outlier
+pub const MIN_RUNS: usize = 5;
+
+/// Number of standard deviations from the mean of its recent durations at which a duration is an outlier
+pub const OUTLIER_SIGMAS: f64 = 3.0;
+
+/// Last known duration of both parts of a Solution, and their recent durations, in seconds
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Timing {
+    pub part_one: Option<f64>,
+    pub part_two: Option<f64>,
+    /// Up to RECENT_RUNS durations of each part, oldest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_one: Vec<f64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recent_two: Vec<f64>,
+}
+
+impl Timing {
+    /// Total duration of both parts, or None if no part has been timed
+    pub fn total(&self) -> Option<Duration> {
+        match (self.part_one, self.part_two) {
+            (None, None) => None,
+            (one, two) => Some(Duration::from_secs_f64(
+                one.unwrap_or(0.0) + two.unwrap_or(0.0),
+            )),
+        }
+    }
+
+    /// Recent durations of a single part, oldest first
+    pub fn recent(&self, part: Part) -> &[f64] {
+        match part {
+            Part::One => &self.recent_one,
+            Part::Two => &self.recent_two,
+        }
+    }
+}
+
+/// A duration of a part that is more than OUTLIER_SIGMAS standard deviations from the mean of its recent durations,
+/// like a regression, or noise of the machine if many days are outliers in the same run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Outlier {
+    pub year: Year,
+    pub day: Day,
+    pub part: Part,
+    /// Duration of the part, in seconds
+    pub seconds: f64,
+    /// Mean and standard deviation of the recent durations, in seconds
+    pub mean: f64,
+    pub deviation: f64,
+    /// Number of recent durations
+    pub runs: usize,
+}
+
+impl Outlier {
+    /// Number of standard deviations that the duration is from the mean, positive if it is slower
+    pub fn sigmas(&self) -> f64 {
+        (self.seconds - self.mean) / self.deviation
+    }
+}
+
+impl Display for Outlier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sigmas = self.sigmas();
+        write!(
+            f,
+            "[Outlier] [{}] [{}] [P{}] {:.2?} is {:.1}σ {} than the mean of {:.2?} over {} runs",
+            self.year,
+            self.day,
+            self.part.number(),
+            Duration::from_secs_f64(self.seconds),
+            sigmas.abs(),
+            if sigmas > 0.0 { "slower" } else { "faster" },
+            Duration::from_secs_f64(self.mean),
+            self.runs
+        )
+    }
+}
+
+/// Timing history of all solutions, keyed by `"{year}-{day:02}"`
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    #[serde(skip)]
+    path: PathBuf,
+    days: BTreeMap<String, Timing>,
+}
+
+fn key(year: Year, day: Day) -> String {
+    format!("{}-{:02}", year, day)
+}
+
+fn seconds(status: &TestStatus) -> Option<f64> {
+    status.duration().map(|duration| duration.as_secs_f64())
+}
+
+impl History {
+    /// Load the History at `path`, a missing or corrupt file results in an empty History
+    pub fn load(path: impl AsRef<Path>) -> History {
+        let path = path.as_ref().to_path_buf();
+        let mut history: History = fs::read_to_string(&path)
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        history.path = path;
+        history
+    }
+
+    /// Write the History back to the path it was loaded from
+    pub fn save(&self) -> AocResult<()> {
+        if let Some(directory) = self.path.parent() {
+            fs::create_dir_all(directory).map_err(AocError::io(directory))?;
+        }
+        let json = serde_json::to_string_pretty(self).expect("A History is always serializable.");
+        fs::write(&self.path, json).map_err(AocError::io(&self.path))
+    }
+
+    pub fn get(&self, year: Year, day: Day) -> Option<&Timing> {
+        self.days.get(&key(year, day))
+    }
+
+    /// Remember the durations of the parts in `test_result`, parts that did not run keep their previous duration
+    pub fn record(&mut self, test_result: &TestResult) {
+        let timing = self
+            .days
+            .entry(key(test_result.year, test_result.day))
+            .or_default();
+        let remember = |recent: &mut Vec<f64>, seconds: f64| {
+            recent.push(seconds);
+            recent.drain(..recent.len().saturating_sub(RECENT_RUNS));
+        };
+        if let Some(seconds) = seconds(&test_result.p1) {
+            timing.part_one = Some(seconds);
+            remember(&mut timing.recent_one, seconds);
+        }
+        if let Some(seconds) = seconds(&test_result.p2) {
+            timing.part_two = Some(seconds);
+            remember(&mut timing.recent_two, seconds);
+        }
+    }
+
+    /// The parts of `test_result` of which the duration is an Outlier versus their recent durations, call this before
+    /// recording `test_result`. Parts with fewer than MIN_RUNS recent durations are never outliers.
+    pub fn outliers(&self, test_result: &TestResult) -> Vec<Outlier> {
+        let Some(timing) = self.get(test_result.year, test_result.day) else {
+            return Vec::new();
+        };
+        [Part::One, Part::Two]
+            .into_iter()
+            .filter_map(|part| {
+                let seconds = seconds(test_result.part(part))?;
+                let recent = timing.recent(part);
+                if recent.len() < MIN_RUNS {
+                    return None;
+                }
+                let mean = recent.iter().sum::<f64>() / recent.len() as f64;
+                let variance = recent
+                    .iter()
+                    .map(|duration| (duration - mean).powi(2))
+                    .sum::<f64>()
+                    / recent.len() as f64;
+                let outlier = Outlier {
+                    year: test_result.year,
+                    day: test_result.day,
+                    part,
+                    seconds,
+                    mean,
+                    deviation: variance.sqrt(),
+                    runs: recent.len(),
+                };
+                (outlier.deviation > 0.0 && outlier.sigmas().abs() > OUTLIER_SIGMAS)
+                    .then_some(outlier)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_record() {
+        let mut history = History::default();
+        history.record(&TestResult {
+            year: 2023,
+            day: 5,
+            parse: None,
+            p1: TestStatus::Success(Duration::from_millis(250), 1.into()),
+            p2: TestStatus::Error(Duration::from_millis(1)),
+            examples: TestStatus::Unknown,
+            p1_memory: None,
+            p2_memory: None,
+        });
+        history.record(&TestResult {
+            year: 2023,
+            day: 5,
+            parse: None,
+            p1: TestStatus::Unknown,
+            p2: TestStatus::Timeout(Duration::from_secs(2)),
+            examples: TestStatus::Unknown,
+            p1_memory: None,
+            p2_memory: None,
+        });
+        let timing = history.get(2023, 5).unwrap().clone();
+        test!(Some(0.25), timing.part_one);
+        test!(Some(2.0), timing.part_two);
+        test!(Some(Duration::from_millis(2250)), timing.total());
+        test!(history.get(2023, 6).is_none());
+        test!(vec![0.25], timing.recent_one);
+    }
+
+    #[test]
+    fn test_outliers() {
+        let test_result = |millis: u64| TestResult {
+            year: 2023,
+            day: 5,
+            parse: None,
+            p1: TestStatus::Success(Duration::from_millis(millis), 1.into()),
+            p2: TestStatus::Unknown,
+            examples: TestStatus::Unknown,
+            p1_memory: None,
+            p2_memory: None,
+        };
+        let mut history = History::default();
+        for millis in [100, 104, 96, 102, 98] {
+            test!(history.outliers(&test_result(200)).is_empty());
+            history.record(&test_result(millis));
+        }
+        test!(history.outliers(&test_result(105)).is_empty());
+        let outliers = history.outliers(&test_result(200));
+        test!(1, outliers.len());
+        test!(Part::One, outliers[0].part);
+        test!(5, outliers[0].runs);
+        test!(outliers[0].sigmas() > 30.0);
+        test!(outliers[0].to_string().contains("slower"));
+        test!(history.outliers(&test_result(50))[0].sigmas() < -OUTLIER_SIGMAS);
+        for _ in 0..RECENT_RUNS {
+            history.record(&test_result(200));
+        }
+        test!(RECENT_RUNS, history.get(2023, 5).unwrap().recent_one.len());
+    }
+}
@@ -0,0 +1,227 @@
+#![allow(dead_code)]
+// This module contains a registry of closures that run at the steps of a day and before and after every part, so
+// extensions like notifications, metrics or answer logging can be added at startup, without changing the runner
+
+use crate::runner::Part;
+use crate::solution::*;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+/// A part that is about to run or just finished, of which the status is in `test_result.part(part)`
+pub struct PartEvent<'a> {
+    pub solution: &'a SolutionBox,
+    pub part: Part,
+    /// The TestResult so far, a part that has not run yet is `TestStatus::Unknown`
+    pub test_result: &'a TestResult,
+}
+
+impl<'a> PartEvent<'a> {
+    pub fn new(
+        solution: &'a SolutionBox,
+        part: Part,
+        test_result: &'a TestResult,
+    ) -> PartEvent<'a> {
+        PartEvent {
+            solution,
+            part,
+            test_result,
+        }
+    }
+}
+
+/// A step of a day that is not about a single part, of which the status so far is in `test_result`
+pub struct DayEvent<'a> {
+    pub solution: &'a SolutionBox,
+    pub test_result: &'a TestResult,
+}
+
+impl<'a> DayEvent<'a> {
+    pub fn new(solution: &'a SolutionBox, test_result: &'a TestResult) -> DayEvent<'a> {
+        DayEvent {
+            solution,
+            test_result,
+        }
+    }
+}
+
+/// A day that could not run because of `error`, like an input that can not be downloaded
+pub struct FailureEvent<'a> {
+    pub solution: &'a SolutionBox,
+    pub error: &'a AocError,
+}
+
+impl<'a> FailureEvent<'a> {
+    pub fn new(solution: &'a SolutionBox, error: &'a AocError) -> FailureEvent<'a> {
+        FailureEvent { solution, error }
+    }
+}
+
+/// A closure that is called with a PartEvent, from the thread that runs the Solution
+pub type Hook = Arc<dyn Fn(&PartEvent) + Send + Sync>;
+
+/// A closure that is called with a DayEvent, from the thread that runs the Solution
+pub type DayHook = Arc<dyn Fn(&DayEvent) + Send + Sync>;
+
+/// A closure that is called with a FailureEvent, from the thread that runs the Solution
+pub type FailureHook = Arc<dyn Fn(&FailureEvent) + Send + Sync>;
+
+/// Hooks that fire after the examples of a day ran, before its puzzle input is obtained, and before and after every
+/// part that runs or is taken from the cache, and when a day can not run, in the order in which they were registered.
+///
+/// Parts do not run when the parse step fails, so no hooks fire for them.
+#[derive(Clone, Default)]
+pub struct Hooks {
+    after_examples: Vec<DayHook>,
+    before_fetch: Vec<DayHook>,
+    before_part: Vec<Hook>,
+    after_part: Vec<Hook>,
+    after_failure: Vec<FailureHook>,
+}
+
+impl Debug for Hooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Hooks")
+            .field("after_examples", &self.after_examples.len())
+            .field("before_fetch", &self.before_fetch.len())
+            .field("before_part", &self.before_part.len())
+            .field("after_part", &self.after_part.len())
+            .field("after_failure", &self.after_failure.len())
+            .finish()
+    }
+}
+
+impl Hooks {
+    /// Call `hook` as soon as the examples of a day ran, the outcome is in `test_result.examples`
+    pub fn after_examples(
+        &mut self,
+        hook: impl Fn(&DayEvent) + Send + Sync + 'static,
+    ) -> &mut Hooks {
+        self.after_examples.push(Arc::new(hook));
+        self
+    }
+
+    /// Call `hook` just before the puzzle input of a day is read from the cache, or downloaded
+    pub fn before_fetch(&mut self, hook: impl Fn(&DayEvent) + Send + Sync + 'static) -> &mut Hooks {
+        self.before_fetch.push(Arc::new(hook));
+        self
+    }
+
+    /// Call `hook` just before a part runs
+    pub fn before_part(&mut self, hook: impl Fn(&PartEvent) + Send + Sync + 'static) -> &mut Hooks {
+        self.before_part.push(Arc::new(hook));
+        self
+    }
+
+    /// Call `hook` as soon as a part finished, when its status is known
+    pub fn after_part(&mut self, hook: impl Fn(&PartEvent) + Send + Sync + 'static) -> &mut Hooks {
+        self.after_part.push(Arc::new(hook));
+        self
+    }
+
+    /// Call `hook` as soon as a day failed to run, its results are lost
+    pub fn after_failure(
+        &mut self,
+        hook: impl Fn(&FailureEvent) + Send + Sync + 'static,
+    ) -> &mut Hooks {
+        self.after_failure.push(Arc::new(hook));
+        self
+    }
+
+    pub fn fire_after_examples(&self, event: &DayEvent) {
+        self.after_examples.iter().for_each(|hook| hook(event));
+    }
+
+    pub fn fire_before_fetch(&self, event: &DayEvent) {
+        self.before_fetch.iter().for_each(|hook| hook(event));
+    }
+
+    pub fn fire_before_part(&self, event: &PartEvent) {
+        self.before_part.iter().for_each(|hook| hook(event));
+    }
+
+    pub fn fire_after_part(&self, event: &PartEvent) {
+        self.after_part.iter().for_each(|hook| hook(event));
+    }
+
+    pub fn fire_after_failure(&self, event: &FailureEvent) {
+        self.after_failure.iter().for_each(|hook| hook(event));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::sync::Mutex;
+
+    struct Day1 {}
+
+    impl Solution for Day1 {
+        no_parse_step!();
+
+        fn solve_part_one(&self, _input: &String, _params: &Params) -> AocResult<Answer> {
+            Ok(0.into())
+        }
+        fn solve_part_two(&self, _input: &String, _params: &Params) -> AocResult<Answer> {
+            Ok(0.into())
+        }
+        fn year(&self) -> Year {
+            2023
+        }
+        fn day(&self) -> Day {
+            1
+        }
+    }
+
+    #[test]
+    fn test_hooks() {
+        let fired = Arc::new(Mutex::new(Vec::new()));
+        let mut hooks = Hooks::default();
+        let (before, after) = (Arc::clone(&fired), Arc::clone(&fired));
+        let (examples, fetch) = (Arc::clone(&fired), Arc::clone(&fired));
+        let failure = Arc::clone(&fired);
+        hooks
+            .after_examples(move |event| {
+                let status = event.test_result.examples.name();
+                examples.lock().unwrap().push((status, Part::One));
+            })
+            .before_fetch(move |_| fetch.lock().unwrap().push(("fetch", Part::One)))
+            .before_part(move |event| before.lock().unwrap().push(("before", event.part)))
+            .after_part(move |event| {
+                let status = event.test_result.part(event.part).name();
+                after.lock().unwrap().push((status, event.part));
+            })
+            .after_failure(move |_| failure.lock().unwrap().push(("failure", Part::One)));
+        let solution: SolutionBox = Arc::new(Day1 {});
+        let mut test_result = TestResult {
+            year: 2023,
+            day: 1,
+            parse: None,
+            p1: TestStatus::Unknown,
+            p2: TestStatus::Unknown,
+            examples: TestStatus::Unknown,
+            p1_memory: None,
+            p2_memory: None,
+        };
+        test_result.examples = TestStatus::Failed(Duration::ZERO, 0.into());
+        hooks.fire_after_examples(&DayEvent::new(&solution, &test_result));
+        hooks.fire_before_fetch(&DayEvent::new(&solution, &test_result));
+        hooks.fire_before_part(&PartEvent::new(&solution, Part::Two, &test_result));
+        test_result.p2 = TestStatus::Cached(Answer::Int(1));
+        hooks.fire_after_part(&PartEvent::new(&solution, Part::Two, &test_result));
+        let error = AocError::MissingSession {
+            path: "cookie".into(),
+        };
+        hooks.fire_after_failure(&FailureEvent::new(&solution, &error));
+        test!(
+            vec![
+                ("Failed", Part::One),
+                ("fetch", Part::One),
+                ("before", Part::Two),
+                ("Cached", Part::Two),
+                ("failure", Part::One)
+            ],
+            *fired.lock().unwrap()
+        );
+    }
+}
@@ -0,0 +1,117 @@
+// This module contains a string Interner, so graphs with labelled nodes (like "AAA", module names or
+// valve IDs) can index a Vec by Symbol, instead of hashing and comparing Strings in their inner loops
+
+use crate::hash::FastHashMap;
+use std::fmt::Debug;
+use std::ops::Index;
+
+/// Small integer ID of an interned label, Symbols are handed out as 0, 1, 2, ... in order of interning
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Index of this Symbol, to index a Vec or BitSet with one item per label
+    pub fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl Debug for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Symbol({})", self.0)
+    }
+}
+
+/// Two-way mapping between labels and Symbols
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    symbols: FastHashMap<String, Symbol>,
+    labels: Vec<String>,
+}
+
+impl Interner {
+    pub fn new() -> Interner {
+        Interner::default()
+    }
+
+    /// Number of interned labels
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// The Symbol of `label`, which is interned if it was not yet
+    pub fn intern(&mut self, label: &str) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(label) {
+            return symbol;
+        }
+        let symbol = Symbol(self.labels.len() as u32);
+        self.symbols.insert(label.to_string(), symbol);
+        self.labels.push(label.to_string());
+        symbol
+    }
+
+    /// The Symbol of `label`, if it was interned
+    pub fn get(&self, label: &str) -> Option<Symbol> {
+        self.symbols.get(label).copied()
+    }
+
+    /// The label of `symbol`
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        &self.labels[symbol.index()]
+    }
+
+    /// All Symbols and their labels, in order of interning
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &str)> {
+        self.labels
+            .iter()
+            .enumerate()
+            .map(|(i, label)| (Symbol(i as u32), label.as_str()))
+    }
+}
+
+impl Index<Symbol> for Interner {
+    type Output = str;
+
+    fn index(&self, symbol: Symbol) -> &Self::Output {
+        self.resolve(symbol)
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Interner {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut interner = Interner::new();
+        for label in iter {
+            interner.intern(label);
+        }
+        interner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_interner() {
+        let mut interner = Interner::new();
+        let aaa = interner.intern("AAA");
+        let bbb = interner.intern("BBB");
+        test!(aaa, interner.intern("AAA"));
+        test!(0, aaa.index());
+        test!(1, bbb.index());
+        test!(2, interner.len());
+        test!(Some(bbb), interner.get("BBB"));
+        test!(interner.get("ZZZ").is_none());
+        test!("AAA", interner.resolve(aaa));
+        test!("BBB", &interner[bbb]);
+        let labels: Vec<&str> = interner.iter().map(|(_, label)| label).collect();
+        test!(vec!["AAA", "BBB"], labels);
+        let collected: Interner = ["broadcaster", "a", "b", "a"].into_iter().collect();
+        test!(3, collected.len());
+    }
+}
@@ -0,0 +1,387 @@
+//! The framework of the Advent of Code solutions: the `Solution` trait and the runner, fetching and caching the
+//! puzzle input, and the data structures that solutions share. It does not depend on any solution, so it can be
+//! reused with other solutions.
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::fs::{self, read_to_string};
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+pub mod algorithms;
+pub mod answers;
+pub mod arena;
+pub mod arrangements;
+pub mod bit_set;
+pub mod byte_grid;
+pub mod cache;
+pub mod counter;
+pub mod default_map;
+pub mod diff;
+pub mod differential;
+pub mod error;
+pub mod frames;
+pub mod geometry;
+pub mod golden;
+pub mod grid;
+pub mod hash;
+pub mod history;
+pub mod hooks;
+pub mod interner;
+mod macros;
+pub mod md5;
+pub mod memory;
+pub mod notebook;
+pub mod ocr;
+pub mod output;
+pub mod parallel;
+pub mod path_finding;
+pub mod patterns;
+pub mod polygon;
+pub mod priority_queue;
+pub mod range_query;
+pub mod regions;
+pub mod registry;
+pub mod render;
+pub mod repl;
+pub mod rolling_hash;
+pub mod runner;
+pub mod scaling;
+pub mod search;
+pub mod shard;
+pub mod signals;
+pub mod simulation;
+pub mod snapshots;
+pub mod solution;
+pub mod sparse;
+pub mod spatial;
+pub mod strings;
+pub mod symmetry;
+#[cfg(test)]
+mod testing;
+pub mod trie;
+pub mod viz;
+pub mod wrapping;
+
+pub use arena::{Arena, NodeId};
+pub use bit_set::{BitSet, FixedBitSet, GridMask};
+pub use byte_grid::ByteGrid;
+pub use counter::Counter;
+pub use default_map::DefaultMap;
+pub use error::{AocError, AocResult};
+pub use hash::{FastHashMap, FastHashSet};
+pub use interner::{Interner, Symbol};
+pub use parallel::ParLines;
+pub use priority_queue::{MinHeap, PriorityQueue};
+pub use range_query::{Fenwick, SegmentTree, SummedAreaTable};
+pub use render::WriteGrid;
+pub use rolling_hash::RollingHash;
+pub use solution::Parse;
+pub use strings::StrExt;
+pub use trie::Trie;
+
+/// Default Integer type
+pub type Int = i64;
+
+/// Default Grid type
+pub type Grid<T> = Vec<Vec<T>>;
+
+/// Default Queue type
+pub type Queue<T> = VecDeque<T>;
+
+/// Default path of the adventofcode.com session cookie, used to download puzzle input
+pub const SESSION_COOKIE_PATH: &str = ".aocf/cookie";
+
+/// Default directory of the cached puzzle inputs
+pub const INPUT_CACHE_DIRECTORY: &str = ".aocf/cache";
+
+/// Where the session cookie and the cached puzzle inputs are stored, the paths of aocf by default
+#[derive(Clone, PartialEq, Eq)]
+pub struct Inputs {
+    pub session_path: PathBuf,
+    /// Session cookie that is used instead of the one stored at `session_path`, e.g. in a notebook
+    pub session: Option<String>,
+    pub cache_directory: PathBuf,
+}
+
+impl Default for Inputs {
+    fn default() -> Self {
+        Inputs {
+            session_path: SESSION_COOKIE_PATH.into(),
+            session: None,
+            cache_directory: INPUT_CACHE_DIRECTORY.into(),
+        }
+    }
+}
+
+impl Debug for Inputs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Inputs")
+            .field("session_path", &self.session_path)
+            .field("session", &self.session.as_ref().map(|_| "<hidden>"))
+            .field("cache_directory", &self.cache_directory)
+            .finish()
+    }
+}
+
+impl Inputs {
+    /// Download puzzle input with the session cookie `session`, instead of the one stored at `session_path`
+    pub fn with_session(self, session: impl Into<String>) -> Inputs {
+        Inputs {
+            session: Some(session.into()),
+            ..self
+        }
+    }
+
+    /// The adventofcode.com session cookie, `session` if it is given, or the one stored at `session_path`
+    pub fn session_cookie(&self) -> AocResult<String> {
+        let missing_session = || AocError::MissingSession {
+            path: self.session_path.clone(),
+        };
+        let cookie = match &self.session {
+            Some(session) => session.clone(),
+            None => read_to_string(&self.session_path).map_err(|_| missing_session())?,
+        };
+        match cookie.trim() {
+            "" => Err(missing_session()),
+            cookie => Ok(cookie.to_string()),
+        }
+    }
+
+    /// Path of the cached puzzle input of AoC(`year`, `day`), in the JSON format of aocf
+    pub fn cache_path(&self, year: i32, day: u32) -> PathBuf {
+        self.cache_directory
+            .join(format!("aoc{}_{:02}.json", year, day))
+    }
+
+    /// Store `input` as the puzzle input of AoC(`year`, `day`) in the cache, so `get` does not download it.
+    ///
+    /// An existing cache file for that day is replaced, including the puzzle description that aocf stored in it.
+    pub fn store(&self, year: i32, day: u32, input: &str) -> AocResult<()> {
+        fs::create_dir_all(&self.cache_directory).map_err(AocError::io(&self.cache_directory))?;
+        let path = self.cache_path(year, day);
+        fs::write(&path, input_cache_json(year, day, input)).map_err(AocError::io(&path))
+    }
+
+    /// The cached puzzle input of AoC(`year`, `day`), None if it is not cached. Never downloads anything.
+    pub fn cached(&self, year: i32, day: u32) -> Option<String> {
+        let aoc_json_cache_path = self.cache_path(year, day);
+        aocf::Aoc::load_json_from(&aoc_json_cache_path).ok()?;
+        let json = read_to_string(&aoc_json_cache_path).ok()?;
+        let json_struct: Value = serde_json::from_str(&json).ok()?;
+        match &json_struct["input"] {
+            Value::String(s) if is_login_page(s) => {
+                debug!(
+                    target: "input",
+                    "AoC json file contains a login page instead of input: '{}'.",
+                    aoc_json_cache_path.display()
+                );
+            }
+            Value::String(s) => return Some(s.clone()),
+            _ => {
+                debug!(
+                    target: "input",
+                    "AoC json file does not contain input field: '{}'.",
+                    aoc_json_cache_path.display()
+                );
+            }
+        };
+        None
+    }
+
+    /// Get the puzzle input of AoC(`year`, `day`), from the cache if possible, downloading it otherwise
+    pub fn get(&self, year: i32, day: u32) -> AocResult<String> {
+        if let Some(input) = self.cached(year, day) {
+            return Ok(input);
+        }
+        let aoc_json_cache_path = self.cache_path(year, day);
+        debug!(
+            target: "input",
+            "Not a valid AoC json file: '{}'.",
+            aoc_json_cache_path.display()
+        );
+        let cookie = self.session_cookie()?;
+        debug!(target: "input", "Downloading json file from adventofcode.com.");
+        let input = aocf::Aoc::new()
+            .year(Some(year))
+            .day(Some(day))
+            .cookie(&cookie)
+            .cache::<&Path>(Some(aoc_json_cache_path.as_path()))
+            .init()
+            .map_err(|error| self.download_error(year, day, error.to_string()))?
+            .get_input(true)
+            .map_err(|error| self.download_error(year, day, error.to_string()))?;
+        if is_login_page(&input) {
+            // aocf already cached the login page, which must not be mistaken for the input on the next run
+            forget_input(&aoc_json_cache_path);
+            return Err(self.session_expired());
+        }
+        Ok(input)
+    }
+
+    /// Title of the puzzle of AoC(`year`, `day`), if aocf stored it in the cache, e.g. `Parabolic Reflector Dish`.
+    /// Never downloads anything.
+    pub fn title(&self, year: i32, day: u32) -> Option<String> {
+        let json = read_to_string(self.cache_path(year, day)).ok()?;
+        let json: Value = serde_json::from_str(&json).ok()?;
+        json["title"].as_str().map(str::to_string)
+    }
+
+    /// Number of stars collected on AoC(`year`, `day`), if aocf stored it in the cache. Never downloads anything.
+    pub fn stars(&self, year: i32, day: u32) -> Option<u8> {
+        let json = read_to_string(self.cache_path(year, day)).ok()?;
+        let json: Value = serde_json::from_str(&json).ok()?;
+        match &json["stars"] {
+            Value::Number(stars) => stars.as_u64().map(|stars| stars as u8),
+            Value::String(stars) => stars.parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Year and day of every file in the cache, sorted
+    pub fn cached_days(&self) -> Vec<(i32, u32)> {
+        let Ok(entries) = fs::read_dir(&self.cache_directory) else {
+            return Vec::new();
+        };
+        let mut days: Vec<(i32, u32)> = entries
+            .filter_map(|entry| {
+                let name = entry.ok()?.file_name().into_string().ok()?;
+                let (year, day) = name
+                    .strip_prefix("aoc")?
+                    .strip_suffix(".json")?
+                    .split_once('_')?;
+                Some((year.parse().ok()?, day.parse().ok()?))
+            })
+            .collect();
+        days.sort();
+        days
+    }
+
+    /// Get the puzzle input of AoC(`year`, `day`) like `get`, and parse it, e.g. into a ByteGrid
+    pub fn parse<T: Parse>(&self, year: i32, day: u32) -> AocResult<T> {
+        self.get(year, day).map(|input| T::parse(&input))
+    }
+
+    fn session_expired(&self) -> AocError {
+        AocError::SessionExpired {
+            path: self.session_path.clone(),
+        }
+    }
+
+    /// Error of a failed download, adventofcode.com answers with status 400 when the session cookie expired
+    fn download_error(&self, year: i32, day: u32, message: String) -> AocError {
+        match message.contains("status code 400") || message.contains("status code 401") {
+            true => self.session_expired(),
+            false => AocError::Network { year, day, message },
+        }
+    }
+}
+
+/// A cache file of aocf, with nothing but the puzzle input
+fn input_cache_json(year: i32, day: u32, input: &str) -> String {
+    let json = serde_json::json!({
+        "year": year,
+        "day": day,
+        "level": "first",
+        "title": null,
+        "stars": null,
+        "solution": {},
+        "input": input,
+        "brief": {},
+    });
+    serde_json::to_string_pretty(&json).expect("A JSON Value is always serializable.")
+}
+
+/// Returns true iff `input` is the page that adventofcode.com returns instead of the puzzle input,
+/// when it does not accept the session cookie
+fn is_login_page(input: &str) -> bool {
+    let start = input.trim_start();
+    start.starts_with("<!DOCTYPE html")
+        || start.starts_with("<html")
+        || input.contains("Please log in to get your puzzle input")
+}
+
+/// Remove the input from the cache file at `path`, but keep the other data that aocf stored in it
+fn forget_input(path: &Path) {
+    let forgotten = read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str::<Value>(&json).ok())
+        .and_then(|mut json| {
+            json["input"] = Value::Null;
+            fs::write(path, serde_json::to_string_pretty(&json).ok()?).ok()
+        });
+    if forgotten.is_none() {
+        debug!(target: "input", "Failed to remove the input from '{}'.", path.display());
+    }
+}
+
+// Return the greatest common multiple of a and b
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Return the least common multiple of a and b
+pub fn lcm(a: u64, b: u64) -> u64 {
+    a * b / gcd(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_cache_json() {
+        let json = input_cache_json(2023, 5, "seeds: 79 14\n");
+        let aoc = aocf::Aoc::from_json(&json).unwrap();
+        test!(Some(2023), aoc.year);
+        test!(Some(5), aoc.day);
+        let value: Value = serde_json::from_str(&json).unwrap();
+        test!("seeds: 79 14\n", value["input"].as_str().unwrap());
+    }
+
+    #[test]
+    fn test_cached_metadata() {
+        let directory = std::env::temp_dir().join(format!("aoc-cached-{}", std::process::id()));
+        let inputs = Inputs {
+            cache_directory: directory.clone(),
+            ..Inputs::default()
+        };
+        inputs.store(2023, 5, "seeds: 79 14\n").unwrap();
+        test!(inputs.title(2023, 5).is_none());
+        let json =
+            input_cache_json(2023, 6, "").replace("\"title\": null", "\"title\": \"Wait For It\"");
+        fs::write(inputs.cache_path(2023, 6), json).unwrap();
+        test!(Some("Wait For It".to_string()), inputs.title(2023, 6));
+        test!(inputs.title(2023, 7).is_none());
+        test!(inputs.stars(2023, 5).is_none());
+        test!(Some("seeds: 79 14\n".to_string()), inputs.cached(2023, 5));
+        test!(vec![(2023, 5), (2023, 6)], inputs.cached_days());
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_with_session() {
+        let inputs = Inputs::default().with_session(" 53616c74\n");
+        test!("53616c74", inputs.session_cookie().unwrap());
+        test!(!format!("{:?}", inputs).contains("53616c74"));
+    }
+
+    #[test]
+    fn test_session_expired() {
+        test!(is_login_page("<!DOCTYPE html>\n<html lang=\"en-us\">"));
+        test!(is_login_page(
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+        ));
+        test!(!is_login_page("px{a<2006:qkq,m>2090:A,rfg}\n"));
+        let inputs = Inputs::default();
+        let expired = inputs.download_error(2023, 1, "https://...: status code 400".to_string());
+        test!(matches!(expired, AocError::SessionExpired { .. }));
+        let network = inputs.download_error(2023, 1, "Connection refused".to_string());
+        test!(matches!(network, AocError::Network { .. }));
+    }
+}
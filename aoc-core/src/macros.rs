@@ -50,35 +50,72 @@ macro_rules! test {
 
 }
 
+/// Assert that the Display of `actual` is the content of a golden file, or rewrite it with `AOC_UPDATE_GOLDEN=1`.
+///
+/// ```ignore
+/// assert_matches_golden!("y2023/d14/after_3_cycles.txt", platform.grid);
+/// ```
+///
+/// The path is relative to `tests/golden` in the root of the crate that calls it.
+#[macro_export]
+macro_rules! assert_matches_golden {
+    ($path:expr, $actual:expr) => {
+        $crate::golden::assert_matches(env!("CARGO_MANIFEST_DIR"), $path, &$actual.to_string())
+    };
+}
+
+/// Log a message through `tracing`, at a level and with a target.
+///
+/// ```ignore
+/// debug!(level: TRACE, "Beam at {:?}.", position);
+/// debug!(target: "beams", level: TRACE, "Beam at {:?}.", position);
+/// debug!(params.is_example(), "{:#?}", grid);
+/// ```
+///
+/// A target is appended to the module path of the caller, so `AOC_LOG=y2023::d16::beams=trace` selects it.
+/// The level defaults to DEBUG, and the older form with a condition logs at DEBUG if the condition holds.
+/// The message is only shown when its level is enabled for its target, using `-v`, `-vv` or `AOC_LOG`.
+///
+/// Messages compile to nothing in release builds, unless the `debug-log` feature is enabled.
 #[macro_export]
 macro_rules! debug {
-    // Match arm with format string parameter
+    (target: $target:literal, level: $level:ident, $($arg:tt)+) => {
+        if cfg!(any(debug_assertions, feature = "debug-log")) {
+            ::tracing::event!(
+                target: concat!(module_path!(), "::", $target),
+                ::tracing::Level::$level,
+                $($arg)+
+            );
+        }
+    };
+
+    (target: $target:literal, $($arg:tt)+) => {
+        $crate::debug!(target: $target, level: DEBUG, $($arg)+)
+    };
+
+    (level: $level:ident, $($arg:tt)+) => {
+        if cfg!(any(debug_assertions, feature = "debug-log")) {
+            ::tracing::event!(::tracing::Level::$level, $($arg)+);
+        }
+    };
+
+    // Match arm with a condition and a format string parameter
     ($should_print:expr, $fmt:expr, $($arg:tt)*) => {
         if $should_print {
-            let message = format!($fmt, $($arg)*);
-            debug!(true, message);
+            $crate::debug!(level: DEBUG, $fmt, $($arg)*);
         }
     };
 
     // Match arm with a condition and a single message
     ($should_print:ident, $message:expr) => {
         if $should_print {
-            println!("[{}:{}] {:#?}", file!(), line!(), $message);
+            $crate::debug!(level: DEBUG, "{:#?}", $message);
         }
     };
 
     (true, $message:expr) => {
-        println!("[{}:{}] {}", file!(), line!(), $message);
+        $crate::debug!(level: DEBUG, "{}", $message);
     };
-
-    (false, $fmt:expr, $($arg:tt)*) => {
-        // Do nothing
-    };
-
-    (false, $message:expr) => {
-        // Do nothing
-    };
-
 }
 
 // See: https://chatgpt.com/share/d866e424-9d25-441f-a232-bf78c8372d7c
@@ -0,0 +1,121 @@
+// This module contains the MD5 searches of the older years, like the lowest suffix of which the hash starts with
+// zeros (2015-04, 2016-05) and the key stretching of 2016-14, the hashes are computed in parallel in batches
+
+use rayon::prelude::*;
+use std::ops::Range;
+
+/// MD5 digest of 16 bytes
+pub type Digest = [u8; 16];
+
+/// Number of suffixes that are hashed in parallel before checking whether one of them matched
+const BATCH: u64 = 1 << 14;
+
+/// MD5 digest of `input`
+pub fn digest(input: &str) -> Digest {
+    ::md5::compute(input).0
+}
+
+/// MD5 hash of `input` in lowercase hexadecimal
+pub fn hash(input: &str) -> String {
+    hex(&digest(input))
+}
+
+/// `digest` in lowercase hexadecimal
+pub fn hex(digest: &Digest) -> String {
+    digest.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// The hash of `input`, hashed again as hexadecimal `rounds` more times, the key stretching of 2016-14
+pub fn stretched(input: &str, rounds: usize) -> String {
+    (0..rounds).fold(hash(input), |hash, _| self::hash(&hash))
+}
+
+/// Number of hexadecimal zeros that `digest` starts with
+pub fn leading_zeros(digest: &Digest) -> usize {
+    digest
+        .iter()
+        .flat_map(|byte| [byte >> 4, byte & 0xf])
+        .take_while(|&nibble| nibble == 0)
+        .count()
+}
+
+/// MD5 digest of `prefix` followed by `suffix` in decimal, which hashes the prefix only once
+fn digest_suffix(prefix: &::md5::Context, suffix: u64) -> Digest {
+    let mut context = prefix.clone();
+    context.consume(suffix.to_string());
+    context.finalize().0
+}
+
+/// The lowest suffix from `start` of which the digest of `prefix` followed by the suffix in decimal satisfies
+/// `predicate`, and that digest. Never returns if no suffix does.
+pub fn find(prefix: &str, start: u64, predicate: impl Fn(&Digest) -> bool + Sync) -> (u64, Digest) {
+    let mut context = ::md5::Context::new();
+    context.consume(prefix);
+    (start..)
+        .step_by(BATCH as usize)
+        .find_map(|batch| {
+            (batch..batch + BATCH)
+                .into_par_iter()
+                .map(|suffix| (suffix, digest_suffix(&context, suffix)))
+                .find_first(|(_, digest)| predicate(digest))
+        })
+        .unwrap()
+}
+
+/// The lowest suffix from `start` of which the hash of `prefix` followed by the suffix starts with `zeros` zeros,
+/// like the AdventCoins of 2015-04
+pub fn find_zeros(prefix: &str, zeros: usize, start: u64) -> u64 {
+    find(prefix, start, |digest| leading_zeros(digest) >= zeros).0
+}
+
+/// Every suffix in ascending order of which the hash of `prefix` followed by the suffix starts with `zeros` zeros,
+/// and its digest, like the door password of 2016-05
+pub fn zeros(prefix: &str, zeros: usize) -> impl Iterator<Item = (u64, Digest)> + '_ {
+    let mut start = 0;
+    std::iter::from_fn(move || {
+        let (suffix, digest) = find(prefix, start, |digest| leading_zeros(digest) >= zeros);
+        start = suffix + 1;
+        Some((suffix, digest))
+    })
+}
+
+/// The hashes of `salt` followed by every index in `indices`, stretched by `rounds`, computed in parallel, to fill
+/// the lookahead of 2016-14
+pub fn hashes(salt: &str, indices: Range<u64>, rounds: usize) -> Vec<String> {
+    indices
+        .into_par_iter()
+        .map(|index| stretched(&format!("{}{}", salt, index), rounds))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_hash() {
+        test!("d41d8cd98f00b204e9800998ecf8427e", hash(""));
+        test!(5, leading_zeros(&digest("abcdef609043")));
+        test!(0, leading_zeros(&digest("")));
+        // 2016-14
+        test!("577571be4de9dcce85a041ba0410f29f", hash("abc0"));
+        test!("a107ff634856bb300138cac6568c0f24", stretched("abc0", 2016));
+        test!(vec![hash("abc0"), hash("abc1")], hashes("abc", 0..2, 0));
+    }
+
+    #[test]
+    fn test_find() {
+        // 2015-04, with fewer zeros than the real puzzle, so it is quick in debug builds
+        let suffix = find_zeros("abcdef", 3, 0);
+        test!(leading_zeros(&digest(&format!("abcdef{}", suffix))) >= 3);
+        test!((0..suffix).all(|n| leading_zeros(&digest(&format!("abcdef{}", n))) < 3));
+        let first: Vec<u64> = zeros("abcdef", 3)
+            .take(2)
+            .map(|(suffix, _)| suffix)
+            .collect();
+        test!(suffix, first[0]);
+        test!(first[1] > first[0]);
+        test!(first[1], find_zeros("abcdef", 3, suffix + 1));
+    }
+}
@@ -0,0 +1,132 @@
+// This module counts the heap memory in use and its high-water mark, with an allocator that wraps the system
+// allocator, so memory-hungry days show up next to slow ones. The binary only makes it the global allocator with its
+// `track-memory` feature, counting every allocation is not free.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Bytes of heap memory in use
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+/// Highest number of bytes in use since the start of the last `measure`
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+/// Whether the TrackingAllocator allocated anything, which only happens if it is the global allocator
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// The system allocator, which counts the bytes in use. Memory is only measured in a binary that makes it the
+/// global allocator:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: aoc::memory::TrackingAllocator = aoc::memory::TrackingAllocator;
+/// ```
+pub struct TrackingAllocator;
+
+impl TrackingAllocator {
+    fn allocated(size: usize) {
+        if !ENABLED.load(Ordering::Relaxed) {
+            ENABLED.store(true, Ordering::Relaxed);
+        }
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    fn deallocated(size: usize) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            Self::allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            Self::allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        Self::deallocated(layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            Self::deallocated(layout.size());
+            Self::allocated(new_size);
+        }
+        new_pointer
+    }
+}
+
+/// Returns true iff the TrackingAllocator is the global allocator, so memory is measured
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Run `f`, and return its result with the peak number of bytes that were in use while it ran, above the bytes that
+/// were in use when it started. None if memory is not tracked.
+///
+/// The counts are shared by all threads, so this includes the allocations of anything that runs at the same time.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<usize>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+    let start = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(start, Ordering::Relaxed);
+    let result = f();
+    let peak = PEAK.load(Ordering::Relaxed);
+    (result, Some(peak.saturating_sub(start)))
+}
+
+/// Format a number of bytes with a binary unit, e.g. `1.50 MiB`
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    match unit {
+        0 => format!("{} B", bytes),
+        _ => format!("{:.2} {}", size, UNITS[unit]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_format_bytes() {
+        test!("512 B", format_bytes(512));
+        test!("1.50 KiB", format_bytes(1536));
+        test!("3.00 MiB", format_bytes(3 * 1024 * 1024));
+        test!("2048.00 GiB", format_bytes(2048 * 1024 * 1024 * 1024));
+    }
+
+    #[test]
+    fn test_measure() {
+        let (sum, peak) = measure(|| {
+            vec![1u8; 1 << 20]
+                .iter()
+                .map(|&b| b as usize)
+                .sum::<usize>()
+        });
+        test!(1 << 20, sum);
+        test!(match is_enabled() {
+            true => peak.is_some_and(|peak| peak >= 1 << 20),
+            false => peak.is_none(),
+        });
+    }
+}
@@ -0,0 +1,65 @@
+// This module renders the data structures as rich output in evcxr notebooks, which call the `evcxr_display` method
+// of the value of a cell if it has one, so a grid shows up as a monospaced block instead of a Debug dump
+
+use crate::grid::Map;
+use crate::path_finding::Graph;
+use crate::{ByteGrid, GridMask};
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+/// `value` as an HTML block of monospaced text
+fn html(value: &impl Display) -> String {
+    let text = value
+        .to_string()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    format!("<pre style=\"line-height: 1.1\">{}</pre>", text.trim_end())
+}
+
+/// Print `value` in the format that evcxr shows as the output of a cell
+fn evcxr_display(value: &impl Display) {
+    println!(
+        "EVCXR_BEGIN_CONTENT text/html\n{}\nEVCXR_END_CONTENT",
+        html(value)
+    );
+}
+
+impl ByteGrid {
+    pub fn evcxr_display(&self) {
+        evcxr_display(self);
+    }
+}
+
+impl GridMask {
+    pub fn evcxr_display(&self) {
+        evcxr_display(self);
+    }
+}
+
+impl<T: Display> Map<T> {
+    pub fn evcxr_display(&self) {
+        evcxr_display(self);
+    }
+}
+
+impl<T: Hash + Eq + Clone + Debug + Display> Graph<T> {
+    pub fn evcxr_display(&self) {
+        evcxr_display(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_html() {
+        let grid = ByteGrid::from("#.<\n.#>\n");
+        test!(
+            "<pre style=\"line-height: 1.1\">#.&lt;\n.#&gt;</pre>",
+            html(&grid)
+        );
+    }
+}
@@ -0,0 +1,144 @@
+#![allow(dead_code)]
+// This module contains an OCR for the ASCII-art letters that Advent of Code uses in some answers,
+// letters are 4 pixels wide, or 5 for Y, and 6 pixels high, followed by a single column of spacing
+
+use crate::Grid;
+
+const LETTER_WIDTH: usize = 4;
+const LETTER_HEIGHT: usize = 6;
+const LETTER_SPACING: usize = 1;
+
+/// Known Advent of Code letters, rows joined by '\n', of which the width is that of their rows
+const ALPHABET: [(&str, char); 18] = [
+    (".##.\n#..#\n#..#\n####\n#..#\n#..#", 'A'),
+    ("###.\n#..#\n###.\n#..#\n#..#\n###.", 'B'),
+    (".##.\n#..#\n#...\n#...\n#..#\n.##.", 'C'),
+    ("####\n#...\n###.\n#...\n#...\n####", 'E'),
+    ("####\n#...\n###.\n#...\n#...\n#...", 'F'),
+    (".##.\n#..#\n#...\n#.##\n#..#\n.###", 'G'),
+    ("#..#\n#..#\n####\n#..#\n#..#\n#..#", 'H'),
+    (".###\n..#.\n..#.\n..#.\n..#.\n.###", 'I'),
+    ("..##\n...#\n...#\n...#\n#..#\n.##.", 'J'),
+    ("#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#", 'K'),
+    ("#...\n#...\n#...\n#...\n#...\n####", 'L'),
+    (".##.\n#..#\n#..#\n#..#\n#..#\n.##.", 'O'),
+    ("###.\n#..#\n#..#\n###.\n#...\n#...", 'P'),
+    ("###.\n#..#\n#..#\n###.\n#.#.\n#..#", 'R'),
+    (".###\n#...\n#...\n.##.\n...#\n###.", 'S'),
+    ("#..#\n#..#\n#..#\n#..#\n#..#\n.##.", 'U'),
+    ("#...#\n#...#\n.#.#.\n..#..\n..#..\n..#..", 'Y'),
+    ("####\n...#\n..#.\n.#..\n#...\n####", 'Z'),
+];
+
+/// Character used for unknown letters
+pub const UNKNOWN_LETTER: char = '?';
+
+/// Returns true iff `c` is a lit pixel, Advent of Code renders those as '#' or '█'
+fn is_lit(c: char) -> bool {
+    c == '#' || c == '█'
+}
+
+/// The letter of which the pixels start at column `x` of `grid`, and its width, None if it is unknown
+fn recognize_letter(grid: &Grid<bool>, x: usize) -> Option<(char, usize)> {
+    let lit = |row: &Vec<bool>, x: usize| row.get(x).copied().unwrap_or(false);
+    ALPHABET
+        .iter()
+        .find(|(letter, _)| {
+            letter.lines().zip(grid).all(|(pixels, row)| {
+                pixels
+                    .chars()
+                    .enumerate()
+                    .all(|(i, pixel)| (pixel == '#') == lit(row, x + i))
+            })
+        })
+        .map(|(letter, c)| (*c, letter.lines().next().unwrap().len()))
+}
+
+/// Convert a `Grid<bool>` of lit pixels into the letters it spells out.
+///
+/// Unknown letters are returned as `UNKNOWN_LETTER`, and assumed to be `LETTER_WIDTH` pixels wide.
+pub fn ocr_grid(grid: &Grid<bool>) -> String {
+    assert!(
+        grid.len() == LETTER_HEIGHT,
+        "Invalid OCR grid height: {}, expected {}.",
+        grid.len(),
+        LETTER_HEIGHT
+    );
+    let columns = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < columns {
+        let (letter, width) = recognize_letter(grid, x).unwrap_or((UNKNOWN_LETTER, LETTER_WIDTH));
+        letters.push(letter);
+        x += width + LETTER_SPACING;
+    }
+    letters
+}
+
+/// Convert a rendered string of lit ('#' or '█') and unlit pixels into the letters it spells out.
+///
+/// Leading and trailing empty lines are ignored.
+pub fn ocr(image: &str) -> String {
+    let grid: Grid<bool> = image
+        .lines()
+        .skip_while(|line| line.trim().is_empty())
+        .take(LETTER_HEIGHT)
+        .map(|line| line.chars().map(is_lit).collect())
+        .collect();
+    ocr_grid(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_ocr() {
+        let image = "
+#..#.####.#....#.....##.
+#..#.#....#....#....#..#
+####.###..#....#....#..#
+#..#.#....#....#....#..#
+#..#.#....#....#....#..#
+#..#.####.####.####..##.
+";
+        test!("HELLO", ocr(image));
+        test!("HELLO", ocr(&image.replace('#', "█").replace('.', " ")));
+    }
+
+    #[test]
+    fn test_ocr_grid() {
+        let grid: Grid<bool> = [
+            "###..###.",
+            "#..#.#..#",
+            "###..#..#",
+            "#..#.###.",
+            "#..#.#...",
+            "###..#...",
+        ]
+        .iter()
+        .map(|row| row.chars().map(is_lit).collect())
+        .collect();
+        test!("BP", ocr_grid(&grid));
+    }
+
+    #[test]
+    fn test_wide_letter() {
+        let image = "
+#..#.####.#...#.###.
+#..#.#....#...#.#..#
+####.###...#.#..#..#
+#..#.#......#...###.
+#..#.#......#...#...
+#..#.####...#...#...
+";
+        test!("HEYP", ocr(image));
+    }
+
+    #[test]
+    fn test_unknown_letter() {
+        let image = "####\n####\n####\n####\n####\n####";
+        test!(UNKNOWN_LETTER.to_string(), ocr(image));
+    }
+}
@@ -0,0 +1,88 @@
+// This module contains the directory that a debugging aid like `--dump-frames`, `--snapshots` or `--viz` writes to,
+// which is set once from the command line, and of which every subdirectory is cleared once per process
+
+use crate::solution::Params;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// Directory that a debugging aid writes to, nothing is written if it is not set
+pub struct OutputDirectory {
+    root: OnceLock<PathBuf>,
+    /// Subdirectories that were cleared by this process
+    cleared: Mutex<Vec<PathBuf>>,
+}
+
+impl OutputDirectory {
+    pub const fn new() -> OutputDirectory {
+        OutputDirectory {
+            root: OnceLock::new(),
+            cleared: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Write to `directory`, can only be set once
+    pub fn init(&self, directory: impl AsRef<Path>) {
+        let _ = self.root.set(directory.as_ref().to_path_buf());
+    }
+
+    /// Returns true iff the directory is set
+    pub fn is_set(&self) -> bool {
+        self.root.get().is_some()
+    }
+
+    /// Path of `name` in the directory, with an `-example` suffix for examples, None if the directory is not set
+    pub fn path(&self, params: &Params, name: &str) -> Option<PathBuf> {
+        let suffix = if params.is_example() { "-example" } else { "" };
+        Some(self.root.get()?.join(format!("{}{}", name, suffix)))
+    }
+
+    /// Create the subdirectory `name` like `path`, and remove what an earlier run left in it if `clear` is true.
+    ///
+    /// A subdirectory is cleared the first time only, so a simulation that runs more than once in a process, e.g.
+    /// in a benchmark, does not remove what the other runs wrote. None if the directory is not set, or creating the
+    /// subdirectory failed.
+    pub fn subdirectory(&self, params: &Params, name: &str, clear: bool) -> Option<PathBuf> {
+        let directory = self.path(params, name)?;
+        let mut cleared = self.cleared.lock().unwrap();
+        if clear && !cleared.contains(&directory) {
+            let _ = fs::remove_dir_all(&directory);
+            cleared.push(directory.clone());
+        }
+        match fs::create_dir_all(&directory) {
+            Ok(()) => Some(directory),
+            Err(error) => {
+                tracing::warn!("Not writing to '{}': {}.", directory.display(), error);
+                None
+            }
+        }
+    }
+}
+
+impl Default for OutputDirectory {
+    fn default() -> Self {
+        OutputDirectory::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_subdirectory() {
+        let root = std::env::temp_dir().join(format!("aoc-output-{}", std::process::id()));
+        let output = OutputDirectory::new();
+        test!(output.path(&Params::INPUT, "d14").is_none());
+        output.init(&root);
+        let example = Params::example(&[]);
+        test!(Some(root.join("d14-example")), output.path(&example, "d14"));
+        let directory = output.subdirectory(&Params::INPUT, "d14", true).unwrap();
+        fs::write(directory.join("000000.txt"), "").unwrap();
+        // Cleared the first time only
+        output.subdirectory(&Params::INPUT, "d14", true).unwrap();
+        test!(directory.join("000000.txt").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,55 @@
+// This module contains helpers to process the lines of puzzle input in parallel, for puzzles in
+// which every line is independent, without every day having to use rayon directly
+
+use crate::Int;
+use rayon::prelude::*;
+
+/// Process the non-empty lines of a str in parallel, on the rayon thread pool
+pub trait ParLines {
+    /// Map every non-empty line with `f`, the results are in line order
+    fn par_lines_map<T: Send>(&self, f: impl Fn(&str) -> T + Sync + Send) -> Vec<T>;
+
+    /// Sum `f` of every non-empty line
+    fn par_lines_sum(&self, f: impl Fn(&str) -> Int + Sync + Send) -> Int;
+
+    /// Count the non-empty lines that satisfy `predicate`
+    fn par_lines_count(&self, predicate: impl Fn(&str) -> bool + Sync + Send) -> usize;
+}
+
+impl ParLines for str {
+    fn par_lines_map<T: Send>(&self, f: impl Fn(&str) -> T + Sync + Send) -> Vec<T> {
+        self.par_lines()
+            .filter(|line| !line.is_empty())
+            .map(f)
+            .collect()
+    }
+
+    fn par_lines_sum(&self, f: impl Fn(&str) -> Int + Sync + Send) -> Int {
+        self.par_lines()
+            .filter(|line| !line.is_empty())
+            .map(f)
+            .sum()
+    }
+
+    fn par_lines_count(&self, predicate: impl Fn(&str) -> bool + Sync + Send) -> usize {
+        self.par_lines()
+            .filter(|line| !line.is_empty() && predicate(line))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_par_lines() {
+        let input = "3\n1\n\n4\n1\n5\n";
+        let parse = |line: &str| line.parse::<Int>().unwrap();
+        test!(vec![3, 1, 4, 1, 5], input.par_lines_map(parse));
+        test!(14, input.par_lines_sum(parse));
+        test!(2, input.par_lines_count(|line| line == "1"));
+        test!(0, "".par_lines_sum(parse));
+    }
+}
@@ -0,0 +1,744 @@
+#![allow(dead_code)]
+// This module contains a generic implementation of the Dijkstra pathfinding algorithm,
+// because these kind of problems will often occurs in Advent of Code, the 0-1 BFS and Dial's
+// algorithm for the small integer distances of grids, which need no priority queue, and a
+// bidirectional BFS between two known states
+
+use crate::arena::{Arena, NodeId};
+use crate::priority_queue::PriorityQueue;
+use crate::{debug, test, Grid, Queue};
+use std::{
+    collections::HashMap,
+    fmt::{self, Debug, Display, Write},
+    hash::Hash,
+    iter::once,
+};
+
+/// Custom types
+type Int = i64;
+type Distance = Int;
+type DistanceOption = Option<Distance>;
+type Edges = Vec<Edge>;
+type Path<T> = Vec<T>;
+
+/// A `Node` with a `state` and an optional `distance` to some starting `Node`.
+#[derive(Clone, Debug)]
+pub struct Node<T> {
+    pub state: T,
+    pub distance_option: DistanceOption,
+    visited: bool,
+    path: Path<T>,
+    /// `Edges` from this `Node` to its neighbours
+    edges: Edges,
+}
+
+impl<T: PartialEq> PartialEq for Node<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+
+impl<T: Debug + Display + Clone> Node<T> {
+    fn new(state: T, distance_option: DistanceOption) -> Node<T> {
+        Node {
+            state,
+            distance_option,
+            visited: false,
+            path: Path::new(),
+            edges: Edges::new(),
+        }
+    }
+
+    fn update(&mut self, distance: Distance, path: Path<T>) {
+        self.distance_option = Some(distance);
+        self.path = path.into_iter().chain(once(self.state.clone())).collect();
+    }
+
+    fn path_to_string(&self) -> String {
+        self.path
+            .iter()
+            .map(|state| format!("{}", state))
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+}
+
+/// Directed `Edge` to the `Node` with NodeId `to`.
+#[derive(PartialEq, Debug, Clone, Copy)]
+struct Edge {
+    to: NodeId,
+    distance: Distance,
+}
+
+#[derive(Debug)]
+pub struct Graph<T: Hash + Eq + Clone + Debug + Display> {
+    /// All `Nodes`, in the order in which they were added
+    nodes: Arena<Node<T>>,
+    /// NodeId of the `Node` with a state
+    ids: HashMap<T, NodeId>,
+    starting_node: NodeId,
+    /// Unvisited `Nodes` that have a distance, by ascending distance
+    queue: PriorityQueue<NodeId, Distance>,
+}
+
+impl<T: Hash + Eq + Clone + Debug + Display> Graph<T> {
+    pub fn new(starting_state: T) -> Graph<T> {
+        let mut nodes = Arena::new();
+        let starting_node = nodes.insert(Node::new(starting_state.clone(), Some(0)));
+        Graph {
+            nodes,
+            ids: HashMap::from([(starting_state, starting_node)]),
+            starting_node,
+            queue: PriorityQueue::new(),
+        }
+    }
+
+    /// Add a new `Edge` from new or existing `Nodes` with specified `states``
+    pub fn add_edge(&mut self, first_state: T, second_state: T, distance: Distance) {
+        let first_node: NodeId = self.insert_node(first_state);
+        let second_node: NodeId = self.insert_node(second_state);
+        self.nodes[first_node].edges.push(Edge {
+            to: second_node,
+            distance,
+        });
+    }
+
+    /// Add a new biderectional `Edge` from new or existing `Nodes` with specified `states``
+    pub fn add_bidirectional_edge(&mut self, first_state: T, second_state: T, distance: Distance) {
+        self.add_edge(first_state.clone(), second_state.clone(), distance);
+        self.add_edge(second_state, first_state, distance);
+    }
+
+    /// Add all `Edges` from new or existing `Nodes` with specified `states``
+    pub fn add_edges(&mut self, edges: Vec<(T, T, Distance)>) {
+        edges
+            .into_iter()
+            .for_each(|(first_state, second_state, distance)| {
+                self.add_edge(first_state, second_state, distance)
+            });
+    }
+
+    /// Add all bidirectional `Edges` from new or existing `Nodes` with specified `states``
+    pub fn add_bidirectional_edges(&mut self, edges: Vec<(T, T, Distance)>) {
+        edges
+            .into_iter()
+            .for_each(|(first_state, second_state, distance)| {
+                self.add_bidirectional_edge(first_state, second_state, distance);
+            });
+    }
+
+    /// Insert a `Node` with specified `state` if there is no such `Node` yet.
+    /// Returns the NodeId of the new or existing `Node`.
+    fn insert_node(&mut self, state: T) -> NodeId {
+        match self.ids.get(&state) {
+            Some(&id) => id,
+            None => {
+                let id = self.nodes.insert(Node::new(state.clone(), None));
+                self.ids.insert(state, id);
+                id
+            }
+        }
+    }
+
+    /// Get the `Node` with specified `state`, if it exists.
+    pub fn get_node(&self, state: &T) -> Option<&Node<T>> {
+        self.ids.get(state).map(|&id| &self.nodes[id])
+    }
+
+    /// All visited `Nodes`, in the order in which they were added
+    pub fn visited_nodes(&self) -> Vec<&Node<T>> {
+        self.nodes.values().filter(|node| node.visited).collect()
+    }
+
+    /// All unvisited `Nodes`, in the order in which they were added
+    pub fn unvisited_nodes(&self) -> Vec<&Node<T>> {
+        self.nodes.values().filter(|node| !node.visited).collect()
+    }
+
+    /// Visit a single Node with a specified state, and update all of it's unvisited neighbours with the shortest_distance to those Nodes.
+    /// Will panic if the Node cannot be visited.
+    fn visit(&mut self, state: &T) {
+        let id = match self.ids.get(state) {
+            Some(&id) => id,
+            None => panic!(
+                "There is no Node with state '{:?}' in this Graph, cannot visit.",
+                state
+            ),
+        };
+        let node = &self.nodes[id];
+        match (node.visited, node.distance_option) {
+            (true, _) => panic!(
+                "The Node with state: '{:?}' has already been visited, cannot visit.",
+                state
+            ),
+            (false, None) => panic!(
+                "The Node with state: '{:?}' has distance `None`, cannot visit.",
+                state
+            ),
+            // Here we can actually visit this Node
+            (false, Some(_)) => self.visit_valid_node(id),
+        }
+    }
+
+    /// Visit a `Node` that is assumed to be valid, meaning that it exists, is unvisited, and has a distance value.
+    /// Will update all unvisited neighbours of the `Node` with the shortest distance to those `Nodes`
+    fn visit_valid_node(&mut self, id: NodeId) {
+        debug!(true, "visit_valid_node(state: {:?})", self.nodes[id].state);
+        if id == self.starting_node {
+            let state = self.nodes[id].state.clone();
+            self.nodes[id].path.push(state);
+        }
+        self.nodes[id].visited = true;
+
+        // Update all unvisited neighbours with the shortest distance to that node
+        let distance_to_current_node = self.nodes[id].distance_option.unwrap();
+        let path_to_neighbour: Path<T> = self.nodes[id].path.clone();
+        for edge in self.nodes[id].edges.clone() {
+            let neighbour = &mut self.nodes[edge.to];
+            if neighbour.visited {
+                continue;
+            }
+            let distance_to_neighbour = distance_to_current_node + edge.distance;
+            if neighbour
+                .distance_option
+                .is_none_or(|previous_distance| distance_to_neighbour < previous_distance)
+            {
+                neighbour.update(distance_to_neighbour, path_to_neighbour.clone());
+                self.queue.push(edge.to, distance_to_neighbour);
+            }
+        }
+    }
+
+    /// Get the next `Node` to visit during Dijkstra's pathfinding algorithm
+    fn get_next_node_to_visit(&mut self) -> Option<NodeId> {
+        self.queue.pop().map(|(id, _)| id)
+    }
+
+    /// Run (Dijkstra) pathfinding algorithm to find shortest distance from self.starting_node to all other Nodes.
+    pub fn run_pathfinding_algorithm(&mut self) {
+        self.visit_valid_node(self.starting_node);
+        while let Some(next_node) = self.get_next_node_to_visit() {
+            self.visit_valid_node(next_node);
+        }
+        // We have now visited all unvisited Nodes that were reachable
+        let unvisited_nodes = self.unvisited_nodes().len();
+        if unvisited_nodes > 0 {
+            println!(
+                "Not all Nodes have been visited, '{}' are unreachable, this should not occur.",
+                unvisited_nodes
+            );
+        }
+    }
+
+    /// Return the distance of a Node in this Graph.
+    ///
+    /// Run `run_pathfinding_algorithm()` first.
+    ///
+    /// Will panic if there is no Node in this Graph with the specified state,
+    /// or if the Node has no distance.
+    pub fn get_distance(&self, state: T) -> Distance {
+        match self.get_node(&state) {
+            Some(node) => node.distance_option.unwrap(),
+            _ => panic!("No Node in Graph with state: {:?}.", state),
+        }
+    }
+
+    /// Test the distance of a Node in this Graph.
+    ///
+    /// Run `run_pathfinding_algorithm()` first.
+    ///
+    /// Will panic if there is no Node in this Graph with the specified state,
+    /// or if the Node has no distance, or if the distance is incorrect.
+    fn test_distance(&self, state: T, expected: Distance) {
+        test!(
+            expected,
+            self.get_distance(state.clone()),
+            "Distance {} == {}",
+            state,
+            expected
+        );
+    }
+
+    /// Return the shortest Path to a Node in this Graph.
+    ///
+    /// Run `run_pathfinding_algorithm()` first.
+    ///
+    /// Will panic if there is no Node in this Graph with the specified state,
+    /// or if the Node has no path.
+    pub fn get_path(&self, state: T) -> Path<T> {
+        match self.get_node(&state) {
+            Some(node) => node.path.clone(),
+            _ => panic!("No Node in Graph with state: {:?}.", state),
+        }
+    }
+
+    /// Test the distance of a Node in this Graph.
+    ///
+    /// Run `run_pathfinding_algorithm()` first.
+    ///
+    /// Will panic if there is no Node in this Graph with the specified state,
+    /// or if the Node has no distance, or if the distance is incorrect.
+    fn test_path(&self, state: T, expected: Path<T>) {
+        test!(
+            expected,
+            self.get_path(state.clone()),
+            "Path to {}: {}",
+            state.clone(),
+            self.get_node(&state).unwrap().path_to_string()
+        );
+    }
+
+    /// Write this Graph as a Graphviz DOT digraph, of which every `Node` is labeled by its state,
+    /// and every `Edge` by its distance if `with_distances`
+    pub fn write_dot(&self, out: &mut impl Write, name: &str, with_distances: bool) -> fmt::Result {
+        writeln!(out, "digraph \"{}\" {{", name)?;
+        for (id, node) in self.nodes.iter() {
+            let label = node.state.to_string().replace('"', "\\\"");
+            writeln!(out, "    n{} [label=\"{}\"];", id.index(), label)?;
+        }
+        for (id, node) in self.nodes.iter() {
+            for edge in &node.edges {
+                write!(out, "    n{} -> n{}", id.index(), edge.to.index())?;
+                match with_distances {
+                    true => writeln!(out, " [label=\"{}\"];", edge.distance)?,
+                    false => writeln!(out, ";")?,
+                }
+            }
+        }
+        write!(out, "}}")
+    }
+}
+
+/// One line per node, with the nodes that its edges lead to and their distances, e.g. `a -> b (3), d (8)`
+impl<T: Hash + Eq + Clone + Debug + Display> Display for Graph<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (line, (_, node)) in self.nodes.iter().enumerate() {
+            if line > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", node.state)?;
+            for (i, edge) in node.edges.iter().enumerate() {
+                let separator = if i == 0 { " -> " } else { ", " };
+                let to = &self.nodes[edge.to].state;
+                write!(f, "{}{} ({})", separator, to, edge.distance)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Shortest distances from `start` to every state that can be reached, where `successors` returns the next states
+/// with a distance of 0 or 1, like moves that are free and moves that take a step. A deque replaces the priority
+/// queue of Dijkstra: states at a distance of 0 go to its front, states at a distance of 1 to its back.
+pub fn zero_one_bfs<T: Hash + Eq + Clone>(
+    start: T,
+    successors: impl Fn(&T) -> Vec<(T, Distance)>,
+) -> HashMap<T, Distance> {
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue: Queue<(T, Distance)> = Queue::from([(start, 0)]);
+    while let Some((state, distance)) = queue.pop_front() {
+        if distances[&state] < distance {
+            continue;
+        }
+        for (next, weight) in successors(&state) {
+            assert!(
+                weight == 0 || weight == 1,
+                "A 0-1 BFS only takes distances of 0 or 1, not {}.",
+                weight
+            );
+            let next_distance = distance + weight;
+            if distances.get(&next).is_some_and(|&d| d <= next_distance) {
+                continue;
+            }
+            distances.insert(next.clone(), next_distance);
+            match weight {
+                0 => queue.push_front((next, next_distance)),
+                _ => queue.push_back((next, next_distance)),
+            }
+        }
+    }
+    distances
+}
+
+/// Shortest distances from `start` to every state that can be reached, where `successors` returns the next states
+/// with a distance from 0 up to `max_distance`, like the risk levels 1-9 of a grid. This is Dial's algorithm: a bucket
+/// of states per distance replaces the priority queue of Dijkstra, only `max_distance + 1` buckets are in use at a
+/// time, so they are reused in a circle.
+pub fn dial<T: Hash + Eq + Clone>(
+    start: T,
+    max_distance: Distance,
+    successors: impl Fn(&T) -> Vec<(T, Distance)>,
+) -> HashMap<T, Distance> {
+    assert!(max_distance >= 0, "Distances can not be negative.");
+    let mut buckets: Vec<Vec<T>> = vec![Vec::new(); max_distance as usize + 1];
+    let bucket = |distance: Distance| distance as usize % (max_distance as usize + 1);
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    buckets[0].push(start);
+    let (mut distance, mut pending) = (0, 1);
+    while pending > 0 {
+        while let Some(state) = buckets[bucket(distance)].pop() {
+            pending -= 1;
+            // States are pushed again when a shorter distance is found, the old entry is left behind
+            if distances[&state] != distance {
+                continue;
+            }
+            for (next, weight) in successors(&state) {
+                assert!(
+                    (0..=max_distance).contains(&weight),
+                    "Dial's algorithm only takes distances from 0 up to {}, not {}.",
+                    max_distance,
+                    weight
+                );
+                let next_distance = distance + weight;
+                if distances.get(&next).is_some_and(|&d| d <= next_distance) {
+                    continue;
+                }
+                distances.insert(next.clone(), next_distance);
+                buckets[bucket(next_distance)].push(next);
+                pending += 1;
+            }
+        }
+        distance += 1;
+    }
+    distances
+}
+
+/// The states from `state` back to the state that a search started from, following the states that they were
+/// reached from in `visited`
+fn trace<T: Hash + Eq + Clone>(visited: &HashMap<T, (Option<T>, Distance)>, state: &T) -> Path<T> {
+    let mut path = vec![state.clone()];
+    while let Some((Some(previous), _)) = visited.get(path.last().unwrap()) {
+        path.push(previous.clone());
+    }
+    path
+}
+
+/// Visit the states that `neighbours` returns for every state in `frontier`, which becomes the next level of the
+/// search. Returns the state where the search met the states `other` visited on the shortest total distance, if any.
+fn expand<T: Hash + Eq + Clone>(
+    frontier: &mut Vec<T>,
+    visited: &mut HashMap<T, (Option<T>, Distance)>,
+    other: &HashMap<T, (Option<T>, Distance)>,
+    neighbours: &impl Fn(&T) -> Vec<T>,
+) -> Option<T> {
+    let mut meeting: Option<(T, Distance)> = None;
+    let mut next_frontier = Vec::new();
+    for state in frontier.drain(..) {
+        let distance = visited[&state].1 + 1;
+        for next in neighbours(&state) {
+            if visited.contains_key(&next) {
+                continue;
+            }
+            visited.insert(next.clone(), (Some(state.clone()), distance));
+            if let Some(&(_, other_distance)) = other.get(&next) {
+                let total = distance + other_distance;
+                if meeting.as_ref().is_none_or(|&(_, best)| total < best) {
+                    meeting = Some((next.clone(), total));
+                }
+            }
+            next_frontier.push(next);
+        }
+    }
+    *frontier = next_frontier;
+    meeting.map(|(state, _)| state)
+}
+
+/// A shortest path from `start` to `goal`, where every step has the same distance, or None if `goal` can not be
+/// reached. The search runs from both ends at once, always extending the smaller frontier, so each side only goes
+/// about half as deep as a BFS from `start` would, which matters when the number of states grows fast with the
+/// depth, like the permutations of a scrambler. `successors` returns the states that a state leads to, and
+/// `predecessors` the states that lead to it, pass the same function twice if every step can be undone.
+pub fn bidirectional_bfs<T: Hash + Eq + Clone>(
+    start: T,
+    goal: T,
+    successors: impl Fn(&T) -> Vec<T>,
+    predecessors: impl Fn(&T) -> Vec<T>,
+) -> Option<Path<T>> {
+    if start == goal {
+        return Some(vec![start]);
+    }
+    let mut forward = HashMap::from([(start.clone(), (None, 0))]);
+    let mut backward = HashMap::from([(goal.clone(), (None, 0))]);
+    let (mut forward_frontier, mut backward_frontier) = (vec![start], vec![goal]);
+    while !forward_frontier.is_empty() && !backward_frontier.is_empty() {
+        let meeting = if forward_frontier.len() <= backward_frontier.len() {
+            expand(&mut forward_frontier, &mut forward, &backward, &successors)
+        } else {
+            expand(
+                &mut backward_frontier,
+                &mut backward,
+                &forward,
+                &predecessors,
+            )
+        };
+        if let Some(meeting) = meeting {
+            let mut path = trace(&forward, &meeting);
+            path.reverse();
+            path.extend(trace(&backward, &meeting).into_iter().skip(1));
+            return Some(path);
+        }
+    }
+    None
+}
+
+/// The orthogonal neighbours of (`x`, `y`) in `grid`, with the distance that `weight` gives to entering them
+fn grid_successors<C>(
+    grid: &Grid<C>,
+    (x, y): (Int, Int),
+    weight: impl Fn(&C) -> Distance,
+) -> Vec<((Int, Int), Distance)> {
+    [(0, -1), (1, 0), (0, 1), (-1, 0)]
+        .into_iter()
+        .map(|(dx, dy)| (x + dx, y + dy))
+        .filter_map(|(x, y)| {
+            let cell = grid
+                .get(usize::try_from(y).ok()?)?
+                .get(usize::try_from(x).ok()?)?;
+            Some(((x, y), weight(cell)))
+        })
+        .collect()
+}
+
+#[test]
+fn test_case_a() {
+    let mut graph: Graph<&str> = Graph::new("a");
+    let edges = vec![
+        ("a", "b", 3),
+        ("b", "c", 2),
+        ("b", "d", 4),
+        ("c", "e", 4),
+        ("e", "b", 10),
+        ("a", "d", 8),
+        ("d", "f", 1),
+        ("f", "g", 5),
+        ("g", "f", 2),
+    ];
+    graph.add_edges(edges);
+    graph.run_pathfinding_algorithm();
+    //dbg!(&graph.visited_nodes);
+    test!(0, graph.unvisited_nodes().len());
+    test!(7, graph.visited_nodes().len());
+    // Test distances
+    let distances = vec![
+        ("a", 0),
+        ("b", 3),
+        ("c", 5),
+        ("d", 7),
+        ("e", 9),
+        ("f", 8),
+        ("g", 13),
+    ];
+    distances.iter().for_each(|t| {
+        graph.test_distance(t.0, t.1);
+    });
+    // Test paths
+    let paths = vec![
+        ("a", vec!["a"]),
+        ("b", vec!["a", "b"]),
+        ("c", vec!["a", "b", "c"]),
+        ("d", vec!["a", "b", "d"]),
+        ("e", vec!["a", "b", "c", "e"]),
+        ("f", vec!["a", "b", "d", "f"]),
+        ("g", vec!["a", "b", "d", "f", "g"]),
+    ];
+    paths.into_iter().for_each(|t| {
+        graph.test_path(t.0, t.1);
+    });
+}
+
+/// Test case from:
+/// https://www.geeksforgeeks.org/dijkstras-shortest-path-algorithm-greedy-algo-7/
+/// See: https://media.geeksforgeeks.org/wp-content/uploads/20240111182238/Working-of-Dijkstras-Algorithm-768.jpg
+#[test]
+fn test_case_b() {
+    let mut graph: Graph<u8> = Graph::new(0);
+    let edges = vec![
+        (0, 1, 4),
+        (1, 2, 8),
+        (2, 3, 7),
+        (3, 4, 9),
+        (4, 5, 10),
+        (5, 6, 2),
+        (6, 7, 1),
+        (7, 0, 8),
+        (1, 7, 11),
+        (2, 8, 2),
+        (8, 7, 7),
+        (8, 6, 6),
+        (2, 5, 4),
+        (3, 5, 14),
+    ];
+    graph.add_bidirectional_edges(edges);
+    graph.run_pathfinding_algorithm();
+    test!(0, graph.unvisited_nodes().len());
+    test!(9, graph.visited_nodes().len());
+    // Test distances
+    let distances = vec![
+        (0, 0),
+        (1, 4),
+        (2, 12),
+        (3, 19),
+        (4, 21),
+        (5, 11),
+        (6, 9),
+        (7, 8),
+        (8, 14),
+    ];
+    distances.iter().for_each(|t| {
+        graph.test_distance(t.0, t.1);
+    });
+    // Test paths
+    let paths = vec![
+        (0, vec![0]),
+        (1, vec![0, 1]),
+        (2, vec![0, 1, 2]),
+        (3, vec![0, 1, 2, 3]),
+        (4, vec![0, 7, 6, 5, 4]),
+        (5, vec![0, 7, 6, 5]),
+        (6, vec![0, 7, 6]),
+        (7, vec![0, 7]),
+        (8, vec![0, 1, 2, 8]),
+    ];
+    paths.into_iter().for_each(|t| {
+        graph.test_path(t.0, t.1);
+    });
+}
+
+/// Test case from:
+/// https://www.tutorialspoint.com/data_structures_algorithms/dijkstras_shortest_path_algorithm.htm
+#[test]
+fn test_case_c() {
+    let mut graph: Graph<&str> = Graph::new("S");
+    let edges = vec![
+        ("S", "A", 6),
+        ("A", "B", 9),
+        ("B", "C", 12),
+        ("C", "D", 3),
+        ("D", "S", 8),
+        ("C", "E", 5),
+        ("D", "E", 10),
+        ("S", "E", 7),
+    ];
+    graph.add_bidirectional_edges(edges);
+    graph.run_pathfinding_algorithm();
+    test!(0, graph.unvisited_nodes().len());
+    test!(6, graph.visited_nodes().len());
+    // Test distances
+    let distances = vec![("S", 0), ("A", 6), ("B", 15), ("C", 11), ("D", 8), ("E", 7)];
+    distances.iter().for_each(|t| {
+        graph.test_distance(t.0, t.1);
+    });
+    // Test paths
+    let paths = vec![
+        ("S", vec!["S"]),
+        ("A", vec!["S", "A"]),
+        ("B", vec!["S", "A", "B"]),
+        ("C", vec!["S", "D", "C"]),
+        ("D", vec!["S", "D"]),
+        ("E", vec!["S", "E"]),
+    ];
+    paths.into_iter().for_each(|t| {
+        graph.test_path(t.0, t.1);
+    });
+}
+
+#[test]
+fn test_write_dot() {
+    let mut graph: Graph<&str> = Graph::new("a");
+    graph.add_edges(vec![("a", "b", 3), ("b", "\"c\"", 2)]);
+    let mut dot = String::new();
+    graph.write_dot(&mut dot, "test", true).unwrap();
+    test!(
+        "digraph \"test\" {\n    n0 [label=\"a\"];\n    n1 [label=\"b\"];\n    n2 [label=\"\\\"c\\\"\"];\n    n0 -> n1 [label=\"3\"];\n    n1 -> n2 [label=\"2\"];\n}",
+        dot
+    );
+}
+
+#[test]
+fn test_display() {
+    let mut graph: Graph<&str> = Graph::new("a");
+    graph.add_edges(vec![("a", "b", 3), ("a", "c", 8), ("b", "c", 2)]);
+    test!("a -> b (3), c (8)\nb -> c (2)\nc", graph.to_string());
+}
+
+/// Test case from 2021-15: the lowest total risk of the example, compared against Dijkstra
+#[test]
+fn test_dial() {
+    let map = crate::grid::Map::parse_digits(
+        "1163751742\n1381373672\n2136511328\n3694931569\n7463417111\n\
+         1319128137\n1359912421\n3125421639\n1293138521\n2311944581",
+    );
+    let successors = |&state: &(Int, Int)| grid_successors(&map.grid, state, |&risk| risk);
+    let distances = dial((0, 0), 9, successors);
+    test!(Some(&40), distances.get(&(9, 9)));
+    test!(100, distances.len());
+    let mut graph: Graph<String> = Graph::new("0,0".to_string());
+    for y in 0..10 {
+        for x in 0..10 {
+            for ((nx, ny), risk) in successors(&(x, y)) {
+                graph.add_edge(format!("{},{}", x, y), format!("{},{}", nx, ny), risk);
+            }
+        }
+    }
+    graph.run_pathfinding_algorithm();
+    for ((x, y), distance) in &distances {
+        test!(*distance, graph.get_distance(format!("{},{}", x, y)));
+    }
+}
+
+/// The fewest walls to break through to cross a maze, where walking is free and breaking a wall takes a step
+#[test]
+fn test_zero_one_bfs() {
+    let grid: Grid<char> = ["..#..", "##.##", "..###", ".#..#", "...#."]
+        .iter()
+        .map(|line| line.chars().collect())
+        .collect();
+    let successors =
+        |&state: &(Int, Int)| grid_successors(&grid, state, |&cell| (cell == '#') as Distance);
+    let distances = zero_one_bfs((0, 0), successors);
+    test!(Some(&1), distances.get(&(2, 1)));
+    test!(Some(&1), distances.get(&(2, 3)));
+    test!(Some(&2), distances.get(&(4, 4)));
+    test!(dial((0, 0), 1, successors), distances);
+}
+
+/// Sorting a permutation with rotations and a swap of the first two elements, compared against a BFS from one end
+#[test]
+fn test_bidirectional_bfs() {
+    let rotate_left = |state: &Vec<u8>| {
+        let mut next = state.clone();
+        next.rotate_left(1);
+        next
+    };
+    let rotate_right = |state: &Vec<u8>| {
+        let mut next = state.clone();
+        next.rotate_right(1);
+        next
+    };
+    let swap = |state: &Vec<u8>| {
+        let mut next = state.clone();
+        next.swap(0, 1);
+        next
+    };
+    let successors = |state: &Vec<u8>| vec![rotate_left(state), swap(state)];
+    let predecessors = |state: &Vec<u8>| vec![rotate_right(state), swap(state)];
+    let (start, goal) = (vec![4, 2, 5, 0, 3, 1], vec![0, 1, 2, 3, 4, 5]);
+    let path = bidirectional_bfs(start.clone(), goal.clone(), successors, predecessors).unwrap();
+    test!(&start, &path[0]);
+    test!(Some(&goal), path.last());
+    test!(path
+        .windows(2)
+        .all(|step| successors(&step[0]).contains(&step[1])));
+    let distances = zero_one_bfs(start.clone(), |state| {
+        successors(state)
+            .into_iter()
+            .map(|next| (next, 1))
+            .collect()
+    });
+    test!(distances[&goal], path.len() as Distance - 1);
+    let path = bidirectional_bfs(goal.clone(), goal.clone(), successors, predecessors);
+    test!(Some(vec![goal.clone()]), path);
+    // Only the rotations can not change the order of the elements
+    let rotations = |state: &Vec<u8>| vec![rotate_left(state)];
+    test!(bidirectional_bfs(start, goal, rotations, rotations).is_none());
+}
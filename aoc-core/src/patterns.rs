@@ -0,0 +1,160 @@
+// This module finds the offsets at which a small grid matches a Map, in any rotation or reflection, for sea-monster
+// style pattern searches and puzzles that assemble tiles
+
+use crate::grid::{Map, Point};
+
+/// One of the 8 ways to rotate and reflect a grid, the grid is first flipped if `flipped`, and then rotated
+/// clockwise by a quarter turn `rotations` times
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    pub flipped: bool,
+    pub rotations: usize,
+}
+
+impl Orientation {
+    /// Every Orientation, starting with the grid as it is
+    pub const ALL: [Orientation; 8] = {
+        let mut all = [Orientation {
+            flipped: false,
+            rotations: 0,
+        }; 8];
+        let mut i = 0;
+        while i < 8 {
+            all[i] = Orientation {
+                flipped: i >= 4,
+                rotations: i % 4,
+            };
+            i += 1;
+        }
+        all
+    };
+}
+
+impl<T: Clone> Map<T> {
+    /// This Map rotated clockwise by a quarter turn, the first column becomes the first row, from bottom to top
+    pub fn rotate_clockwise(&self) -> Map<T> {
+        (0..self.get_columns())
+            .map(|x| self.grid.iter().rev().map(|row| row[x].clone()).collect())
+            .collect()
+    }
+
+    /// This Map mirrored left to right
+    pub fn flip_horizontal(&self) -> Map<T> {
+        self.grid
+            .iter()
+            .map(|row| row.iter().rev().cloned().collect())
+            .collect()
+    }
+
+    /// This Map in `orientation`
+    pub fn oriented(&self, orientation: Orientation) -> Map<T> {
+        let mut map = match orientation.flipped {
+            true => self.flip_horizontal(),
+            false => self.clone(),
+        };
+        for _ in 0..orientation.rotations % 4 {
+            map = map.rotate_clockwise();
+        }
+        map
+    }
+}
+
+impl<T: PartialEq> Map<T> {
+    /// The top-left Points of every place where `pattern` matches this Map, row by row. A cell of the pattern that
+    /// is one of the `wildcards` matches any cell, e.g. the spaces around a sea monster.
+    pub fn find_pattern(&self, pattern: &Map<T>, wildcards: &[T]) -> Vec<Point> {
+        if pattern.is_empty() {
+            return Vec::new();
+        }
+        let cells: Vec<(usize, usize, &T)> = pattern
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, value)| (x, y, value)))
+            .filter(|(_, _, value)| !wildcards.contains(value))
+            .collect();
+        self.windows(pattern.get_columns(), pattern.get_rows())
+            .filter(|window| {
+                cells
+                    .iter()
+                    .all(|&(x, y, value)| window.get(x as _, y as _) == Some(value))
+            })
+            .map(|window| window.origin)
+            .collect()
+    }
+}
+
+impl<T: PartialEq + Clone> Map<T> {
+    /// The top-left Points of every place where `pattern` matches this Map in any Orientation, see `find_pattern`.
+    /// Orientations in which a symmetric pattern looks the same as in an earlier Orientation are skipped, so every
+    /// match is found once.
+    pub fn find_pattern_oriented(
+        &self,
+        pattern: &Map<T>,
+        wildcards: &[T],
+    ) -> Vec<(Point, Orientation)> {
+        let mut patterns: Vec<Map<T>> = Vec::new();
+        let mut matches = Vec::new();
+        for orientation in Orientation::ALL {
+            let oriented = pattern.oriented(orientation);
+            if patterns.iter().any(|earlier| earlier.grid == oriented.grid) {
+                continue;
+            }
+            matches.extend(
+                self.find_pattern(&oriented, wildcards)
+                    .into_iter()
+                    .map(|point| (point, orientation)),
+            );
+            patterns.push(oriented);
+        }
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    fn map(rows: &str) -> Map<char> {
+        rows.lines().map(|row| row.chars().collect()).collect()
+    }
+
+    #[test]
+    fn test_orientations() {
+        let map = map("ab\ncd\nef");
+        test!("eca\nfdb", map.rotate_clockwise().to_string());
+        test!("ba\ndc\nfe", map.flip_horizontal().to_string());
+        let rotations = |flipped| Orientation {
+            flipped,
+            rotations: 2,
+        };
+        test!("fe\ndc\nba", map.oriented(rotations(false)).to_string());
+        test!("ef\ncd\nab", map.oriented(rotations(true)).to_string());
+        test!(8, Orientation::ALL.len());
+        test!(Orientation::default(), Orientation::ALL[0]);
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let monster = map("#.\n##");
+        let sea = map("......\n#..##.\n##.##.\n......");
+        test!(
+            vec![Point::new(0, 1), Point::new(3, 1)],
+            sea.find_pattern(&monster, &['.'])
+        );
+        // Without wildcards, the cells around the pattern have to match too
+        test!(vec![Point::new(0, 1)], sea.find_pattern(&monster, &[]));
+        test!(sea.find_pattern(&map("#######"), &[]).is_empty());
+        let sea = map("......\n#....#\n##..##\n......");
+        // The monster at (4, 1) is rotated by three quarter turns, which is the same as flipped
+        test!(
+            vec![
+                (Point::new(0, 1), Orientation::ALL[0]),
+                (Point::new(4, 1), Orientation::ALL[3])
+            ],
+            sea.find_pattern_oriented(&monster, &['.'])
+        );
+        // A symmetric pattern is found once per place
+        test!(6, sea.find_pattern_oriented(&map("#"), &[]).len());
+    }
+}
@@ -0,0 +1,203 @@
+#![allow(dead_code)]
+// This module counts the lattice points of a polygon from its vertices alone, with the shoelace formula and Pick's
+// theorem, so loops that are defined by instructions with huge distances do not have to be drawn on a grid, and
+// classifies the cells of a grid as inside or outside of a loop that is drawn on it
+
+use crate::grid::{Direction, Map, Point};
+use crate::hash::FastHashMap;
+use crate::{gcd, Int};
+
+/// The vertices of the loop that starts at `start`, and moves `distance` in `direction` for every instruction.
+///
+/// If the instructions return to `start`, it is not repeated at the end.
+pub fn vertices(
+    start: Point,
+    instructions: impl IntoIterator<Item = (Direction, Int)>,
+) -> Vec<Point> {
+    let mut vertices = vec![start];
+    let mut point = start;
+    for (direction, distance) in instructions {
+        point = point.move_distance(&direction, distance);
+        vertices.push(point);
+    }
+    if vertices.len() > 1 && vertices.last() == Some(&start) {
+        vertices.pop();
+    }
+    vertices
+}
+
+/// The edges of the closed polygon through `vertices`, from every vertex to the next one
+fn edges(vertices: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    let next = vertices.iter().cycle().skip(1);
+    vertices.iter().zip(next).map(|(&a, &b)| (a, b))
+}
+
+/// Number of lattice points on the boundary of the closed polygon through `vertices`
+pub fn boundary(vertices: &[Point]) -> Int {
+    edges(vertices)
+        .map(|(a, b)| gcd((b.x - a.x).unsigned_abs(), (b.y - a.y).unsigned_abs()) as Int)
+        .sum()
+}
+
+/// Twice the area of the closed polygon through `vertices`, which is an integer, with the shoelace formula
+pub fn double_area(vertices: &[Point]) -> Int {
+    let sum: i128 = edges(vertices)
+        .map(|(a, b)| a.x as i128 * b.y as i128 - b.x as i128 * a.y as i128)
+        .sum();
+    sum.abs() as Int
+}
+
+/// Number of lattice points strictly inside the closed polygon through `vertices`, which must not intersect itself.
+///
+/// Pick's theorem relates the area `A` to the `i` interior and `b` boundary points: `A = i + b/2 - 1`.
+pub fn interior(vertices: &[Point]) -> Int {
+    if vertices.len() < 3 {
+        return 0;
+    }
+    (double_area(vertices) - boundary(vertices) + 2) / 2
+}
+
+/// Number of lattice points inside or on the boundary of the closed polygon through `vertices`, e.g. the cubic
+/// meters of the lagoon of 2023-18
+pub fn lattice_points(vertices: &[Point]) -> Int {
+    interior(vertices) + boundary(vertices)
+}
+
+/// Whether a cell of a grid is part of a loop, or inside or outside of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Loop,
+    Inside,
+    Outside,
+}
+
+/// The tiles of the closed loop through the adjacent points of `path`, by the two directions in which every tile
+/// connects to its neighbors on the loop, e.g. the pipes of 2023-10, including the tile of the animal
+pub fn loop_tiles(path: &[Point]) -> FastHashMap<Point, [Direction; 2]> {
+    let direction = |from: &Point, to: &Point| match (to.x - from.x, to.y - from.y) {
+        (0, -1) => Direction::North,
+        (1, 0) => Direction::East,
+        (0, 1) => Direction::South,
+        (-1, 0) => Direction::West,
+        _ => panic!("{} and {} are not adjacent.", from, to),
+    };
+    let previous = path.iter().cycle().skip(path.len().saturating_sub(1));
+    let next = path.iter().cycle().skip(1);
+    path.iter()
+        .zip(previous.zip(next))
+        .map(|(point, (previous, next))| {
+            (*point, [direction(point, previous), direction(point, next)])
+        })
+        .collect()
+}
+
+/// The Side of every cell of a `width` by `height` grid, of which the tiles of a closed loop connect in the given
+/// directions, by counting the loop tiles left of every cell in its row: odd means inside.
+///
+/// Only tiles that connect to the North count, the corners that connect to the South do not, so a row that runs along
+/// the loop from a `L` to a `7` crosses it once, and from a `L` to a `J` not at all.
+pub fn sides(width: usize, height: usize, tiles: &FastHashMap<Point, [Direction; 2]>) -> Map<Side> {
+    (0..height as Int)
+        .map(|y| {
+            let mut inside = false;
+            (0..width as Int)
+                .map(|x| match tiles.get(&Point::new(x, y)) {
+                    Some(directions) => {
+                        inside ^= directions.contains(&Direction::North);
+                        Side::Loop
+                    }
+                    None if inside => Side::Inside,
+                    None => Side::Outside,
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Direction::*;
+    use crate::{test, WriteGrid};
+
+    #[test]
+    fn test_square() {
+        let square = vertices(
+            Point::new(0, 0),
+            [(East, 2), (South, 2), (West, 2), (North, 2)],
+        );
+        test!(4, square.len());
+        test!(8, boundary(&square));
+        test!(8, double_area(&square));
+        test!(1, interior(&square));
+        test!(9, lattice_points(&square));
+    }
+
+    #[test]
+    fn test_dig_plan() {
+        // The dig plan of the example of 2023-18
+        let plan = [
+            (East, 6),
+            (South, 5),
+            (West, 2),
+            (South, 2),
+            (East, 2),
+            (South, 2),
+            (West, 5),
+            (North, 2),
+            (West, 1),
+            (North, 2),
+            (East, 2),
+            (North, 3),
+            (West, 2),
+            (North, 2),
+        ];
+        let lagoon = vertices(Point::new(0, 0), plan);
+        test!(38, boundary(&lagoon));
+        test!(62, lattice_points(&lagoon));
+        // The same loop with huge distances, which could not be drawn on a grid
+        let scaled = vertices(Point::new(0, 0), plan.map(|(d, l)| (d, l * 100_000_000)));
+        test!(3_800_000_000 as Int, boundary(&scaled));
+        test!(420_000_001_900_000_001 as Int, lattice_points(&scaled));
+    }
+
+    #[test]
+    fn test_sides() {
+        // A loop with a notch, which is outside
+        let plan = [
+            (East, 6),
+            (South, 4),
+            (West, 2),
+            (North, 2),
+            (West, 2),
+            (South, 2),
+            (West, 2),
+            (North, 4),
+        ];
+        let path: Vec<Point> = plan
+            .iter()
+            .scan(Point::new(0, 0), |point, &(direction, distance)| {
+                let steps: Vec<Point> = (0..distance)
+                    .map(|_| {
+                        *point = point.move_to(&direction);
+                        *point
+                    })
+                    .collect();
+                Some(steps)
+            })
+            .flatten()
+            .collect();
+        let tiles = loop_tiles(&path);
+        test!([South, East], tiles[&Point::new(0, 0)]);
+        let sides = sides(7, 5, &tiles);
+        let symbol = |side: &Side| match side {
+            Side::Loop => '#',
+            Side::Inside => 'I',
+            Side::Outside => '.',
+        };
+        test!(
+            "#######\n#IIIII#\n#I###I#\n#I#.#I#\n###.###",
+            sides.display_with(symbol).to_string()
+        );
+    }
+}
@@ -0,0 +1,241 @@
+// This module contains a min-heap PriorityQueue with decrease-key, because Dijkstra and A*
+// need to lower the priority of a queued state instead of pushing it a second time
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Min-heap of unique keys, popped in order of ascending priority.
+///
+/// Every key is queued at most once, pushing a queued key again only lowers its priority (decrease-key),
+/// the position of every key in the heap is indexed to make that O(log n).
+#[derive(Clone)]
+pub struct PriorityQueue<K, P> {
+    heap: Vec<(K, P)>,
+    indices: HashMap<K, usize>,
+}
+
+/// A PriorityQueue pops its smallest priority first
+pub type MinHeap<K, P> = PriorityQueue<K, P>;
+
+impl<K: Hash + Eq + Clone, P: Ord> PriorityQueue<K, P> {
+    pub fn new() -> PriorityQueue<K, P> {
+        PriorityQueue {
+            heap: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    pub fn with_capacity(capacity: usize) -> PriorityQueue<K, P> {
+        PriorityQueue {
+            heap: Vec::with_capacity(capacity),
+            indices: HashMap::with_capacity(capacity),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    /// Returns the priority of a queued key
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        self.indices.get(key).map(|&index| &self.heap[index].1)
+    }
+
+    /// Queue `key` with `priority`, or lower the priority of an already queued `key`.
+    ///
+    /// Returns true iff `key` was queued or its priority was lowered.
+    pub fn push(&mut self, key: K, priority: P) -> bool {
+        match self.indices.get(&key) {
+            Some(&index) if priority < self.heap[index].1 => {
+                self.heap[index].1 = priority;
+                self.sift_up(index);
+                true
+            }
+            Some(_) => false,
+            None => {
+                self.indices.insert(key.clone(), self.heap.len());
+                self.heap.push((key, priority));
+                self.sift_up(self.heap.len() - 1);
+                true
+            }
+        }
+    }
+
+    /// Returns the key with the smallest priority, without removing it
+    pub fn peek(&self) -> Option<(&K, &P)> {
+        self.heap.first().map(|(key, priority)| (key, priority))
+    }
+
+    /// Remove and return the key with the smallest priority
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        let (key, priority) = self.heap.pop().unwrap();
+        self.indices.remove(&key);
+        if !self.heap.is_empty() {
+            self.sift_down(0);
+        }
+        Some((key, priority))
+    }
+
+    pub fn clear(&mut self) {
+        self.heap.clear();
+        self.indices.clear();
+    }
+
+    /// Swap two entries of the heap, and update their indices
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        *self.indices.get_mut(&self.heap[a].0).unwrap() = a;
+        *self.indices.get_mut(&self.heap[b].0).unwrap() = b;
+    }
+
+    /// Move the entry at `index` up to its place, only updating the indices of the entries it passes once
+    fn sift_up(&mut self, mut index: usize) {
+        let start = index;
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.heap[start].1 >= self.heap[parent].1 {
+                break;
+            }
+            index = parent;
+        }
+        if index == start {
+            return;
+        }
+        // Rotate the path from index to start down by one, moving the entry at start to index
+        let mut hole = start;
+        while hole != index {
+            let parent = (hole - 1) / 2;
+            self.heap.swap(hole, parent);
+            *self.indices.get_mut(&self.heap[hole].0).unwrap() = hole;
+            hole = parent;
+        }
+        *self.indices.get_mut(&self.heap[index].0).unwrap() = index;
+    }
+
+    /// Move the entry at `index` down to its place, only updating the indices of the entries it passes once
+    fn sift_down(&mut self, mut index: usize) {
+        let start = index;
+        loop {
+            let (left, right) = (2 * index + 1, 2 * index + 2);
+            let mut smallest = index;
+            if left < self.heap.len() && self.heap[left].1 < self.heap[smallest].1 {
+                smallest = left;
+            }
+            if right < self.heap.len() && self.heap[right].1 < self.heap[smallest].1 {
+                smallest = right;
+            }
+            if smallest == index {
+                break;
+            }
+            self.heap.swap(index, smallest);
+            *self.indices.get_mut(&self.heap[index].0).unwrap() = index;
+            index = smallest;
+        }
+        if index != start {
+            *self.indices.get_mut(&self.heap[index].0).unwrap() = index;
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, P: Ord> Default for PriorityQueue<K, P> {
+    fn default() -> Self {
+        PriorityQueue::new()
+    }
+}
+
+impl<K: Debug, P: Debug> Debug for PriorityQueue<K, P> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.heap.iter()).finish()
+    }
+}
+
+impl<K: Hash + Eq + Clone, P: Ord> FromIterator<(K, P)> for PriorityQueue<K, P> {
+    fn from_iter<I: IntoIterator<Item = (K, P)>>(iter: I) -> Self {
+        let mut queue = PriorityQueue::new();
+        for (key, priority) in iter {
+            queue.push(key, priority);
+        }
+        queue
+    }
+}
+
+impl<K: Hash + Eq + Clone, P: Ord> Extend<(K, P)> for PriorityQueue<K, P> {
+    fn extend<I: IntoIterator<Item = (K, P)>>(&mut self, iter: I) {
+        for (key, priority) in iter {
+            self.push(key, priority);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_pop_order() {
+        let mut queue: PriorityQueue<char, i64> =
+            [('d', 4), ('a', 1), ('e', 5), ('c', 3), ('b', 2)]
+                .into_iter()
+                .collect();
+        test!(5, queue.len());
+        test!(Some((&'a', &1)), queue.peek());
+        let popped: Vec<char> = std::iter::from_fn(|| queue.pop().map(|(key, _)| key)).collect();
+        test!(vec!['a', 'b', 'c', 'd', 'e'], popped);
+        test!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_decrease_key() {
+        let mut queue: PriorityQueue<&str, i64> = PriorityQueue::new();
+        test!(queue.push("a", 10));
+        test!(queue.push("b", 5));
+        test!(!queue.push("a", 20));
+        test!(Some(&10), queue.priority(&"a"));
+        test!(queue.push("a", 1));
+        test!(2, queue.len());
+        test!(Some(("a", 1)), queue.pop());
+        test!(Some(("b", 5)), queue.pop());
+        test!(queue.pop().is_none());
+    }
+
+    #[test]
+    fn test_against_sorting() {
+        let priorities: Vec<i64> = (0..200).map(|i| (i * 7919 + 13) % 101).collect();
+        let mut queue: PriorityQueue<usize, i64> = PriorityQueue::new();
+        for (key, &priority) in priorities.iter().enumerate() {
+            queue.push(key, priority + 100);
+        }
+        for (key, &priority) in priorities.iter().enumerate().step_by(3) {
+            queue.push(key, priority);
+        }
+        let mut expected: Vec<i64> = priorities
+            .iter()
+            .enumerate()
+            .map(|(key, &priority)| {
+                if key % 3 == 0 {
+                    priority
+                } else {
+                    priority + 100
+                }
+            })
+            .collect();
+        expected.sort();
+        let popped: Vec<i64> = std::iter::from_fn(|| queue.pop().map(|(_, p)| p)).collect();
+        test!(expected, popped);
+    }
+}
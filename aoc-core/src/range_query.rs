@@ -0,0 +1,325 @@
+// This module contains a Fenwick tree and a segment tree, for puzzles with a lot of dynamic range
+// queries, where rescanning the range for every query is O(n²), and a summed-area table for static 2D ranges
+
+use crate::{Grid, Int};
+use std::fmt::Debug;
+use std::ops::Range;
+
+/// Fenwick tree (binary indexed tree) of `Int`, with point updates and prefix sums in O(log n)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fenwick {
+    /// 1-indexed tree, tree[i] is the sum of the `i & -i` values up to and including i
+    tree: Vec<Int>,
+}
+
+impl Fenwick {
+    /// Fenwick tree of `len` zeros
+    pub fn new(len: usize) -> Fenwick {
+        Fenwick {
+            tree: vec![0; len + 1],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tree.len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Add `delta` to the value at `index`
+    pub fn add(&mut self, index: usize, delta: Int) {
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Sum of the values in `0..end`
+    pub fn prefix_sum(&self, end: usize) -> Int {
+        let mut sum = 0;
+        let mut i = end;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    /// Sum of the values in `range`
+    pub fn range_sum(&self, range: Range<usize>) -> Int {
+        self.prefix_sum(range.end) - self.prefix_sum(range.start)
+    }
+
+    /// The value at `index`
+    pub fn get(&self, index: usize) -> Int {
+        self.range_sum(index..index + 1)
+    }
+
+    /// Set the value at `index` to `value`
+    pub fn set(&mut self, index: usize, value: Int) {
+        self.add(index, value - self.get(index));
+    }
+
+    /// Smallest `end` for which `prefix_sum(end) >= target`, assuming all values are non-negative.
+    ///
+    /// With values that count occurrences, this finds the item with a given rank.
+    pub fn lower_bound(&self, target: Int) -> Option<usize> {
+        if target <= 0 {
+            return Some(0);
+        }
+        let mut position = 0;
+        let mut remaining = target;
+        let mut step = (self.tree.len()).next_power_of_two();
+        while step > 0 {
+            let next = position + step;
+            if next < self.tree.len() && self.tree[next] < remaining {
+                position = next;
+                remaining -= self.tree[next];
+            }
+            step /= 2;
+        }
+        (position < self.len()).then_some(position + 1)
+    }
+}
+
+impl From<&[Int]> for Fenwick {
+    fn from(values: &[Int]) -> Self {
+        let mut tree = vec![0; values.len() + 1];
+        tree[1..].copy_from_slice(values);
+        // Build in O(n), by pushing every partial sum to its parent
+        for i in 1..tree.len() {
+            let parent = i + (i & i.wrapping_neg());
+            if parent < tree.len() {
+                tree[parent] += tree[i];
+            }
+        }
+        Fenwick { tree }
+    }
+}
+
+/// Segment tree over an associative operation with an identity, with point updates and range queries in O(log n)
+#[derive(Clone)]
+pub struct SegmentTree<T, F> {
+    len: usize,
+    /// Leaves at `len..2 * len`, node i combines nodes 2i and 2i + 1
+    tree: Vec<T>,
+    identity: T,
+    combine: F,
+}
+
+impl<T: Copy, F: Fn(T, T) -> T> SegmentTree<T, F> {
+    /// Segment tree of `values`, combined by `combine`, for which `identity` is the identity element
+    pub fn new(values: &[T], identity: T, combine: F) -> SegmentTree<T, F> {
+        let len = values.len();
+        let mut tree = vec![identity; 2 * len];
+        tree[len..].copy_from_slice(values);
+        for i in (1..len).rev() {
+            tree[i] = combine(tree[2 * i], tree[2 * i + 1]);
+        }
+        SegmentTree {
+            len,
+            tree,
+            identity,
+            combine,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> T {
+        self.tree[self.len + index]
+    }
+
+    /// Set the value at `index` to `value`
+    pub fn set(&mut self, index: usize, value: T) {
+        let mut i = self.len + index;
+        self.tree[i] = value;
+        while i > 1 {
+            i /= 2;
+            self.tree[i] = (self.combine)(self.tree[2 * i], self.tree[2 * i + 1]);
+        }
+    }
+
+    /// Combination of all values in `range`, or the identity if `range` is empty
+    pub fn query(&self, range: Range<usize>) -> T {
+        assert!(
+            range.end <= self.len,
+            "Range {:?} is out of bounds for a SegmentTree of length {}.",
+            range,
+            self.len
+        );
+        // Combine from both ends inwards, keeping the order for non-commutative operations
+        let (mut left, mut right) = (range.start + self.len, range.end + self.len);
+        let (mut left_result, mut right_result) = (self.identity, self.identity);
+        while left < right {
+            if left % 2 == 1 {
+                left_result = (self.combine)(left_result, self.tree[left]);
+                left += 1;
+            }
+            if right % 2 == 1 {
+                right -= 1;
+                right_result = (self.combine)(self.tree[right], right_result);
+            }
+            left /= 2;
+            right /= 2;
+        }
+        (self.combine)(left_result, right_result)
+    }
+}
+
+impl SegmentTree<Int, fn(Int, Int) -> Int> {
+    /// Segment tree of range minimums
+    pub fn min(values: &[Int]) -> Self {
+        SegmentTree::new(values, Int::MAX, Int::min)
+    }
+
+    /// Segment tree of range maximums
+    pub fn max(values: &[Int]) -> Self {
+        SegmentTree::new(values, Int::MIN, Int::max)
+    }
+
+    /// Segment tree of range sums
+    pub fn sum(values: &[Int]) -> Self {
+        SegmentTree::new(values, 0, |a, b| a + b)
+    }
+}
+
+impl<T: Debug, F> Debug for SegmentTree<T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(&self.tree[self.len..]).finish()
+    }
+}
+
+/// Summed-area table of a `Grid<Int>`, with the sum of any rectangle in O(1)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummedAreaTable {
+    /// sums[y][x] is the sum of the values above and to the left of (x, y), exclusive
+    sums: Grid<Int>,
+}
+
+impl SummedAreaTable {
+    pub fn new(grid: &Grid<Int>) -> SummedAreaTable {
+        let columns = grid.first().map_or(0, Vec::len);
+        let mut sums = vec![vec![0; columns + 1]; grid.len() + 1];
+        for (y, row) in grid.iter().enumerate() {
+            for (x, value) in row.iter().enumerate() {
+                sums[y + 1][x + 1] = value + sums[y][x + 1] + sums[y + 1][x] - sums[y][x];
+            }
+        }
+        SummedAreaTable { sums }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.sums.len() - 1
+    }
+
+    pub fn columns(&self) -> usize {
+        self.sums[0].len() - 1
+    }
+
+    /// Sum of the values in columns `x` of rows `y`
+    pub fn sum(&self, x: Range<usize>, y: Range<usize>) -> Int {
+        self.sums[y.end][x.end] - self.sums[y.start][x.end] - self.sums[y.end][x.start]
+            + self.sums[y.start][x.start]
+    }
+
+    /// The top left (x, y) and the sum of the `size`×`size` window with the largest sum, the first one in reading
+    /// order if there are several. None if the grid is smaller than the window.
+    pub fn max_window(&self, size: usize) -> Option<(usize, usize, Int)> {
+        let (columns, rows) = (self.columns(), self.rows());
+        if size > columns || size > rows {
+            return None;
+        }
+        (0..=rows - size)
+            .flat_map(|y| (0..=columns - size).map(move |x| (x, y)))
+            .map(|(x, y)| (x, y, self.sum(x..x + size, y..y + size)))
+            .reduce(|best, window| if window.2 > best.2 { window } else { best })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    const VALUES: [Int; 8] = [5, 2, 8, 1, 9, 3, 7, 4];
+
+    #[test]
+    fn test_fenwick() {
+        let mut fenwick = Fenwick::from(&VALUES[..]);
+        test!(39, fenwick.prefix_sum(8));
+        test!(15, fenwick.prefix_sum(3));
+        test!(21, fenwick.range_sum(2..6));
+        test!(9, fenwick.get(4));
+        fenwick.add(4, -9);
+        fenwick.set(0, 0);
+        test!(25, fenwick.prefix_sum(8));
+        for end in 0..=8 {
+            test!(
+                fenwick.prefix_sum(end),
+                Fenwick::from(&[0, 2, 8, 1, 0, 3, 7, 4][..]).prefix_sum(end),
+                "prefix_sum({})",
+                end
+            );
+        }
+    }
+
+    #[test]
+    fn test_fenwick_lower_bound() {
+        // Counts of the values 0..5, find the value with a given rank
+        let counts = Fenwick::from(&[0, 2, 0, 3, 1][..]);
+        test!(Some(2), counts.lower_bound(1));
+        test!(Some(2), counts.lower_bound(2));
+        test!(Some(4), counts.lower_bound(3));
+        test!(Some(5), counts.lower_bound(6));
+        test!(counts.lower_bound(7).is_none());
+    }
+
+    #[test]
+    fn test_segment_tree() {
+        let mut min = SegmentTree::min(&VALUES);
+        let max = SegmentTree::max(&VALUES);
+        let sum = SegmentTree::sum(&VALUES);
+        test!(1, min.query(0..8));
+        test!(2, min.query(0..3));
+        test!(9, max.query(2..6));
+        test!(21, sum.query(2..6));
+        test!(Int::MAX, min.query(3..3));
+        min.set(3, 10);
+        test!(2, min.query(0..8));
+        test!(3, min.query(3..6));
+        // Non-commutative operations keep their order, concatenate digits as (number, 10^digits)
+        let digits: Vec<(Int, Int)> = (1..=5).map(|digit| (digit, 10)).collect();
+        let concatenation = SegmentTree::new(&digits, (0, 1), |(a, a_power), (b, b_power)| {
+            (a * b_power + b, a_power * b_power)
+        });
+        test!(234, concatenation.query(1..4).0);
+        test!(12345, concatenation.query(0..5).0);
+    }
+
+    #[test]
+    fn test_summed_area_table() {
+        let grid: Grid<Int> = vec![vec![1, 2, 3, 4], vec![5, -6, 7, 8], vec![9, 10, -11, 12]];
+        let table = SummedAreaTable::new(&grid);
+        test!((4, 3), (table.columns(), table.rows()));
+        test!(44, table.sum(0..4, 0..3));
+        test!(-6, table.sum(1..2, 1..2));
+        test!(0, table.sum(2..2, 0..3));
+        for (x, y) in [(0..2, 1..3), (1..4, 0..2), (3..4, 0..3)] {
+            let brute_force: Int = grid[y.clone()].iter().flat_map(|row| &row[x.clone()]).sum();
+            test!(brute_force, table.sum(x, y));
+        }
+        test!(Some((2, 0, 22)), table.max_window(2));
+        test!(Some((3, 2, 12)), table.max_window(1));
+        test!(table.max_window(4).is_none());
+    }
+}
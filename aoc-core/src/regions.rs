@@ -0,0 +1,141 @@
+// This module labels the connected regions of equal cells of a Map, and measures their area, perimeter and number
+// of sides, for fencing-cost style puzzles, and to validate flood fills
+
+use crate::grid::{Direction, Map, Point};
+use crate::hash::FastHashSet;
+use crate::Int;
+
+/// Cells of a Map that are connected horizontally or vertically, and have the same value
+#[derive(Debug, Clone)]
+pub struct Region {
+    /// Index of the Region in `Map::regions`
+    pub label: usize,
+    /// Points of the cells, in the order in which they were filled
+    pub points: Vec<Point>,
+    cells: FastHashSet<Point>,
+}
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::North,
+    Direction::East,
+    Direction::South,
+    Direction::West,
+];
+
+impl Region {
+    pub fn contains(&self, point: &Point) -> bool {
+        self.cells.contains(point)
+    }
+
+    /// Number of cells
+    pub fn area(&self) -> usize {
+        self.points.len()
+    }
+
+    /// Number of cell edges between a cell of the Region and a cell outside it, or the edge of the Map
+    pub fn perimeter(&self) -> usize {
+        self.points
+            .iter()
+            .flat_map(|point| DIRECTIONS.iter().map(move |d| point.move_to(d)))
+            .filter(|neighbor| !self.contains(neighbor))
+            .count()
+    }
+
+    /// Number of straight sides of the fence around the Region, including the fences around its holes, which is
+    /// the number of its corners
+    pub fn sides(&self) -> usize {
+        let is_in = |point: &Point, dx: Int, dy: Int| self.contains(&point.translate(dx, dy));
+        self.points
+            .iter()
+            .map(|point| {
+                [(-1, -1), (1, -1), (1, 1), (-1, 1)]
+                    .iter()
+                    .filter(|&&(dx, dy)| {
+                        let (horizontal, vertical) = (is_in(point, dx, 0), is_in(point, 0, dy));
+                        // An outer corner, or an inner corner of which the diagonal cell is outside
+                        (!horizontal && !vertical)
+                            || (horizontal && vertical && !is_in(point, dx, dy))
+                    })
+                    .count()
+            })
+            .sum()
+    }
+}
+
+impl<T: PartialEq> Map<T> {
+    /// Label every cell with the Region it belongs to, by flood filling from the first cell that is not yet labeled,
+    /// row by row
+    pub fn regions(&self) -> Vec<Region> {
+        let mut labeled: FastHashSet<Point> = FastHashSet::default();
+        let mut regions: Vec<Region> = Vec::new();
+        for y in 0..self.get_rows() {
+            for x in 0..self.get_columns() {
+                let start = Point::new(x as Int, y as Int);
+                if labeled.contains(&start) {
+                    continue;
+                }
+                let value = self.point_get(&start).unwrap();
+                let (mut points, mut stack) = (Vec::new(), vec![start]);
+                labeled.insert(start);
+                while let Some(point) = stack.pop() {
+                    points.push(point);
+                    for neighbor in self.get_adjacent(&point) {
+                        if self.point_get(&neighbor) == Some(value) && labeled.insert(neighbor) {
+                            stack.push(neighbor);
+                        }
+                    }
+                }
+                regions.push(Region {
+                    label: regions.len(),
+                    cells: points.iter().copied().collect(),
+                    points,
+                });
+            }
+        }
+        regions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::{Map, Point};
+    use crate::test;
+
+    fn map(rows: &str) -> Map<char> {
+        rows.lines().map(|row| row.chars().collect()).collect()
+    }
+
+    /// Area, perimeter and sides of every Region, by its value
+    fn metrics(rows: &str) -> Vec<(char, usize, usize, usize)> {
+        let map = map(rows);
+        map.regions()
+            .iter()
+            .map(|region| {
+                let value = *map.point_get(&region.points[0]).unwrap();
+                (value, region.area(), region.perimeter(), region.sides())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_regions() {
+        test!(
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4)
+            ],
+            metrics("AAAA\nBBCD\nBBCC\nEEEC")
+        );
+        // The sides of the holes count too
+        let holes = metrics("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO");
+        test!(('O', 21, 36, 20), holes[0]);
+        test!(5, holes.len());
+        let region = &map("ab\nba").regions()[0];
+        test!(0, region.label);
+        test!(region.contains(&Point::new(0, 0)));
+        test!(!region.contains(&Point::new(1, 1)));
+    }
+}
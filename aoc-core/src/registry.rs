@@ -0,0 +1,168 @@
+// This module contains the view of a Solution in which its Parsed type is erased, so solutions of different days
+// can be stored in one registry and run by the same runner. Every Solution has it, days only implement Solution.
+
+use crate::solution::*;
+use std::any::Any;
+
+/// Result of the parse step of a Solution, of which the type is erased
+pub struct ParsedInput(Box<dyn Any + Send + Sync>);
+
+/// A Solution of which the Parsed type is erased, see `SolutionBox`.
+///
+/// Its methods are those of Solution, `parse` and `solve_parsed` pass the result of the parse step around as a
+/// ParsedInput, which is only valid for the Solution that parsed it.
+pub trait DynSolution: Send + Sync {
+    fn year(&self) -> Year;
+
+    fn day(&self) -> Day;
+
+    fn tags(&self) -> &'static [Tag];
+
+    fn explain(&self, input: Input) -> Vec<String>;
+
+    fn timeout(&self) -> Option<Duration>;
+
+    fn threads(&self) -> usize;
+
+    fn cache_version(&self, part: Part) -> Option<&'static str>;
+
+    fn define_examples(&self) -> Vec<Example>;
+
+    fn run_examples(&self) -> bool;
+
+    fn has_parse_step(&self) -> bool;
+
+    /// Parse `input` with the parse step of this Solution
+    fn parse(&self, input: Input, params: &Params) -> AocResult<ParsedInput>;
+
+    /// Solve `part` from the `parsed` input, which must be parsed by this Solution
+    fn solve_parsed(&self, parsed: &ParsedInput, part: Part, params: &Params) -> AocResult<Answer>;
+
+    fn repl(&self, parsed: &ParsedInput) -> Option<Box<dyn Repl>>;
+
+    /// Parse `input` and solve `part` from it
+    fn solve(&self, input: Input, part: Part, params: &Params) -> AocResult<Answer> {
+        self.solve_parsed(&self.parse(input, params)?, part, params)
+    }
+}
+
+impl<S: Solution + 'static> DynSolution for S {
+    fn year(&self) -> Year {
+        Solution::year(self)
+    }
+
+    fn day(&self) -> Day {
+        Solution::day(self)
+    }
+
+    fn tags(&self) -> &'static [Tag] {
+        Solution::tags(self)
+    }
+
+    fn explain(&self, input: Input) -> Vec<String> {
+        Solution::explain(self, input)
+    }
+
+    fn timeout(&self) -> Option<Duration> {
+        Solution::timeout(self)
+    }
+
+    fn threads(&self) -> usize {
+        Solution::threads(self)
+    }
+
+    fn cache_version(&self, part: Part) -> Option<&'static str> {
+        Solution::cache_version(self, part)
+    }
+
+    fn define_examples(&self) -> Vec<Example> {
+        Solution::define_examples(self)
+    }
+
+    fn run_examples(&self) -> bool {
+        Solution::run_examples(self)
+    }
+
+    fn has_parse_step(&self) -> bool {
+        Solution::has_parse_step(self)
+    }
+
+    fn parse(&self, input: Input, params: &Params) -> AocResult<ParsedInput> {
+        let parsed = Solution::parse(self, input, params)?;
+        Ok(ParsedInput(Box::new(parsed)))
+    }
+
+    fn solve_parsed(&self, parsed: &ParsedInput, part: Part, params: &Params) -> AocResult<Answer> {
+        let parsed = downcast::<S>(parsed);
+        match part {
+            Part::One => self.solve_part_one(parsed, params),
+            Part::Two => self.solve_part_two(parsed, params),
+        }
+    }
+
+    fn repl(&self, parsed: &ParsedInput) -> Option<Box<dyn Repl>> {
+        Solution::repl(self, downcast::<S>(parsed))
+    }
+}
+
+/// The Parsed input of `S` in `parsed`, which only another Solution can have parsed into something else
+fn downcast<S: Solution>(parsed: &ParsedInput) -> &S::Parsed {
+    parsed
+        .0
+        .downcast_ref()
+        .expect("The input is parsed by another Solution.")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::sync::Arc;
+
+    /// Solution that parses its input into numbers, and fails on anything else
+    #[derive(Default)]
+    struct Numbers {}
+
+    impl Solution for Numbers {
+        type Parsed = Vec<Int>;
+
+        fn parse(&self, input: Input, _params: &Params) -> AocResult<Vec<Int>> {
+            input
+                .split_whitespace()
+                .map(|number| number.parse().map_err(AocError::parse("a number")))
+                .collect()
+        }
+        fn solve_part_one(&self, numbers: &Vec<Int>, _params: &Params) -> AocResult<Answer> {
+            Ok(numbers.iter().sum::<Int>().into())
+        }
+        fn solve_part_two(&self, numbers: &Vec<Int>, _params: &Params) -> AocResult<Answer> {
+            Ok(numbers.iter().product::<Int>().into())
+        }
+        fn year(&self) -> Year {
+            2023
+        }
+        fn day(&self) -> Day {
+            0
+        }
+    }
+
+    #[test]
+    fn test_erased() {
+        let solution: SolutionBox = Arc::new(Numbers {});
+        let parsed = solution.parse("2 3 4", &Params::INPUT).unwrap();
+        test!(
+            Answer::Int(9),
+            solution
+                .solve_parsed(&parsed, Part::One, &Params::INPUT)
+                .unwrap()
+        );
+        test!(
+            Answer::Int(24),
+            solution
+                .solve_parsed(&parsed, Part::Two, &Params::INPUT)
+                .unwrap()
+        );
+        test!(solution.has_parse_step());
+        test!(solution.solve("2 x", Part::One, &Params::INPUT).is_err());
+    }
+}
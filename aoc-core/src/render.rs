@@ -0,0 +1,86 @@
+// This module renders grids straight into a fmt::Write, like a Formatter or a String, instead of
+// collecting a String per row and joining them, which allocates a lot for big grids and many frames
+
+use std::fmt::{self, Display, Write};
+
+/// Rendering of a grid, one line per row, without a trailing newline
+pub trait WriteGrid<T> {
+    /// Write every cell with `cell`
+    fn write_with(&self, out: &mut impl Write, cell: impl Fn(&T) -> char) -> fmt::Result;
+
+    /// Write every cell with its Display implementation
+    fn write_to(&self, out: &mut impl Write) -> fmt::Result
+    where
+        T: Display;
+
+    /// Display the grid with `cell`, e.g. in `format!` or `write!`, without rendering it into a String first
+    fn display_with<F: Fn(&T) -> char>(&self, cell: F) -> DisplayGrid<'_, T, F>;
+}
+
+impl<T> WriteGrid<T> for [Vec<T>] {
+    fn write_with(&self, out: &mut impl Write, cell: impl Fn(&T) -> char) -> fmt::Result {
+        for (y, row) in self.iter().enumerate() {
+            if y > 0 {
+                out.write_char('\n')?;
+            }
+            for value in row {
+                out.write_char(cell(value))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn write_to(&self, out: &mut impl Write) -> fmt::Result
+    where
+        T: Display,
+    {
+        for (y, row) in self.iter().enumerate() {
+            if y > 0 {
+                out.write_char('\n')?;
+            }
+            for value in row {
+                write!(out, "{}", value)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn display_with<F: Fn(&T) -> char>(&self, cell: F) -> DisplayGrid<'_, T, F> {
+        DisplayGrid { grid: self, cell }
+    }
+}
+
+/// Grid that is displayed by rendering every cell with `cell`, see `WriteGrid::display_with`
+pub struct DisplayGrid<'a, T, F> {
+    grid: &'a [Vec<T>],
+    cell: F,
+}
+
+impl<T, F: Fn(&T) -> char> Display for DisplayGrid<'_, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.grid.write_with(f, &self.cell)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test, Grid};
+
+    #[test]
+    fn test_write_grid() {
+        let grid: Grid<bool> = vec![vec![true, false], vec![false, true]];
+        let mut rendered = String::new();
+        grid.write_with(&mut rendered, |&on| if on { '#' } else { '.' })
+            .unwrap();
+        test!("#.\n.#", rendered);
+        test!(
+            "[#.\n.#]",
+            format!("[{}]", grid.display_with(|&on| if on { '#' } else { '.' }))
+        );
+        let digits: Grid<u8> = vec![vec![1, 2], vec![3, 4]];
+        let mut rendered = String::new();
+        digits.write_to(&mut rendered).unwrap();
+        test!("12\n34", rendered);
+    }
+}
@@ -0,0 +1,116 @@
+#![allow(dead_code)]
+// This module contains a small command loop over the parsed input of a day, so puzzle state can be explored
+// interactively, instead of recompiling with dbg! statements
+
+use std::io::{self, BufRead, Write};
+
+/// The state of a day that can be explored with commands, see `Solution::repl`
+pub trait Repl {
+    /// Usage and description of every command, e.g. `("cycle [N]", "Run N spin cycles")`, the first word of
+    /// the usage is the name of the command
+    fn commands(&self) -> &'static [(&'static str, &'static str)];
+
+    /// Run the command `name` with `args`, and return what to print, or a message explaining why it failed
+    fn run(&mut self, name: &str, args: &[&str]) -> Result<String, String>;
+}
+
+/// Commands that every Repl understands
+const BUILT_IN_COMMANDS: [(&str, &str); 2] = [
+    ("help", "Show the commands"),
+    ("quit", "Leave the REPL, like end of input"),
+];
+
+fn help(repl: &dyn Repl) -> String {
+    let commands: Vec<(&str, &str)> = repl
+        .commands()
+        .iter()
+        .chain(BUILT_IN_COMMANDS.iter())
+        .copied()
+        .collect();
+    let width = commands.iter().map(|(usage, _)| usage.len()).max();
+    commands
+        .iter()
+        .map(|(usage, description)| {
+            format!("{:<w$}  {}", usage, description, w = width.unwrap_or(0))
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Read commands from `input` and write what they print to `output`, until `quit` or the end of `input`
+pub fn run(
+    repl: &mut dyn Repl,
+    prompt: &str,
+    mut input: impl BufRead,
+    output: &mut impl Write,
+) -> io::Result<()> {
+    writeln!(output, "Type `help` to show the commands.")?;
+    let mut line = String::new();
+    loop {
+        write!(output, "{}> ", prompt)?;
+        output.flush()?;
+        line.clear();
+        if input.read_line(&mut line)? == 0 {
+            return writeln!(output);
+        }
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&name, args)) = words.split_first() else {
+            continue;
+        };
+        let is_command = repl
+            .commands()
+            .iter()
+            .any(|(usage, _)| usage.split(' ').next() == Some(name));
+        let printed = match name {
+            "help" => Ok(help(repl)),
+            "quit" | "exit" => return Ok(()),
+            name if is_command => repl.run(name, args),
+            name => Err(format!("Unknown command '{}', try `help`.", name)),
+        };
+        let printed = printed.unwrap_or_else(|message| message);
+        writeln!(output, "{}", printed.trim_end())?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    struct Counter(i64);
+
+    impl Repl for Counter {
+        fn commands(&self) -> &'static [(&'static str, &'static str)] {
+            &[("add N", "Add N to the counter")]
+        }
+
+        fn run(&mut self, _name: &str, args: &[&str]) -> Result<String, String> {
+            let n: i64 = args
+                .first()
+                .and_then(|n| n.parse().ok())
+                .ok_or("Usage: add N")?;
+            self.0 += n;
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn test_run() {
+        let mut output = Vec::new();
+        let input = "add 2\n\nadd x\nsubtract 1\nadd 3\nquit\nadd 4\n".as_bytes();
+        run(&mut Counter(0), "counter", input, &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().skip(1).collect();
+        test!(
+            vec![
+                "counter> 2",
+                "counter> counter> Usage: add N",
+                "counter> Unknown command 'subtract', try `help`.",
+                "counter> 5",
+                "counter> "
+            ],
+            lines
+        );
+        test!(help(&Counter(0)).starts_with("add N  Add N to the counter\nhelp   "));
+    }
+}
@@ -0,0 +1,180 @@
+// This module contains polynomial rolling hashes and windowed helpers, because substring and pattern
+// puzzles (marker detection, repeated sequences) need to compare a lot of windows of the same length
+
+use crate::hash::FastHashMap;
+use std::hash::Hash;
+use std::ops::Range;
+
+/// Two (modulus, base) pairs, a collision has to happen for both to fool a DoubleHash
+const MODULI: [u64; 2] = [1_000_000_007, 998_244_353];
+const BASES: [u64; 2] = [131, 137];
+
+/// Hash of a window under both moduli
+pub type DoubleHash = (u64, u64);
+
+/// Prefix hashes of a sequence of bytes, to hash any window in O(1)
+#[derive(Debug, Clone)]
+pub struct RollingHash {
+    prefixes: [Vec<u64>; 2],
+    powers: [Vec<u64>; 2],
+}
+
+impl RollingHash {
+    pub fn new(bytes: &[u8]) -> RollingHash {
+        let build = |i: usize| {
+            let (modulus, base) = (MODULI[i], BASES[i]);
+            let mut prefixes = Vec::with_capacity(bytes.len() + 1);
+            let mut powers = Vec::with_capacity(bytes.len() + 1);
+            prefixes.push(0);
+            powers.push(1);
+            for &byte in bytes {
+                prefixes.push((prefixes.last().unwrap() * base + byte as u64 + 1) % modulus);
+                powers.push(powers.last().unwrap() * base % modulus);
+            }
+            (prefixes, powers)
+        };
+        let ((prefixes_a, powers_a), (prefixes_b, powers_b)) = (build(0), build(1));
+        RollingHash {
+            prefixes: [prefixes_a, prefixes_b],
+            powers: [powers_a, powers_b],
+        }
+    }
+
+    /// Length of the hashed sequence
+    pub fn len(&self) -> usize {
+        self.prefixes[0].len() - 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Hash of the window `range`, equal windows always have equal hashes
+    pub fn hash(&self, range: Range<usize>) -> DoubleHash {
+        let single = |i: usize| {
+            let modulus = MODULI[i];
+            let prefix = &self.prefixes[i];
+            (prefix[range.end] + modulus * modulus
+                - prefix[range.start] * self.powers[i][range.len()] % modulus)
+                % modulus
+        };
+        (single(0), single(1))
+    }
+
+    /// Hashes of all windows of `length`, by start index
+    pub fn window_hashes(&self, length: usize) -> impl Iterator<Item = DoubleHash> + '_ {
+        (0..(self.len() + 1).saturating_sub(length))
+            .map(move |start| self.hash(start..start + length))
+    }
+}
+
+/// Start index of the first window of `length` in which every item is distinct, in O(n)
+pub fn first_distinct_window<T: Hash + Eq>(items: &[T], length: usize) -> Option<usize> {
+    let mut counts: FastHashMap<&T, usize> = FastHashMap::default();
+    let mut duplicates = 0;
+    for (end, item) in items.iter().enumerate() {
+        let count = counts.entry(item).or_default();
+        *count += 1;
+        if *count == 2 {
+            duplicates += 1;
+        }
+        if end >= length {
+            let count = counts.get_mut(&items[end - length]).unwrap();
+            *count -= 1;
+            if *count == 1 {
+                duplicates -= 1;
+            }
+        }
+        if end + 1 >= length && duplicates == 0 {
+            return Some(end + 1 - length);
+        }
+    }
+    None
+}
+
+/// Start indices of the first two equal windows of `length`, compared by hash and verified byte by byte
+pub fn find_repeated_window(bytes: &[u8], length: usize) -> Option<(usize, usize)> {
+    if length == 0 || length > bytes.len() {
+        return None;
+    }
+    let rolling_hash = RollingHash::new(bytes);
+    let mut seen: FastHashMap<DoubleHash, Vec<usize>> = FastHashMap::default();
+    for (start, hash) in rolling_hash.window_hashes(length).enumerate() {
+        let window = &bytes[start..start + length];
+        let starts = seen.entry(hash).or_default();
+        if let Some(&first) = starts
+            .iter()
+            .find(|&&first| &bytes[first..first + length] == window)
+        {
+            return Some((first, start));
+        }
+        starts.push(start);
+    }
+    None
+}
+
+/// Range of the longest window that occurs at least twice, possibly overlapping, in O(n log n)
+pub fn longest_repeated_window(bytes: &[u8]) -> Option<Range<usize>> {
+    let rolling_hash = RollingHash::new(bytes);
+    let repeated = |length: usize| -> Option<usize> {
+        let mut first_starts: FastHashMap<DoubleHash, usize> = FastHashMap::default();
+        rolling_hash
+            .window_hashes(length)
+            .enumerate()
+            .find_map(|(start, hash)| first_starts.insert(hash, start))
+    };
+    // A repeated window of length n also contains a repeated window of length n - 1
+    let (mut low, mut high) = (1, bytes.len());
+    let mut longest = None;
+    while low <= high {
+        let length = (low + high) / 2;
+        match repeated(length) {
+            Some(start) => {
+                longest = Some(start..start + length);
+                low = length + 1;
+            }
+            None => high = length - 1,
+        }
+    }
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_rolling_hash() {
+        let bytes = b"abcabcabd";
+        let rolling_hash = RollingHash::new(bytes);
+        test!(9, rolling_hash.len());
+        test!(rolling_hash.hash(0..3) == rolling_hash.hash(3..6));
+        test!(rolling_hash.hash(0..4) == rolling_hash.hash(3..7));
+        test!(rolling_hash.hash(0..3) != rolling_hash.hash(6..9));
+        test!(rolling_hash.hash(0..3) == RollingHash::new(b"abc").hash(0..3));
+        test!(7, rolling_hash.window_hashes(3).count());
+        test!(0, rolling_hash.window_hashes(10).count());
+        // Leading zero bytes must not be ignored
+        test!(RollingHash::new(b"\0a").hash(0..2) != RollingHash::new(b"a").hash(0..1));
+    }
+
+    #[test]
+    fn test_first_distinct_window() {
+        let marker = |input: &str, length: usize| {
+            first_distinct_window(input.as_bytes(), length).map(|start| start + length)
+        };
+        test!(Some(7), marker("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4));
+        test!(Some(5), marker("bvwbjplbgvbhsrlpgdmjqwftvncz", 4));
+        test!(Some(19), marker("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 14));
+        test!(marker("aaaa", 2).is_none());
+    }
+
+    #[test]
+    fn test_repeated_windows() {
+        test!(Some((0, 4)), find_repeated_window(b"abcdabcd", 4));
+        test!(find_repeated_window(b"abcdabce", 4).is_none());
+        test!(Some(1..6), longest_repeated_window(b"banana_anana"));
+        test!(longest_repeated_window(b"abc").is_none());
+    }
+}
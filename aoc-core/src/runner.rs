@@ -0,0 +1,875 @@
+// This module runs Advent of Code solutions, and collects their results into TestResults
+
+use crate::answers::Answers;
+use crate::cache::Cache;
+use crate::history::History;
+use crate::hooks::{DayEvent, FailureEvent, Hooks, PartEvent};
+use crate::memory;
+use crate::registry::ParsedInput;
+use crate::solution::*;
+use crate::Inputs;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+
+/// Part of an Advent of Code problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    /// Number of this part, as used by adventofcode.com
+    pub fn number(self) -> u8 {
+        self as u8 + 1
+    }
+}
+
+impl TestResult {
+    /// The TestStatus of a single part
+    pub fn part(&self, part: Part) -> &TestStatus {
+        match part {
+            Part::One => &self.p1,
+            Part::Two => &self.p2,
+        }
+    }
+
+    /// Peak heap memory of a single part in bytes, if memory is tracked
+    pub fn memory(&self, part: Part) -> Option<usize> {
+        match part {
+            Part::One => self.p1_memory,
+            Part::Two => self.p2_memory,
+        }
+    }
+
+    /// Total duration of both parts, if any part ran
+    pub fn total(&self) -> Option<Duration> {
+        match (self.p1.duration(), self.p2.duration()) {
+            (None, None) => None,
+            (one, two) => Some(one.unwrap_or_default() + two.unwrap_or_default()),
+        }
+    }
+}
+
+/// Exit status of the process, so shell scripts and hooks can gate on the correctness of solutions.
+///
+/// Ordered from least to most severe, the most severe status of all parts is used. Code 2 is skipped, because clap
+/// exits with it on invalid arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ExitStatus {
+    Success = 0,
+    WrongAnswer = 1,
+    Timeout = 3,
+    Error = 4,
+}
+
+impl ExitStatus {
+    /// The ExitStatus of a single part, a part that did not run counts as a Success
+    fn of_part(status: &TestStatus) -> ExitStatus {
+        match status {
+            TestStatus::Success(_, _)
+            | TestStatus::Unverified(_, _)
+            | TestStatus::Cached(_)
+            | TestStatus::Unknown => ExitStatus::Success,
+            TestStatus::Failed(_, _) => ExitStatus::WrongAnswer,
+            TestStatus::Timeout(_) => ExitStatus::Timeout,
+            TestStatus::Error(_) => ExitStatus::Error,
+        }
+    }
+
+    /// The most severe ExitStatus of all parts of all TestResults
+    pub fn of(test_results: &[TestResult]) -> ExitStatus {
+        test_results
+            .iter()
+            .flat_map(|test_result| [&test_result.examples, &test_result.p1, &test_result.p2])
+            .map(ExitStatus::of_part)
+            .max()
+            .unwrap_or(ExitStatus::Success)
+    }
+}
+
+impl From<ExitStatus> for std::process::ExitCode {
+    fn from(status: ExitStatus) -> Self {
+        std::process::ExitCode::from(status as u8)
+    }
+}
+
+/// Order in which solutions are run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Order {
+    /// By year and day
+    #[default]
+    Chronological,
+    /// Fast days first, for immediate feedback, days without a timing history run last
+    FastestFirst,
+    /// Slow days first, days without a timing history run first
+    SlowestFirst,
+    /// Randomly, to shake out hidden state shared between days
+    Shuffle,
+}
+
+/// Sort `solutions` in `order`, using the timing `history`, and `seed` to shuffle
+pub fn sort(solutions: &mut [SolutionBox], order: Order, history: &History, seed: u64) {
+    let duration = |solution: &SolutionBox| {
+        history
+            .get(solution.year(), solution.day())
+            .and_then(|timing| timing.total())
+            .unwrap_or(Duration::MAX)
+    };
+    match order {
+        Order::Chronological => solutions.sort_by_key(|solution| (solution.year(), solution.day())),
+        Order::FastestFirst => solutions.sort_by_key(duration),
+        Order::SlowestFirst => {
+            solutions.sort_by_key(|solution| std::cmp::Reverse(duration(solution)))
+        }
+        Order::Shuffle => solutions.shuffle(&mut StdRng::seed_from_u64(seed)),
+    }
+}
+
+/// Estimated wall-clock duration of running `solutions` in order on `jobs` threads, by their durations in `history`,
+/// in which every solution starts as soon as the number of `threads` it uses are free, like in `run_all`. Returns the
+/// estimate, and the number of solutions without a timing history, which are left out of it.
+pub fn eta(solutions: &[SolutionBox], history: &History, jobs: usize) -> (Duration, usize) {
+    let jobs = jobs.max(1);
+    // When every thread is free, sorted
+    let mut threads = vec![Duration::ZERO; jobs];
+    let mut unknown = 0;
+    for solution in solutions {
+        let timing = history.get(solution.year(), solution.day());
+        let Some(duration) = timing.and_then(|timing| timing.total()) else {
+            unknown += 1;
+            continue;
+        };
+        let needed = solution.threads().clamp(1, jobs);
+        let start = threads[needed - 1];
+        threads[..needed].fill(start + duration);
+        threads.sort();
+    }
+    (threads.into_iter().max().unwrap(), unknown)
+}
+
+/// Selection of days or years, like `1-10,14,16`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Selection<T>(Vec<RangeInclusive<T>>);
+
+impl<T: PartialOrd> Selection<T> {
+    pub fn contains(&self, value: &T) -> bool {
+        self.0.iter().any(|range| range.contains(value))
+    }
+}
+
+impl<T: FromStr + PartialOrd + Copy> FromStr for Selection<T> {
+    type Err = String;
+
+    fn from_str(selection: &str) -> Result<Self, Self::Err> {
+        let parse = |value: &str| {
+            value
+                .trim()
+                .parse::<T>()
+                .map_err(|_| format!("Invalid selection: '{}'.", value.trim()))
+        };
+        selection
+            .split(',')
+            .map(|range| match range.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end) = (parse(start)?, parse(end)?);
+                    match start <= end {
+                        true => Ok(start..=end),
+                        false => Err(format!("Invalid selection range: '{}'.", range.trim())),
+                    }
+                }
+                None => parse(range).map(|value| value..=value),
+            })
+            .collect::<Result<_, _>>()
+            .map(Selection)
+    }
+}
+
+impl<T: Display + PartialEq> Display for Selection<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, range) in self.0.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            match range.start() == range.end() {
+                true => write!(f, "{}{}", separator, range.start())?,
+                false => write!(f, "{}{}-{}", separator, range.start(), range.end())?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Options that control how solutions are run
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    /// Abandon a part that takes longer than this, unless the Solution overrides it
+    pub timeout: Option<Duration>,
+    /// Do not run the examples, which keeps stdout clean for `--raw`
+    pub skip_examples: bool,
+    /// Number of untimed iterations of each part before the measured one
+    pub warmup: usize,
+    /// Expected answers, a part without an expected answer for its input is `TestStatus::Unverified`
+    pub answers: Answers,
+    /// Use the cached answers of parts that opted in, instead of solving them again
+    pub use_cache: bool,
+    /// Hooks that fire before and after every part
+    pub hooks: Hooks,
+    /// Where the puzzle inputs are cached, and the session cookie to download them with
+    pub inputs: Inputs,
+    /// Start no more solutions once one fails, or can not run, the solutions that already started still finish
+    pub fail_fast: bool,
+}
+
+/// Parse a Duration like `30s`, `500ms` or `2m`, a plain number is a number of seconds
+pub fn parse_duration(duration: &str) -> Result<Duration, String> {
+    let duration = duration.trim();
+    let split_at = duration
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(duration.len());
+    let (value, unit) = duration.split_at(split_at);
+    let value: f64 = value
+        .parse()
+        .map_err(|_| format!("Invalid duration: '{}'.", duration))?;
+    let seconds = match unit {
+        "ms" => value / 1000.0,
+        "" | "s" => value,
+        "m" => value * 60.0,
+        _ => return Err(format!("Invalid duration unit: '{}'.", unit)),
+    };
+    Ok(Duration::from_secs_f64(seconds))
+}
+
+/// Run `job` on a worker thread, and abandon it if it does not finish within `timeout`.
+///
+/// An abandoned worker thread keeps running in the background until the process exits.
+fn run_on_worker<T: Send + 'static>(
+    job: impl FnOnce() -> T + Send + 'static,
+    timeout: Option<Duration>,
+) -> Result<T, RecvTimeoutError> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        // Sending fails if this job has been abandoned, which is fine
+        let _ = sender.send(job());
+    });
+    match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+    }
+}
+
+/// TestStatus of a job that did not finish on its worker thread
+fn unfinished(error: RecvTimeoutError, duration: Duration) -> TestStatus {
+    match error {
+        RecvTimeoutError::Timeout => TestStatus::Timeout(duration),
+        // The worker thread panicked
+        RecvTimeoutError::Disconnected => TestStatus::Error(duration),
+    }
+}
+
+/// Solve a part of a Solution from its `parsed` input on a worker thread, abandon it if it does not
+/// finish within `timeout`, then compare its answer with the `expected` answer, if there is one.
+///
+/// The part is first solved `warmup` times without being measured, which must finish within `timeout` times the
+/// number of iterations all together, so a single iteration may take longer than `timeout`.
+/// Returns the peak memory of the measured iteration too, if memory is tracked and the part finished.
+fn run_part(
+    solution: &SolutionBox,
+    part: Part,
+    parsed: Arc<ParsedInput>,
+    expected: Option<&Answer>,
+    timeout: Option<Duration>,
+    warmup: usize,
+) -> (TestStatus, Option<usize>) {
+    let worker = Arc::clone(solution);
+    let instant = Instant::now();
+    let solve = move || {
+        let solve = || worker.solve_parsed(&parsed, part, &Params::INPUT);
+        for _ in 0..warmup {
+            if let Err(error) = solve() {
+                return (Err(error), Duration::ZERO, None);
+            }
+        }
+        let instant = Instant::now();
+        let (answer, memory) = memory::measure(solve);
+        (answer, instant.elapsed(), memory)
+    };
+    // A timeout that overflows a Duration is no timeout at all
+    let iterations = u32::try_from(warmup)
+        .ok()
+        .and_then(|warmup| warmup.checked_add(1));
+    let timeout = timeout.and_then(|timeout| timeout.checked_mul(iterations?));
+    match run_on_worker(solve, timeout) {
+        Ok((Ok(answer), duration, memory)) => match expected {
+            Some(expected) if answer == *expected => {
+                (TestStatus::Success(duration, answer), memory)
+            }
+            Some(_) => (TestStatus::Failed(duration, answer), memory),
+            None => (TestStatus::Unverified(duration, answer), memory),
+        },
+        Ok((Err(error), _, _)) => {
+            let (year, day) = (solution.year(), solution.day());
+            tracing::error!("AoC {}-{:02} part {}: {}", year, day, part.number(), error);
+            (TestStatus::Error(instant.elapsed()), None)
+        }
+        Err(error) => (unfinished(error, instant.elapsed()), None),
+    }
+}
+
+/// Run the examples, the parse step and both parts of a Solution, fails if the puzzle input can not be obtained.
+///
+/// Parts that opted in to the `cache` use their cached answer instead, unless it is not the expected answer,
+/// and cache their answer if it is not wrong.
+pub fn run(
+    solution: &SolutionBox,
+    options: &RunOptions,
+    cache: &Mutex<Cache>,
+) -> AocResult<TestResult> {
+    let mut test_result: TestResult = TestResult {
+        day: solution.day(),
+        year: solution.year(),
+        parse: None,
+        p1: TestStatus::Unknown,
+        p2: TestStatus::Unknown,
+        examples: TestStatus::Unknown,
+        p1_memory: None,
+        p2_memory: None,
+    };
+    if !options.skip_examples {
+        let instant = Instant::now();
+        // A failing example panics, which fails the examples of this day instead of the whole run
+        let examples = panic::catch_unwind(AssertUnwindSafe(|| solution.run_examples()));
+        test_result.examples = match examples {
+            Ok(true) => TestStatus::Success(instant.elapsed(), 1.into()),
+            Ok(false) | Err(_) => TestStatus::Failed(instant.elapsed(), 0.into()),
+        };
+        options
+            .hooks
+            .fire_after_examples(&DayEvent::new(solution, &test_result));
+    }
+
+    let (year, day) = (solution.year(), solution.day());
+    options
+        .hooks
+        .fire_before_fetch(&DayEvent::new(solution, &test_result));
+    let input: Arc<str> = options.inputs.get(year, day)?.into();
+    let expected = |part: Part| options.answers.get(year, day, part, &input).cloned();
+    let cached = |part: Part| {
+        let cache = cache.lock().unwrap();
+        let answer = cache
+            .get(solution, part, &input)
+            .filter(|_| options.use_cache)?;
+        expected(part)
+            .is_none_or(|expected| expected == *answer)
+            .then(|| answer.clone())
+    };
+    let parts = [Part::One, Part::Two].map(|part| (part, expected(part), cached(part)));
+    let timeout = solution.timeout().or(options.timeout);
+
+    // The input is only parsed if a part is not cached
+    let mut parsed: Option<Arc<ParsedInput>> = None;
+    if parts.iter().any(|(_, _, cached)| cached.is_none()) {
+        let worker = Arc::clone(solution);
+        let worker_input = Arc::clone(&input);
+        let instant = Instant::now();
+        let parse = move || {
+            let instant = Instant::now();
+            let parsed = worker.parse(&worker_input, &Params::INPUT);
+            (parsed, instant.elapsed())
+        };
+        match run_on_worker(parse, timeout) {
+            Ok((Ok(parsed_input), duration)) => {
+                test_result.parse = Some(duration);
+                parsed = Some(Arc::new(parsed_input));
+            }
+            Ok((Err(error), duration)) => {
+                tracing::error!("AoC {}-{:02}: {}", year, day, error);
+                test_result.p1 = TestStatus::Error(duration);
+                test_result.p2 = TestStatus::Error(duration);
+                return Ok(test_result);
+            }
+            Err(error) => {
+                test_result.p1 = unfinished(error, instant.elapsed());
+                test_result.p2 = unfinished(error, instant.elapsed());
+                return Ok(test_result);
+            }
+        }
+    }
+    for (part, expected, cached) in parts {
+        options
+            .hooks
+            .fire_before_part(&PartEvent::new(solution, part, &test_result));
+        let (status, memory) = match cached {
+            Some(answer) => (TestStatus::Cached(answer), None),
+            None => run_part(
+                solution,
+                part,
+                Arc::clone(
+                    parsed
+                        .as_ref()
+                        .expect("The input is parsed if a part is not cached."),
+                ),
+                expected.as_ref(),
+                timeout,
+                options.warmup,
+            ),
+        };
+        if let TestStatus::Success(_, answer) | TestStatus::Unverified(_, answer) = &status {
+            cache
+                .lock()
+                .unwrap()
+                .insert(solution, part, &input, answer.clone());
+        }
+        match part {
+            Part::One => (test_result.p1, test_result.p1_memory) = (status, memory),
+            Part::Two => (test_result.p2, test_result.p2_memory) = (status, memory),
+        }
+        options
+            .hooks
+            .fire_after_part(&PartEvent::new(solution, part, &test_result));
+    }
+
+    Ok(test_result)
+}
+
+/// Number of threads that are not in use by the solutions that are running
+struct Budget {
+    available: Mutex<usize>,
+    released: Condvar,
+}
+
+impl Budget {
+    fn new(threads: usize) -> Budget {
+        Budget {
+            available: Mutex::new(threads),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Wait until `threads` threads are available, and take them
+    fn acquire(&self, threads: usize) {
+        let mut available = self.available.lock().unwrap();
+        while *available < threads {
+            available = self.released.wait(available).unwrap();
+        }
+        *available -= threads;
+    }
+
+    fn release(&self, threads: usize) {
+        *self.available.lock().unwrap() += threads;
+        self.released.notify_all();
+    }
+}
+
+/// Run `solutions` in order, starting the next one as soon as the threads it uses fit in `jobs` threads,
+/// so `jobs = 1` runs them one after another.
+///
+/// `on_start` is called just before a solution starts, the results are in the order of `solutions`. With
+/// `options.fail_fast`, the results end at the last solution that started.
+pub fn run_all(
+    solutions: &[SolutionBox],
+    options: &RunOptions,
+    cache: &Mutex<Cache>,
+    jobs: usize,
+    on_start: impl Fn(usize, &SolutionBox),
+) -> Vec<AocResult<TestResult>> {
+    let jobs = jobs.max(1);
+    let budget = Budget::new(jobs);
+    let failed = AtomicBool::new(false);
+    thread::scope(|scope| {
+        let mut handles = Vec::new();
+        for (i, solution) in solutions.iter().enumerate() {
+            let threads = solution.threads().clamp(1, jobs);
+            budget.acquire(threads);
+            if options.fail_fast && failed.load(Ordering::SeqCst) {
+                budget.release(threads);
+                break;
+            }
+            on_start(i, solution);
+            let (budget, failed) = (&budget, &failed);
+            handles.push(scope.spawn(move || {
+                let result = run(solution, options, cache);
+                if let Err(error) = &result {
+                    let event = FailureEvent::new(solution, error);
+                    options.hooks.fire_after_failure(&event);
+                }
+                let is_success = result.as_ref().is_ok_and(|test_result| {
+                    ExitStatus::of(std::slice::from_ref(test_result)) == ExitStatus::Success
+                });
+                if !is_success {
+                    failed.store(true, Ordering::SeqCst);
+                }
+                budget.release(threads);
+                result
+            }));
+        }
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    })
+}
+
+/// Run a single Solution with the default settings and the expected and cached answers of the working directory,
+/// the `main` of the small per-day binaries
+pub fn main(solution: SolutionBox) -> std::process::ExitCode {
+    let answers = match Answers::load(crate::answers::ANSWERS_PATH) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitStatus::Error.into();
+        }
+    };
+    let options = RunOptions {
+        answers,
+        use_cache: true,
+        ..RunOptions::default()
+    };
+    let cache = Mutex::new(Cache::load(crate::cache::CACHE_PATH));
+    let test_result = match run(&solution, &options, &cache) {
+        Ok(test_result) => test_result,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitStatus::Error.into();
+        }
+    };
+    if let Err(error) = cache.into_inner().unwrap().save() {
+        eprintln!("Failed to save the cache. {}", error);
+    }
+    println!("{:?}", test_result);
+    ExitStatus::of(&[test_result]).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_selection() {
+        let days: Selection<Day> = "1-10,14, 16".parse().unwrap();
+        test!(days.contains(&1));
+        test!(days.contains(&10));
+        test!(!days.contains(&11));
+        test!(days.contains(&14));
+        test!(days.contains(&16));
+        test!(!days.contains(&25));
+        test!("1-10,14,16", days.to_string());
+        let years: Selection<Year> = "2022-2023".parse().unwrap();
+        test!(years.contains(&2022) && years.contains(&2023));
+        test!(!years.contains(&2021));
+        test!("10-1".parse::<Selection<Day>>().is_err());
+        test!("1,a".parse::<Selection<Day>>().is_err());
+        test!("".parse::<Selection<Day>>().is_err());
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        test!(Duration::from_secs(30), parse_duration("30s").unwrap());
+        test!(Duration::from_secs(30), parse_duration("30").unwrap());
+        test!(Duration::from_millis(500), parse_duration("500ms").unwrap());
+        test!(Duration::from_secs(120), parse_duration("2m").unwrap());
+        test!(Duration::from_millis(1500), parse_duration("1.5s").unwrap());
+        test!(parse_duration("30h").is_err());
+        test!(parse_duration("s").is_err());
+    }
+
+    /// Solution of which part one never finishes in time, and part two fails on input that is not a number, or
+    /// panics on a negative number
+    #[derive(Default)]
+    struct Stuck {}
+
+    impl Solution for Stuck {
+        no_parse_step!();
+
+        fn solve_part_one(&self, _input: &String, _params: &Params) -> AocResult<Answer> {
+            thread::sleep(Duration::from_secs(10));
+            Ok(1.into())
+        }
+        fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+            let number: Int = input.parse().map_err(AocError::parse("a number"))?;
+            assert!(number >= 0, "Negative numbers are not supported.");
+            Ok(number.into())
+        }
+        fn year(&self) -> Year {
+            2023
+        }
+        fn day(&self) -> Day {
+            0
+        }
+    }
+
+    #[test]
+    fn test_run_part() {
+        let stuck = Stuck::create_box();
+        let timeout = Some(Duration::from_millis(10));
+        let two = Some(&Answer::Int(2));
+        let input = |input: &str| Arc::new(stuck.parse(input, &Params::INPUT).unwrap());
+        let (status, memory) = run_part(&stuck, Part::One, input("2"), two, timeout, 0);
+        test!(matches!(status, TestStatus::Timeout(_)));
+        test!(memory.is_none());
+        let (status, memory) = run_part(&stuck, Part::Two, input("2"), two, timeout, 0);
+        test!(matches!(status, TestStatus::Success(_, Answer::Int(2))));
+        test!(memory.is_some() == memory::is_enabled());
+        let (status, _) = run_part(&stuck, Part::Two, input("2"), two, timeout, 3);
+        test!(matches!(status, TestStatus::Success(_, Answer::Int(2))));
+        let (status, _) = run_part(&stuck, Part::Two, input("3"), two, None, 0);
+        test!(matches!(status, TestStatus::Failed(_, Answer::Int(3))));
+        let (status, _) = run_part(&stuck, Part::Two, input("3"), None, None, 0);
+        test!(matches!(status, TestStatus::Unverified(_, Answer::Int(3))));
+        let (status, memory) = run_part(&stuck, Part::Two, input("NaN"), two, None, 0);
+        test!(matches!(status, TestStatus::Error(_)));
+        test!(memory.is_none());
+        let (status, _) = run_part(&stuck, Part::Two, input("-1"), two, None, 0);
+        test!(matches!(status, TestStatus::Error(_)));
+    }
+
+    /// Solution that does nothing, on a given day
+    #[derive(Default)]
+    struct Nothing<const DAY: Day> {}
+
+    impl<const DAY: Day> Solution for Nothing<DAY> {
+        no_parse_step!();
+
+        fn solve_part_one(&self, _input: &String, _params: &Params) -> AocResult<Answer> {
+            Ok(0.into())
+        }
+        fn solve_part_two(&self, _input: &String, _params: &Params) -> AocResult<Answer> {
+            Ok(0.into())
+        }
+        fn year(&self) -> Year {
+            2023
+        }
+        fn day(&self) -> Day {
+            DAY
+        }
+    }
+
+    /// Nothing, which uses 2 threads at once
+    #[derive(Default)]
+    struct Parallel<const DAY: Day> {}
+
+    impl<const DAY: Day> Solution for Parallel<DAY> {
+        no_parse_step!();
+
+        fn solve_part_one(&self, input: &String, params: &Params) -> AocResult<Answer> {
+            Nothing::<DAY> {}.solve_part_one(input, params)
+        }
+        fn solve_part_two(&self, input: &String, params: &Params) -> AocResult<Answer> {
+            Nothing::<DAY> {}.solve_part_two(input, params)
+        }
+        fn year(&self) -> Year {
+            2023
+        }
+        fn day(&self) -> Day {
+            DAY
+        }
+        fn threads(&self) -> usize {
+            2
+        }
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut history = History::default();
+        for (day, millis) in [(1, 300), (2, 100)] {
+            history.record(&TestResult {
+                year: 2023,
+                day,
+                parse: None,
+                p1: TestStatus::Success(Duration::from_millis(millis), 0.into()),
+                p2: TestStatus::Unknown,
+                examples: TestStatus::Unknown,
+                p1_memory: None,
+                p2_memory: None,
+            });
+        }
+        let mut solutions = vec![
+            Nothing::<3>::create_box(),
+            Nothing::<1>::create_box(),
+            Nothing::<2>::create_box(),
+        ];
+        let days =
+            |solutions: &[SolutionBox]| solutions.iter().map(|s| s.day()).collect::<Vec<_>>();
+        sort(&mut solutions, Order::Chronological, &history, 0);
+        test!(vec![1, 2, 3], days(&solutions));
+        sort(&mut solutions, Order::FastestFirst, &history, 0);
+        test!(vec![2, 1, 3], days(&solutions));
+        sort(&mut solutions, Order::SlowestFirst, &history, 0);
+        test!(vec![3, 1, 2], days(&solutions));
+        sort(&mut solutions, Order::Chronological, &history, 0);
+        sort(&mut solutions, Order::Shuffle, &history, 42);
+        let shuffled = days(&solutions);
+        sort(&mut solutions, Order::Chronological, &history, 0);
+        sort(&mut solutions, Order::Shuffle, &history, 42);
+        test!(shuffled, days(&solutions));
+    }
+
+    #[test]
+    fn test_eta() {
+        let mut history = History::default();
+        for (day, millis) in [(1, 300), (2, 100), (3, 200)] {
+            history.record(&TestResult {
+                year: 2023,
+                day,
+                parse: None,
+                p1: TestStatus::Success(Duration::from_millis(millis), 0.into()),
+                p2: TestStatus::Unknown,
+                examples: TestStatus::Unknown,
+                p1_memory: None,
+                p2_memory: None,
+            });
+        }
+        let mut solutions = vec![
+            Nothing::<2>::create_box(),
+            Nothing::<3>::create_box(),
+            Nothing::<1>::create_box(),
+            Nothing::<4>::create_box(),
+        ];
+        let millis = Duration::from_millis;
+        test!((millis(600), 1), eta(&solutions, &history, 1));
+        // The slowest day starts last, and runs alone at the end
+        test!((millis(400), 1), eta(&solutions, &history, 2));
+        sort(&mut solutions, Order::SlowestFirst, &history, 0);
+        test!((millis(300), 1), eta(&solutions, &history, 2));
+        // Day 3 waits until day 1 frees its thread, because it uses both
+        let solutions = vec![Nothing::<1>::create_box(), Parallel::<3>::create_box()];
+        test!((millis(500), 0), eta(&solutions, &history, 2));
+        test!((millis(300), 0), eta(&solutions, &history, 3));
+    }
+
+    #[test]
+    fn test_budget() {
+        let budget = Arc::new(Budget::new(4));
+        budget.acquire(3);
+        let waiting = {
+            let budget = Arc::clone(&budget);
+            thread::spawn(move || budget.acquire(2))
+        };
+        thread::sleep(Duration::from_millis(10));
+        test!(!waiting.is_finished());
+        budget.release(3);
+        waiting.join().unwrap();
+        test!(2, *budget.available.lock().unwrap());
+    }
+
+    #[test]
+    fn test_fail_fast() {
+        // Without a cached input or a session cookie, no solution can run
+        let directory = std::env::temp_dir().join(format!("aoc-fail-fast-{}", std::process::id()));
+        let mut options = RunOptions {
+            skip_examples: true,
+            inputs: Inputs {
+                session_path: directory.join("cookie"),
+                session: None,
+                cache_directory: directory.join("cache"),
+            },
+            ..RunOptions::default()
+        };
+        let solutions = [
+            Nothing::<1>::create_box(),
+            Nothing::<2>::create_box(),
+            Nothing::<3>::create_box(),
+        ];
+        let cache = Mutex::new(Cache::default());
+        let results = run_all(&solutions, &options, &cache, 1, |_, _| ());
+        test!(3, results.len());
+        test!(results.iter().all(|result| result.is_err()));
+        options.fail_fast = true;
+        test!(1, run_all(&solutions, &options, &cache, 1, |_, _| ()).len());
+    }
+
+    /// Solution of which the input is a number, and that fails to parse anything else
+    #[derive(Default)]
+    struct Number {}
+
+    impl Solution for Number {
+        type Parsed = Int;
+
+        fn parse(&self, input: Input, _params: &Params) -> AocResult<Int> {
+            input.trim().parse().map_err(AocError::parse("a number"))
+        }
+        fn solve_part_one(&self, number: &Int, _params: &Params) -> AocResult<Answer> {
+            Ok((*number).into())
+        }
+        fn solve_part_two(&self, number: &Int, _params: &Params) -> AocResult<Answer> {
+            Ok((-*number).into())
+        }
+        fn year(&self) -> Year {
+            2023
+        }
+        fn day(&self) -> Day {
+            0
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let directory =
+            std::env::temp_dir().join(format!("aoc-parse-error-{}", std::process::id()));
+        let options = RunOptions {
+            skip_examples: true,
+            use_cache: false,
+            inputs: Inputs {
+                session_path: directory.join("cookie"),
+                session: None,
+                cache_directory: directory.join("cache"),
+            },
+            ..RunOptions::default()
+        };
+        let number = Number::create_box();
+        let cache = Mutex::new(Cache::default());
+        options.inputs.store(2023, 0, "42").unwrap();
+        let test_result = run(&number, &options, &cache).unwrap();
+        test!(matches!(
+            test_result.p2,
+            TestStatus::Unverified(_, Answer::Int(-42))
+        ));
+        options.inputs.store(2023, 0, "x").unwrap();
+        let test_result = run(&number, &options, &cache).unwrap();
+        test!(matches!(test_result.p1, TestStatus::Error(_)));
+        test!(matches!(test_result.p2, TestStatus::Error(_)));
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_exit_status() {
+        let test_result = |p1: TestStatus, p2: TestStatus| TestResult {
+            year: 2023,
+            day: 0,
+            parse: None,
+            p1,
+            p2,
+            examples: TestStatus::Unknown,
+            p1_memory: None,
+            p2_memory: None,
+        };
+        let success = || TestStatus::Success(Duration::ZERO, 1.into());
+        let failed = || TestStatus::Failed(Duration::ZERO, 1.into());
+        test!(ExitStatus::Success, ExitStatus::of(&[]));
+        test!(
+            ExitStatus::Success,
+            ExitStatus::of(&[test_result(success(), success())])
+        );
+        test!(
+            ExitStatus::WrongAnswer,
+            ExitStatus::of(&[test_result(success(), failed())])
+        );
+        test!(
+            ExitStatus::Error,
+            ExitStatus::of(&[
+                test_result(TestStatus::Error(Duration::ZERO), failed()),
+                test_result(TestStatus::Timeout(Duration::ZERO), success()),
+            ])
+        );
+    }
+}
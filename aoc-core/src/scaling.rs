@@ -0,0 +1,142 @@
+// This module scales a Map up, or inserts virtual cells between its cells, like the trick that squeezes between pipes
+// to find the inside of the loop of 2023-10, and maps the cells of the result back to the original cells
+
+use crate::grid::{Map, Point};
+use crate::Int;
+
+/// A virtual cell between cells of the original Map, see `Map::expand`
+#[derive(Debug)]
+pub enum Gap<'a, T> {
+    /// Between the cells left and right of it
+    Horizontal { left: &'a T, right: &'a T },
+    /// Between the cells above and below it
+    Vertical { above: &'a T, below: &'a T },
+    /// Between 4 cells, diagonally
+    Corner,
+}
+
+/// A Map that was scaled up from an original Map, of which every original cell is a `cell` by `cell` block that
+/// starts every `step` cells
+#[derive(Clone)]
+pub struct Scaled<T> {
+    pub map: Map<T>,
+    step: Int,
+    cell: Int,
+}
+
+impl<T> Scaled<T> {
+    /// The Point of the original cell that `point` is part of, None if it is a Gap, or outside the Map
+    pub fn original(&self, point: &Point) -> Option<Point> {
+        // A Map that is scaled by 0 has no cells, and a `step` of 0
+        if self.map.grid.is_empty() || !self.map.point_within_grid(point) {
+            return None;
+        }
+        let (x, y) = (point.x.rem_euclid(self.step), point.y.rem_euclid(self.step));
+        (x < self.cell && y < self.cell)
+            .then(|| Point::new(point.x / self.step, point.y / self.step))
+    }
+
+    /// The top-left Point of the block of the original cell at `point`
+    pub fn scaled(&self, point: &Point) -> Point {
+        Point::new(point.x * self.step, point.y * self.step)
+    }
+}
+
+impl<T: Clone> Map<T> {
+    /// This Map of which every cell is repeated `factor` times in both directions
+    pub fn scale(&self, factor: usize) -> Scaled<T> {
+        let map = self
+            .grid
+            .iter()
+            .flat_map(|row| {
+                let row: Vec<T> = row
+                    .iter()
+                    .flat_map(|value| std::iter::repeat_n(value, factor).cloned())
+                    .collect();
+                std::iter::repeat_n(row, factor)
+            })
+            .collect();
+        Scaled {
+            map,
+            step: factor as Int,
+            cell: factor as Int,
+        }
+    }
+
+    /// This Map with a virtual cell between every two neighboring cells, and in every corner between 4 cells, of
+    /// which `gap` gives the value, e.g. a pipe if the cells on both sides are connected pipes
+    pub fn expand(&self, gap: impl Fn(Gap<T>) -> T) -> Scaled<T> {
+        let (rows, columns) = match self.grid.is_empty() {
+            true => (0, 0),
+            false => (self.get_rows() * 2 - 1, self.get_columns() * 2 - 1),
+        };
+        let map = (0..rows)
+            .map(|y| {
+                (0..columns)
+                    .map(|x| {
+                        let cell = |x: usize, y: usize| &self.grid[y][x];
+                        match (x % 2, y % 2) {
+                            (0, 0) => cell(x / 2, y / 2).clone(),
+                            (1, 0) => gap(Gap::Horizontal {
+                                left: cell(x / 2, y / 2),
+                                right: cell(x / 2 + 1, y / 2),
+                            }),
+                            (0, _) => gap(Gap::Vertical {
+                                above: cell(x / 2, y / 2),
+                                below: cell(x / 2, y / 2 + 1),
+                            }),
+                            _ => gap(Gap::Corner),
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        Scaled {
+            map,
+            step: 2,
+            cell: 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    fn map(rows: &str) -> Map<char> {
+        rows.lines().map(|row| row.chars().collect()).collect()
+    }
+
+    #[test]
+    fn test_scale() {
+        let scaled = map("ab\ncd").scale(2);
+        test!("aabb\naabb\nccdd\nccdd", scaled.map.to_string());
+        test!(Some(Point::new(1, 0)), scaled.original(&Point::new(3, 1)));
+        test!(scaled.original(&Point::new(4, 0)).is_none());
+        test!(Point::new(2, 2), scaled.scaled(&Point::new(1, 1)));
+        test!(map("ab\ncd").scale(0).original(&Point::new(0, 0)).is_none());
+    }
+
+    #[test]
+    fn test_expand() {
+        // Pipes that run next to each other leave a gap between them, which a flood fill can squeeze through
+        let pipes = map("F7F7\n||||\nLJLJ");
+        let connects = |from: char, to: char, horizontal: bool| match horizontal {
+            true => "-LF".contains(from) && "-J7".contains(to),
+            false => "|7F".contains(from) && "|LJ".contains(to),
+        };
+        let expanded = pipes.expand(|gap| match gap {
+            Gap::Horizontal { left, right } if connects(*left, *right, true) => '-',
+            Gap::Vertical { above, below } if connects(*above, *below, false) => '|',
+            _ => '.',
+        });
+        test!(
+            "F-7.F-7\n|.|.|.|\n|.|.|.|\n|.|.|.|\nL-J.L-J",
+            expanded.map.to_string()
+        );
+        test!(Some(Point::new(1, 1)), expanded.original(&Point::new(2, 2)));
+        test!(expanded.original(&Point::new(3, 2)).is_none());
+        test!(Point::new(6, 4), expanded.scaled(&Point::new(3, 2)));
+    }
+}
@@ -0,0 +1,433 @@
+#![allow(dead_code)]
+// This module contains a depth-first and a best-first branch and bound search for the best path through a state
+// space, like the longest hike of 2023-23 or the most geodes of 2022-19, the contraction of corridors that makes
+// such a state space small enough to search, and a meet-in-the-middle search that splits a search over subsets or
+// sequences into two halves that are joined
+
+use crate::Int;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+/// Trait for a state space in which the path with the largest score is searched, of which the implementing type
+/// holds the static data, like a graph, and `State` the data of a single path, like its last node and visited nodes
+pub trait Search {
+    type State;
+
+    /// The states that `state` can move to, with the score gained by moving there
+    fn successors(&self, state: &Self::State) -> Vec<(Self::State, Int)>;
+
+    /// Whether a path may end in `state`
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// Upper bound of the score that can still be gained from `state`, branches of which the score so far plus this
+    /// bound can not beat the best score so far are pruned. `Int::MAX` by default, which never prunes.
+    fn bound(&self, _state: &Self::State) -> Int {
+        Int::MAX
+    }
+
+    /// Return the largest score of a path from `start` to a goal, None if no goal can be reached
+    fn longest_path(&self, start: Self::State) -> Option<Int> {
+        let mut best: Option<Int> = None;
+        self.branch(start, 0, &mut best);
+        best
+    }
+
+    /// Return the largest score of a path from `start` to a goal like `longest_path`, but search the states in order
+    /// of the score they were reached with plus their `bound`, so the best paths are tried first. The search ends as
+    /// soon as no queued state can beat the best score so far, the incumbent, which prunes far more than a
+    /// depth-first search if `bound` is tight, at the cost of keeping the queued states in memory.
+    ///
+    /// `incumbent` is a score that is known to be reachable, like the score of a greedy path, which prunes from the
+    /// start, it is returned if no path beats it. `bound` must never underestimate, or the best path may be pruned.
+    fn best_first(&self, start: Self::State, incumbent: Option<Int>) -> Option<Int> {
+        let mut best = incumbent;
+        // The queued states, by their index in `states`, with the highest score plus bound first
+        let mut queue: BinaryHeap<(Int, Int, usize)> = BinaryHeap::new();
+        let mut states: Vec<Option<Self::State>> = Vec::new();
+        let bound = self.bound(&start);
+        queue.push((bound, 0, 0));
+        states.push(Some(start));
+        while let Some((bound, score, i)) = queue.pop() {
+            if best.is_some_and(|best| bound <= best) {
+                break;
+            }
+            let state = states[i].take().unwrap();
+            if self.is_goal(&state) && best.is_none_or(|best| score > best) {
+                best = Some(score);
+            }
+            for (successor, gain) in self.successors(&state) {
+                let score = score + gain;
+                let bound = score.saturating_add(self.bound(&successor));
+                if best.is_some_and(|best| bound <= best) {
+                    continue;
+                }
+                queue.push((bound, score, states.len()));
+                states.push(Some(successor));
+            }
+        }
+        best
+    }
+
+    /// Search the paths that continue from `state`, which was reached with `score`
+    fn branch(&self, state: Self::State, score: Int, best: &mut Option<Int>) {
+        if self.is_goal(&state) && best.is_none_or(|best| score > best) {
+            *best = Some(score);
+        }
+        for (successor, gain) in self.successors(&state) {
+            let score = score + gain;
+            let bound = self.bound(&successor);
+            if best.is_some_and(|best| score.saturating_add(bound) <= best) {
+                continue;
+            }
+            self.branch(successor, score, best);
+        }
+    }
+}
+
+/// Contract the corridors of the graph that is reachable from `start` into weighted edges between its junctions.
+///
+/// A junction is a node with more than two neighbors, or a node in `keep`, like the start and the end of a maze. A
+/// corridor must have at most one neighbor besides the node it was entered from, and a corridor that ends without
+/// reaching a junction is left out. The weight of an edge is its number of steps.
+pub fn contract<N: Hash + Eq + Clone>(
+    start: N,
+    neighbors: impl Fn(&N) -> Vec<N>,
+    keep: &[N],
+) -> HashMap<N, Vec<(N, Int)>> {
+    let is_junction = |node: &N| keep.contains(node) || neighbors(node).len() > 2;
+    let mut edges: HashMap<N, Vec<(N, Int)>> = HashMap::new();
+    let mut stack = vec![start];
+    while let Some(junction) = stack.pop() {
+        if edges.contains_key(&junction) {
+            continue;
+        }
+        let mut junction_edges = Vec::new();
+        for first in neighbors(&junction) {
+            let (mut previous, mut current, mut steps) = (junction.clone(), first, 1);
+            let end = loop {
+                if is_junction(&current) {
+                    break Some(current);
+                }
+                let Some(next) = neighbors(&current)
+                    .into_iter()
+                    .find(|next| *next != previous)
+                else {
+                    break None;
+                };
+                (previous, current) = (current, next);
+                steps += 1;
+            };
+            if let Some(end) = end {
+                stack.push(end.clone());
+                junction_edges.push((end, steps));
+            }
+        }
+        edges.insert(junction, junction_edges);
+    }
+    edges
+}
+
+/// Every subset of `items`, as the value that `add` builds by adding its items one by one to `empty`, without
+/// keeping them in memory, e.g. the sums of the subsets of a half of the items
+pub fn subsets<'a, T, V: 'a>(
+    items: &'a [T],
+    empty: V,
+    add: impl Fn(&V, &T) -> V + 'a,
+) -> impl Iterator<Item = V> + 'a {
+    // Every subset is reached once, by adding items in the order of `items`
+    let mut stack = vec![(0, empty)];
+    std::iter::from_fn(move || {
+        let (start, value) = stack.pop()?;
+        for (i, item) in items.iter().enumerate().skip(start) {
+            stack.push((i + 1, add(&value, item)));
+        }
+        Some(value)
+    })
+}
+
+/// Every sequence of `length` of `choices`, as the value that `add` builds by adding its choices one by one to
+/// `empty`, without keeping them in memory, e.g. the states after a half of the moves
+pub fn sequences<'a, T, V: 'a>(
+    choices: &'a [T],
+    length: usize,
+    empty: V,
+    add: impl Fn(&V, &T) -> V + 'a,
+) -> impl Iterator<Item = V> + 'a {
+    let mut stack = vec![(0, empty)];
+    std::iter::from_fn(move || loop {
+        let (depth, value) = stack.pop()?;
+        if depth == length {
+            return Some(value);
+        }
+        stack.extend(
+            choices
+                .iter()
+                .rev()
+                .map(|choice| (depth + 1, add(&value, choice))),
+        );
+    })
+}
+
+/// Join the values of the two halves of a search, of which every pair of a `left` and a `right` value with an equal
+/// key is passed to `combine`, which can count, collect or keep the best of them. This turns a search of 2^40 values
+/// into two of 2^20, like the subsets of 40 items from the `subsets` of both halves of the items.
+///
+/// The left values are kept in a hash map by `left_key`, and looked up by the `right_key` of every right value. At
+/// most `limit` left values are in memory at a time, if there are more, `right` is called again for every `limit`
+/// left values, trading time for memory. Use `usize::MAX` to keep all of them.
+pub fn meet_in_the_middle<L, R, K: Hash + Eq, I: IntoIterator<Item = R>>(
+    left: impl IntoIterator<Item = L>,
+    right: impl Fn() -> I,
+    left_key: impl Fn(&L) -> K,
+    right_key: impl Fn(&R) -> K,
+    limit: usize,
+    mut combine: impl FnMut(&L, &R),
+) {
+    assert!(
+        limit > 0,
+        "At least one value of the left half must fit in memory."
+    );
+    let mut left = left.into_iter().peekable();
+    while left.peek().is_some() {
+        let mut table: HashMap<K, Vec<L>> = HashMap::new();
+        for value in left.by_ref().take(limit) {
+            table.entry(left_key(&value)).or_default().push(value);
+        }
+        for value in right() {
+            for matching in table.get(&right_key(&value)).into_iter().flatten() {
+                combine(matching, &value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    const MAZE: &str = "\
+#.#####
+#.....#
+#.###.#
+#.....#
+###.###
+###...#
+#####.#";
+
+    type Tile = (usize, usize);
+
+    /// The longest hike through MAZE without visiting a junction twice
+    struct Hike {
+        /// The number of junctions
+        junctions: usize,
+        edges: Vec<Vec<(usize, Int)>>,
+        /// The longest edge, a bound on what every remaining junction can add
+        longest_edge: Int,
+        end: usize,
+    }
+
+    impl Search for Hike {
+        /// The current junction, and the visited junctions as a bit mask
+        type State = (usize, u64);
+
+        fn successors(&self, &(junction, visited): &(usize, u64)) -> Vec<((usize, u64), Int)> {
+            self.edges[junction]
+                .iter()
+                .filter(|(next, _)| visited & (1 << next) == 0)
+                .map(|&(next, steps)| ((next, visited | (1 << next)), steps))
+                .collect()
+        }
+
+        fn is_goal(&self, &(junction, _): &(usize, u64)) -> bool {
+            junction == self.end
+        }
+
+        fn bound(&self, &(_, visited): &(usize, u64)) -> Int {
+            let unvisited = self.junctions as Int - visited.count_ones() as Int;
+            unvisited * self.longest_edge
+        }
+    }
+
+    fn neighbors(&(x, y): &Tile) -> Vec<Tile> {
+        let lines: Vec<&[u8]> = MAZE.lines().map(str::as_bytes).collect();
+        let candidates = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+        candidates
+            .into_iter()
+            .filter(|&(x, y)| lines.get(y).and_then(|line| line.get(x)) == Some(&b'.'))
+            .collect()
+    }
+
+    #[test]
+    fn test_contract() {
+        let (start, end) = ((1, 0), (5, 6));
+        let edges = contract(start, neighbors, &[start, end]);
+        // The start, the end, and the two junctions of the loop
+        test!(4, edges.len());
+        test!(vec![((1, 1), 1)], edges[&start]);
+        let mut from_junction = edges[&(1, 1)].clone();
+        from_junction.sort();
+        test!(vec![((1, 0), 1), ((3, 3), 4), ((3, 3), 8)], from_junction);
+    }
+
+    #[test]
+    fn test_longest_path() {
+        let (start, end) = ((1, 0), (5, 6));
+        let edges = contract(start, neighbors, &[start, end]);
+        let junctions: Vec<Tile> = edges.keys().copied().collect();
+        let index = |tile: &Tile| {
+            junctions
+                .iter()
+                .position(|junction| junction == tile)
+                .unwrap()
+        };
+        let hike = Hike {
+            edges: junctions
+                .iter()
+                .map(|junction| {
+                    edges[junction]
+                        .iter()
+                        .map(|(next, steps)| (index(next), *steps))
+                        .collect()
+                })
+                .collect(),
+            longest_edge: edges
+                .values()
+                .flatten()
+                .map(|&(_, steps)| steps)
+                .max()
+                .unwrap(),
+            end: index(&end),
+            junctions: junctions.len(),
+        };
+        let start = index(&start);
+        // The long way around the loop, and not back again the short way
+        test!(Some::<Int>(14), hike.longest_path((start, 1 << start)));
+        test!(Some::<Int>(0), hike.longest_path((hike.end, 1 << hike.end)));
+        test!(Some::<Int>(14), hike.best_first((start, 1 << start), None));
+        test!(
+            Some::<Int>(14),
+            hike.best_first((start, 1 << start), Some(10))
+        );
+        // Nothing beats the incumbent, so everything is pruned
+        test!(
+            Some::<Int>(20),
+            hike.best_first((start, 1 << start), Some(20))
+        );
+        test!(
+            Some::<Int>(0),
+            hike.best_first((hike.end, 1 << hike.end), None)
+        );
+    }
+
+    const WEIGHTS: [Int; 12] = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11, 13, 17];
+
+    #[test]
+    fn test_enumerate() {
+        let mut sums: Vec<Int> = subsets(&[1, 2, 4], 0, |sum, item| sum + item).collect();
+        sums.sort();
+        test!(vec![0, 1, 2, 3, 4, 5, 6, 7], sums);
+        let words: Vec<String> = sequences(&['a', 'b'], 2, String::new(), |word, &c| {
+            format!("{}{}", word, c)
+        })
+        .collect();
+        test!(vec!["aa", "ab", "ba", "bb"], words);
+        test!(1, sequences(&['a'], 0, 0, |n, _| n + 1).count());
+    }
+
+    #[test]
+    fn test_meet_in_the_middle() {
+        // The subsets of WEIGHTS that weigh a third of the total, like the groups of packages of 2015-24
+        let target = WEIGHTS.iter().sum::<Int>() / 3;
+        let expected = (0..1 << WEIGHTS.len())
+            .filter(|mask| {
+                (0..WEIGHTS.len())
+                    .filter(|i| mask & (1 << i) != 0)
+                    .map(|i| WEIGHTS[i])
+                    .sum::<Int>()
+                    == target
+            })
+            .count();
+        let (first, second) = WEIGHTS.split_at(WEIGHTS.len() / 2);
+        // The weight and the number of packages of a subset
+        let add = |&(weight, count): &(Int, Int), item: &Int| (weight + item, count + 1);
+        for limit in [usize::MAX, 5] {
+            let (mut groups, mut fewest) = (0, Int::MAX);
+            meet_in_the_middle(
+                subsets(first, (0, 0), add),
+                || subsets(second, (0, 0), add),
+                |&(weight, _)| weight,
+                |&(weight, _)| target - weight,
+                limit,
+                |left, right| {
+                    groups += 1;
+                    fewest = fewest.min(left.1 + right.1);
+                },
+            );
+            test!(expected, groups);
+            // 13 + 17
+            test!(2, fewest);
+        }
+    }
+
+    /// A knapsack of which the items with the largest total value that fit are searched, as (weight, value)
+    struct Knapsack {
+        items: Vec<(Int, Int)>,
+        capacity: Int,
+    }
+
+    impl Search for Knapsack {
+        /// The next item to decide on, and the weight so far
+        type State = (usize, Int);
+
+        fn successors(&self, &(i, weight): &(usize, Int)) -> Vec<((usize, Int), Int)> {
+            let Some(&(item_weight, value)) = self.items.get(i) else {
+                return Vec::new();
+            };
+            let mut successors = vec![((i + 1, weight), 0)];
+            if weight + item_weight <= self.capacity {
+                successors.push(((i + 1, weight + item_weight), value));
+            }
+            successors
+        }
+
+        fn is_goal(&self, &(i, _): &(usize, Int)) -> bool {
+            i == self.items.len()
+        }
+
+        /// The value of the remaining items that fit on their own
+        fn bound(&self, &(i, weight): &(usize, Int)) -> Int {
+            self.items[i..]
+                .iter()
+                .filter(|&&(item_weight, _)| weight + item_weight <= self.capacity)
+                .map(|&(_, value)| value)
+                .sum()
+        }
+    }
+
+    #[test]
+    fn test_best_first() {
+        let knapsack = Knapsack {
+            items: vec![(12, 4), (2, 2), (1, 1), (1, 2), (4, 10)],
+            capacity: 15,
+        };
+        test!(Some::<Int>(15), knapsack.best_first((0, 0), None));
+        test!(
+            knapsack.longest_path((0, 0)),
+            knapsack.best_first((0, 0), None)
+        );
+        test!(
+            Some::<Int>(0),
+            Knapsack {
+                items: vec![(2, 5)],
+                capacity: 1
+            }
+            .best_first((0, 0), None)
+        );
+    }
+}
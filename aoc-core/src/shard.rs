@@ -0,0 +1,101 @@
+// This module splits a list of days into shards, so the tests of all days can be split across processes or machines
+// with `AOC_TEST_SHARD=2/8`, and verifying the full inputs stays practical as the number of years grows
+
+use std::str::FromStr;
+
+/// Environment variable with the shard of the days to test, e.g. `2/8` for the second of 8 shards
+pub const SHARD_ENV: &str = "AOC_TEST_SHARD";
+
+/// The `index`th of `count` shards, 1-based, which contains every `count`th day, starting at day `index` in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shard {
+    pub index: usize,
+    pub count: usize,
+}
+
+impl Shard {
+    /// The single shard that contains all days
+    pub const ALL: Shard = Shard { index: 1, count: 1 };
+
+    /// The shard of SHARD_ENV, or `Shard::ALL` if it is not set
+    pub fn from_env() -> Result<Shard, String> {
+        match std::env::var(SHARD_ENV) {
+            Ok(shard) if !shard.is_empty() => shard
+                .parse()
+                .map_err(|error| format!("Invalid {}: {}", SHARD_ENV, error)),
+            _ => Ok(Shard::ALL),
+        }
+    }
+
+    /// Returns true iff the day at `position`, 0-based, is in this shard. Days are dealt out in turn, so days of
+    /// the same year, which tend to get slower, are spread over the shards.
+    pub fn contains(&self, position: usize) -> bool {
+        position % self.count == self.index - 1
+    }
+
+    /// The items of this shard
+    pub fn select<T>(self, items: impl IntoIterator<Item = T>) -> impl Iterator<Item = T> {
+        items
+            .into_iter()
+            .enumerate()
+            .filter(move |(position, _)| self.contains(*position))
+            .map(|(_, item)| item)
+    }
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    /// Parse `index/count`, e.g. `2/8`
+    fn from_str(shard: &str) -> Result<Shard, String> {
+        let (index, count) = shard.split_once('/').ok_or(format!(
+            "'{}' is not of the form index/count, e.g. 2/8",
+            shard
+        ))?;
+        let parse = |number: &str| {
+            number
+                .trim()
+                .parse::<usize>()
+                .map_err(|error| format!("'{}': {}", number, error))
+        };
+        let (index, count) = (parse(index)?, parse(count)?);
+        if index == 0 || index > count {
+            return Err(format!("shard {} is not in 1..={}", index, count));
+        }
+        Ok(Shard { index, count })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_shard() {
+        test!(
+            Ok::<Shard, String>(Shard { index: 2, count: 8 }),
+            "2/8".parse()
+        );
+        test!("0/8".parse::<Shard>().is_err());
+        test!("9/8".parse::<Shard>().is_err());
+        test!("2-8".parse::<Shard>().is_err());
+        test!("a/8".parse::<Shard>().is_err());
+        let days: Vec<usize> = (1..=25).collect();
+        test!(
+            vec![2, 5, 8, 11, 14, 17, 20, 23],
+            Shard { index: 2, count: 3 }
+                .select(days.clone())
+                .collect::<Vec<_>>()
+        );
+        test!(25, Shard::ALL.select(days.clone()).count());
+        // Every day is in exactly one shard
+        let shards = (1..=4).map(|index| Shard { index, count: 4 });
+        test!(
+            25,
+            shards
+                .map(|shard| shard.select(days.clone()).count())
+                .sum::<usize>()
+        );
+    }
+}
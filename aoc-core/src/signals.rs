@@ -0,0 +1,238 @@
+// This module contains an event queue that propagates signals through a network of named nodes, like the modules of
+// 2023-20, so a day only describes how a single node reacts to a signal
+
+use crate::interner::{Interner, Symbol};
+use crate::{Int, Queue};
+
+/// A node in a `Network`, of which the implementing type is the state of the node
+pub trait Node: Default {
+    type Signal: Clone;
+
+    /// Called once for every input of this node when the `Network` is built, e.g. to remember the last signal of
+    /// every input
+    fn connect(&mut self, _input: Symbol) {}
+
+    /// Handle `signal` from `input`, and return the signal to send to every output of this node, if any
+    fn receive(&mut self, input: Symbol, signal: &Self::Signal) -> Option<Self::Signal>;
+}
+
+/// A signal on its way from one node to another
+#[derive(Debug, Clone, PartialEq)]
+pub struct Message<S> {
+    pub from: Symbol,
+    pub to: Symbol,
+    pub signal: S,
+}
+
+/// Identifies a condition of which `Network::first` returns the tick at which it first held
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Watch(usize);
+
+/// Condition on a delivered message
+type Condition<S> = Box<dyn Fn(&Message<S>) -> bool>;
+
+/// Condition on the delivered messages, and the tick at which it first held
+struct Watched<S> {
+    condition: Condition<S>,
+    first: Option<Int>,
+}
+
+/// Named nodes of type `N`, wired up from their outputs to the inputs of other nodes
+pub struct Network<N: Node> {
+    names: Interner,
+    nodes: Vec<N>,
+    inputs: Vec<Vec<Symbol>>,
+    outputs: Vec<Vec<Symbol>>,
+    queue: Queue<Message<N::Signal>>,
+    watched: Vec<Watched<N::Signal>>,
+    /// Number of calls to `tick`
+    ticks: Int,
+    /// Number of messages that were delivered
+    delivered: Int,
+}
+
+impl<N: Node> Network<N> {
+    /// Build a Network from the name, the node and the names of the outputs of every node. A name that only occurs as
+    /// an output gets a default node.
+    pub fn new<'a>(nodes: impl IntoIterator<Item = (&'a str, N, Vec<&'a str>)>) -> Network<N> {
+        let mut network = Network {
+            names: Interner::new(),
+            nodes: Vec::new(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            queue: Queue::new(),
+            watched: Vec::new(),
+            ticks: 0,
+            delivered: 0,
+        };
+        for (name, node, outputs) in nodes {
+            let from = network.intern(name);
+            network.nodes[from.index()] = node;
+            for output in outputs {
+                let to = network.intern(output);
+                network.outputs[from.index()].push(to);
+                network.inputs[to.index()].push(from);
+            }
+        }
+        for (i, inputs) in network.inputs.iter().enumerate() {
+            for &input in inputs {
+                network.nodes[i].connect(input);
+            }
+        }
+        network
+    }
+
+    fn intern(&mut self, name: &str) -> Symbol {
+        let symbol = self.names.intern(name);
+        if symbol.index() == self.nodes.len() {
+            self.nodes.push(N::default());
+            self.inputs.push(Vec::new());
+            self.outputs.push(Vec::new());
+        }
+        symbol
+    }
+
+    /// The Symbol of the node named `name`
+    pub fn get(&self, name: &str) -> Option<Symbol> {
+        self.names.get(name)
+    }
+
+    /// The name of `node`
+    pub fn name(&self, node: Symbol) -> &str {
+        self.names.resolve(node)
+    }
+
+    pub fn node(&self, node: Symbol) -> &N {
+        &self.nodes[node.index()]
+    }
+
+    /// The nodes that send their signals to `node`
+    pub fn inputs(&self, node: Symbol) -> &[Symbol] {
+        &self.inputs[node.index()]
+    }
+
+    /// The nodes that `node` sends its signals to
+    pub fn outputs(&self, node: Symbol) -> &[Symbol] {
+        &self.outputs[node.index()]
+    }
+
+    /// Number of calls to `tick`
+    pub fn ticks(&self) -> Int {
+        self.ticks
+    }
+
+    /// Number of messages that were delivered
+    pub fn delivered(&self) -> Int {
+        self.delivered
+    }
+
+    /// Watch for the first tick at which a delivered message satisfies `condition`, see `first`
+    pub fn watch(&mut self, condition: impl Fn(&Message<N::Signal>) -> bool + 'static) -> Watch {
+        self.watched.push(Watched {
+            condition: Box::new(condition),
+            first: None,
+        });
+        Watch(self.watched.len() - 1)
+    }
+
+    /// The first tick at which the condition of `watch` held, counting from 1
+    pub fn first(&self, watch: Watch) -> Option<Int> {
+        self.watched[watch.0].first
+    }
+
+    /// Send `signal` from `from` to all of its outputs, and deliver messages until none are left
+    pub fn tick(&mut self, from: Symbol, signal: N::Signal) {
+        self.tick_inspect(from, signal, |_| ());
+    }
+
+    /// Like `tick`, but call `inspect` with every message just before it is delivered
+    pub fn tick_inspect(
+        &mut self,
+        from: Symbol,
+        signal: N::Signal,
+        mut inspect: impl FnMut(&Message<N::Signal>),
+    ) {
+        self.ticks += 1;
+        self.send(from, signal);
+        while let Some(message) = self.queue.pop_front() {
+            inspect(&message);
+            for watched in &mut self.watched {
+                if watched.first.is_none() && (watched.condition)(&message) {
+                    watched.first = Some(self.ticks);
+                }
+            }
+            self.delivered += 1;
+            let to = message.to;
+            if let Some(signal) = self.nodes[to.index()].receive(message.from, &message.signal) {
+                self.send(to, signal);
+            }
+        }
+    }
+
+    fn send(&mut self, from: Symbol, signal: N::Signal) {
+        for &to in &self.outputs[from.index()] {
+            self.queue.push_back(Message {
+                from,
+                to,
+                signal: signal.clone(),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    /// Node that counts the signals it receives, and passes on every signal but the multiples of 3
+    #[derive(Default)]
+    struct Relay {
+        received: usize,
+        connected: usize,
+    }
+
+    impl Node for Relay {
+        type Signal = u32;
+
+        fn connect(&mut self, _input: Symbol) {
+            self.connected += 1;
+        }
+
+        fn receive(&mut self, _input: Symbol, signal: &u32) -> Option<u32> {
+            self.received += 1;
+            (!signal.is_multiple_of(3)).then_some(signal + 1)
+        }
+    }
+
+    #[test]
+    fn test_network() {
+        let mut network = Network::new([
+            ("source", Relay::default(), vec!["a", "b"]),
+            ("a", Relay::default(), vec!["sink"]),
+            ("b", Relay::default(), vec!["a"]),
+        ]);
+        let [source, a, b, sink] =
+            ["source", "a", "b", "sink"].map(|name| network.get(name).unwrap());
+        test!(&[source, b], network.inputs(a));
+        test!(2, network.node(a).connected);
+        let to_sink = network.watch(move |message| message.to == sink && message.signal == 3);
+        let to_source = network.watch(move |message| message.to == source);
+        let mut delivered = Vec::new();
+        network.tick_inspect(source, 1, |message| {
+            delivered.push((message.to, message.signal))
+        });
+        test!(
+            vec![(a, 1), (b, 1), (sink, 2), (a, 2), (sink, 3)],
+            delivered
+        );
+        test!(Some(1), network.first(to_sink));
+        network.tick(source, 2);
+        test!(Some(1), network.first(to_sink));
+        test!(network.first(to_source).is_none());
+        test!(2, network.ticks());
+        test!(9, network.delivered());
+        test!(3, network.node(sink).received);
+        test!("sink", network.name(sink));
+    }
+}
@@ -0,0 +1,247 @@
+// This module contains a generic "simulate with cycle skipping" engine, because a lot of
+// Advent of Code puzzles ask for the state after an absurd amount of steps of a deterministic process
+
+use crate::Int;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A `Cycle` in a `Simulation`: the state after `start` steps is equal to the state after `start + length` steps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: Int,
+    pub length: Int,
+}
+
+/// Trait for a deterministic simulation, of which the implementing type is the state
+pub trait Simulation {
+    /// Canonical hash of a state, two states with the same `Key` must behave identically
+    type Key: Hash + Eq;
+
+    /// Advance the simulation a single step
+    fn step(&mut self);
+
+    /// Return the canonical hash of the current state
+    fn key(&self) -> Self::Key;
+
+    /// Step until a state is seen for the second time, and return the `Cycle` that was found.
+    ///
+    /// Afterwards, the simulation is in the state after `cycle.start + cycle.length` steps.
+    fn find_cycle(&mut self) -> Cycle {
+        let mut seen: HashMap<Self::Key, Int> = HashMap::new();
+        let mut steps: Int = 0;
+        loop {
+            if let Some(start) = seen.insert(self.key(), steps) {
+                return Cycle {
+                    start,
+                    length: steps - start,
+                };
+            }
+            self.step();
+            steps += 1;
+        }
+    }
+
+    /// Advance the simulation `steps` steps, fast-forwarding as soon as a cycle is detected
+    fn advance(&mut self, steps: Int) {
+        self.advance_inspect(steps, |_| ());
+    }
+
+    /// Like `advance`, but call `inspect` with the state after every step that is simulated,
+    /// e.g. to record it as a `Frame`. The steps that are skipped by fast-forwarding are not inspected.
+    fn advance_inspect(&mut self, steps: Int, mut inspect: impl FnMut(&Self)) {
+        self.advance_numbered(0, steps, |_, state| inspect(state));
+    }
+
+    /// Like `advance_inspect` from the state after `start` steps to the state after `end` steps, e.g. when it is
+    /// replayed from a `Snapshot`, and `inspect` also gets the number of steps after which the state is.
+    /// `advance_numbered(0, steps, ...)` inspects the same states as `advance_inspect(steps, ...)`.
+    fn advance_numbered(&mut self, start: Int, end: Int, mut inspect: impl FnMut(Int, &Self)) {
+        let mut seen: HashMap<Self::Key, Int> = HashMap::new();
+        for current_step in start..end {
+            if let Some(previous_step) = seen.insert(self.key(), current_step) {
+                let remaining_steps = (end - current_step) % (current_step - previous_step);
+                for step in end - remaining_steps..end {
+                    self.step();
+                    inspect(step + 1, self);
+                }
+                return;
+            }
+            self.step();
+            inspect(current_step + 1, self);
+        }
+    }
+
+    /// Return the state after `steps` steps, fast-forwarding as soon as a cycle is detected
+    fn state_after(mut self, steps: Int) -> Self
+    where
+        Self: Sized,
+    {
+        self.advance(steps);
+        self
+    }
+}
+
+/// Return the first step at which every `Cycle` is at its start, i.e. the smallest step that is at least the `start`
+/// of every `Cycle`, and a whole number of its `length`s after that `start`. None if the cycles never align.
+///
+/// This is the least common multiple of the lengths if every `start` equals its `length`, like the ghosts of 2023-08,
+/// and the Chinese remainder theorem for moduli that need not be coprime otherwise. Every `length` must be positive.
+pub fn align(cycles: &[Cycle]) -> Option<Int> {
+    // Every step that satisfies the cycles so far is `offset` plus a multiple of `period`
+    let (mut offset, mut period): (i128, i128) = (0, 1);
+    for cycle in cycles {
+        let (start, length) = (cycle.start as i128, cycle.length as i128);
+        // Solve `offset + period * k = start (mod length)` for k
+        let (divisor, inverse) = gcd_inverse(period, length);
+        let difference = start - offset;
+        if difference % divisor != 0 {
+            return None;
+        }
+        let modulus = length / divisor;
+        let k = (difference / divisor).rem_euclid(modulus) * inverse.rem_euclid(modulus) % modulus;
+        offset += period * k;
+        period *= modulus;
+        offset = offset.rem_euclid(period);
+    }
+    let latest_start = cycles
+        .iter()
+        .map(|cycle| cycle.start as i128)
+        .max()
+        .unwrap_or(0);
+    if offset < latest_start {
+        offset += (latest_start - offset + period - 1) / period * period;
+    }
+    Int::try_from(offset).ok()
+}
+
+/// Return the greatest common divisor of `a` and `b`, and the inverse of `a / gcd` modulo `b / gcd`
+fn gcd_inverse(a: i128, b: i128) -> (i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+    (old_r, old_s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    /// Simulation with a tail of 3 steps (0, 1, 2), followed by a cycle of length 4 (3, 4, 5, 6)
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Counter {
+        value: Int,
+    }
+
+    impl Simulation for Counter {
+        type Key = Int;
+
+        fn step(&mut self) {
+            self.value = if self.value == 6 { 3 } else { self.value + 1 };
+        }
+
+        fn key(&self) -> Int {
+            self.value
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let mut counter = Counter { value: 0 };
+        test!(
+            Cycle {
+                start: 3,
+                length: 4
+            },
+            counter.find_cycle()
+        );
+        test!(3, counter.value);
+    }
+
+    #[test]
+    fn test_state_after() {
+        let mut brute_force = Counter { value: 0 };
+        for steps in 0..50 {
+            test!(
+                brute_force,
+                Counter { value: 0 }.state_after(steps),
+                "state_after({})",
+                steps
+            );
+            brute_force.step();
+        }
+        test!(4, Counter { value: 0 }.state_after(1000000000).value);
+    }
+
+    #[test]
+    fn test_advance_inspect() {
+        let mut counter = Counter { value: 0 };
+        let mut inspected = Vec::new();
+        counter.advance_inspect(10, |counter| inspected.push(counter.value));
+        // The cycle is detected after 7 steps, of which 3 remaining steps are simulated
+        test!(vec![1, 2, 3, 4, 5, 6, 3, 4, 5, 6], inspected);
+        test!(Counter { value: 0 }.state_after(10), counter);
+    }
+
+    #[test]
+    fn test_advance_numbered() {
+        let mut counter = Counter { value: 0 };
+        let mut steps = Vec::new();
+        counter.advance_numbered(0, 1000, |step, _| steps.push(step));
+        // Fast-forwarded from step 7 to step 999
+        test!(vec![1, 2, 3, 4, 5, 6, 7, 1000], steps);
+        let mut inspected = Vec::new();
+        Counter { value: 0 }
+            .advance_numbered(0, 10, |step, counter| inspected.push((step, counter.value)));
+        test!(
+            vec![
+                (1, 1),
+                (2, 2),
+                (3, 3),
+                (4, 4),
+                (5, 5),
+                (6, 6),
+                (7, 3),
+                (8, 4),
+                (9, 5),
+                (10, 6)
+            ],
+            inspected
+        );
+        test!(Counter { value: 0 }.state_after(1000), counter);
+        // Replayed from the state after 4 steps
+        let mut replayed = Counter { value: 4 };
+        replayed.advance_numbered(4, 1000, |_, _| ());
+        test!(counter, replayed);
+    }
+
+    #[test]
+    fn test_align() {
+        let cycle = |start, length| Cycle { start, length };
+        test!(Some(23), align(&[cycle(2, 3), cycle(3, 5), cycle(2, 7)]));
+        test!(Some(8), align(&[cycle(0, 4), cycle(2, 6)]));
+        test!(align(&[cycle(0, 4), cycle(1, 6)]).is_none());
+        test!(Some(10), align(&[cycle(10, 4), cycle(0, 2)]));
+        test!(
+            Some(3 * 4 * 5 * 7),
+            align(&[cycle(12, 12), cycle(15, 15), cycle(35, 35)])
+        );
+        test!(Some(0), align(&[]));
+        let large = |length| cycle(length, length);
+        test!(
+            Some::<Int>(18_215_611_419_223),
+            align(&[
+                large(20_777),
+                large(19_199),
+                large(18_673),
+                large(16_043),
+                large(12_361),
+                large(15_517)
+            ])
+        );
+    }
+}
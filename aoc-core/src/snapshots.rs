@@ -0,0 +1,164 @@
+#![allow(dead_code)]
+// This module saves the state of simulations every N steps when `--snapshots` is given, and restores a saved state
+// with `--replay`, so a divergence at step 700 of a long simulation can be debugged without simulating from step 0
+
+use crate::output::OutputDirectory;
+use crate::solution::Params;
+use crate::Int;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Directory that snapshots are saved to, snapshots are not saved if it is not set
+static DIRECTORY: OutputDirectory = OutputDirectory::new();
+
+/// Number of steps between snapshots, and the step to replay from
+static SETTINGS: OnceLock<(Int, Option<Int>)> = OnceLock::new();
+
+/// Save a snapshot every `every` steps to `directory`, and replay from the snapshot of step `replay` if it is given.
+/// Can only be set once.
+pub fn init(directory: impl AsRef<Path>, every: Int, replay: Option<Int>) {
+    DIRECTORY.init(directory);
+    let _ = SETTINGS.set((every.max(1), replay));
+}
+
+/// State of a simulation that can be saved as text, and restored from it
+pub trait Snapshot: Sized {
+    fn save(&self) -> String;
+
+    /// The state of which `snapshot` is the saved text
+    fn restore(snapshot: &str) -> Self;
+}
+
+/// Snapshots of a single simulation, saved to `<directory>/<name>/000000000.txt`, `000000100.txt`, ... by step.
+///
+/// `Snapshots::default()` saves and replays nothing, e.g. for a part that repeats the simulation of the other part.
+#[derive(Default)]
+pub struct Snapshots {
+    /// None if snapshots are not saved, or saving a snapshot failed
+    directory: Option<PathBuf>,
+    every: Int,
+    replay: Option<Int>,
+    count: usize,
+}
+
+impl Snapshots {
+    /// Snapshots of the simulation `name`, of which the directory gets an `-example` suffix for examples.
+    ///
+    /// Snapshots of a run of the same simulation by an earlier process are removed, unless one of them is replayed.
+    pub fn new(params: &Params, name: &str) -> Snapshots {
+        let Some((every, replay)) = SETTINGS.get() else {
+            return Snapshots::default();
+        };
+        Snapshots {
+            directory: DIRECTORY.subdirectory(params, name, replay.is_none()),
+            every: *every,
+            replay: *replay,
+            count: 0,
+        }
+    }
+
+    /// Returns true iff snapshots are saved
+    pub fn is_enabled(&self) -> bool {
+        self.directory.is_some()
+    }
+
+    /// Number of snapshots that were saved
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    fn path(directory: &Path, step: Int) -> PathBuf {
+        directory.join(format!("{:09}.txt", step))
+    }
+
+    /// Save `state` if `step` is a multiple of the number of steps between snapshots
+    pub fn record(&mut self, step: Int, state: &impl Snapshot) {
+        let Some(directory) = &self.directory else {
+            return;
+        };
+        if step % self.every != 0 {
+            return;
+        }
+        let path = Self::path(directory, step);
+        match fs::write(&path, state.save()) {
+            Ok(()) => self.count += 1,
+            Err(error) => {
+                tracing::warn!("Stopped saving snapshots, '{}': {}.", path.display(), error);
+                self.directory = None;
+            }
+        }
+    }
+
+    /// Replace `state` with the snapshot to replay, and return its step. Returns 0 and leaves `state` as it is if
+    /// no snapshot is replayed, or if there is no snapshot of that step.
+    pub fn replay<S: Snapshot>(&self, state: &mut S) -> Int {
+        let (Some(directory), Some(step)) = (&self.directory, self.replay) else {
+            return 0;
+        };
+        let path = Self::path(directory, step);
+        match fs::read_to_string(&path) {
+            Ok(snapshot) => {
+                tracing::info!("Replaying from '{}'.", path.display());
+                *state = S::restore(&snapshot);
+                step
+            }
+            Err(error) => {
+                tracing::warn!("Not replaying '{}': {}.", path.display(), error);
+                0
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[derive(Debug, PartialEq)]
+    struct Counter(Int);
+
+    impl Snapshot for Counter {
+        fn save(&self) -> String {
+            self.0.to_string()
+        }
+
+        fn restore(snapshot: &str) -> Self {
+            Counter(snapshot.parse().unwrap())
+        }
+    }
+
+    #[test]
+    fn test_snapshots() {
+        let root = std::env::temp_dir().join(format!("aoc-snapshots-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        let mut snapshots = Snapshots {
+            directory: Some(root.clone()),
+            every: 10,
+            ..Snapshots::default()
+        };
+        for step in 0..=25 {
+            snapshots.record(step, &Counter(step * step));
+        }
+        test!(3, snapshots.len());
+        test!(
+            "400",
+            fs::read_to_string(root.join("000000020.txt")).unwrap()
+        );
+        let mut counter = Counter(0);
+        test!(0 as Int, snapshots.replay(&mut counter));
+        snapshots.replay = Some(20);
+        test!(20 as Int, snapshots.replay(&mut counter));
+        test!(Counter(400), counter);
+        snapshots.replay = Some(25);
+        test!(0 as Int, snapshots.replay(&mut counter));
+        fs::remove_dir_all(&root).unwrap();
+        let disabled = Snapshots::new(&Params::INPUT, "disabled");
+        test!(!disabled.is_enabled() && disabled.is_empty());
+    }
+}
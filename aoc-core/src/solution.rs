@@ -0,0 +1,802 @@
+#![allow(unused_imports, dead_code)]
+pub use crate::repl::Repl;
+pub use crate::runner::Part;
+pub use crate::*;
+
+pub use nom::branch::alt;
+pub use nom::bytes::complete::tag;
+pub use nom::character::complete::{alpha1, digit1, one_of};
+pub use nom::combinator::{map, map_res};
+pub use nom::multi::separated_list1;
+pub use nom::sequence::{preceded, terminated, tuple};
+pub use nom::IResult;
+
+use colored::Colorize;
+pub use std::collections::HashMap;
+use std::fmt::write;
+pub use std::fmt::Debug;
+use std::fmt::Display;
+use std::iter;
+pub use std::iter::once;
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+pub use std::time::Duration;
+pub use std::time::Instant;
+
+pub type Year = i32;
+pub type Day = u32;
+/// Puzzle input of a Solution, borrowed from the runner, so it is never copied for a part or an example
+pub type Input<'a> = &'a str;
+pub type ExampleInput = &'static str;
+
+use colored::*;
+
+/// Advent of Code Answer, usually a number, but some puzzles spell out their answer in letters
+#[derive(PartialEq, Eq, Clone, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(Int),
+    String(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(int) => write!(f, "{}", int),
+            Answer::String(string) => write!(f, "{}", string),
+        }
+    }
+}
+
+impl Debug for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(int) => write!(f, "{}", int),
+            Answer::String(string) => write!(f, "{:?}", string),
+        }
+    }
+}
+
+impl From<Int> for Answer {
+    fn from(int: Int) -> Self {
+        Answer::Int(int)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(string: String) -> Self {
+        Answer::String(string)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(string: &str) -> Self {
+        Answer::String(string.to_string())
+    }
+}
+
+/// Use the newtype pattern to implement `From` and `Into` for `Input` and `Vec<&str>`. \
+/// `InputLines` is only a wrapper for `Vec<&str>`, of which the lines borrow from the `Input`.
+///
+/// See: https://doc.rust-lang.org/book/ch19-03-advanced-traits.html#using-the-newtype-pattern-to-implement-external-traits-on-external-types
+pub struct InputLines<'a>(Vec<&'a str>);
+
+impl<'a> InputLines<'a> {
+    pub fn filter_empty_lines(self) -> InputLines<'a> {
+        InputLines(self.0.into_iter().filter(|line| !line.is_empty()).collect())
+    }
+}
+
+impl Debug for InputLines<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#?}", self.0)
+    }
+}
+
+/// Make Input convertible to InputLines(Vec<&str>) by lines()
+impl<'a> From<Input<'a>> for InputLines<'a> {
+    fn from(input: Input<'a>) -> Self {
+        InputLines(input.lines().collect())
+    }
+}
+
+/// Make the input of a Solution without a parse step convertible to InputLines(Vec<&str>) by lines()
+impl<'a> From<&'a String> for InputLines<'a> {
+    fn from(input: &'a String) -> Self {
+        InputLines(input.lines().collect())
+    }
+}
+
+/// Make InputLines convertible to Vec<&str>
+impl<'a> From<InputLines<'a>> for Vec<&'a str> {
+    fn from(lines: InputLines<'a>) -> Self {
+        lines.0
+    }
+}
+
+/// Make InputLines convertible to Vec<String>, which copies every line
+impl From<InputLines<'_>> for Vec<String> {
+    fn from(lines: InputLines<'_>) -> Self {
+        lines.0.into_iter().map(String::from).collect()
+    }
+}
+
+/// Make InputLines convertible to Grid<char>
+impl From<InputLines<'_>> for Grid<char> {
+    fn from(lines: InputLines<'_>) -> Self {
+        lines.0.into_iter().map(|s| s.chars().collect()).collect()
+    }
+}
+
+/// Trim example_input, remove preceding spaces from all lines, remove first \n, keep empty lines intact
+fn trim_example_input(input: ExampleInput) -> String {
+    input
+        .lines()
+        .map(|line| {
+            if line.trim().is_empty() {
+                line // Keep empty lines intact
+            } else {
+                line.trim_start() // Trim leading spaces from non-empty lines
+            }
+        })
+        .skip(1) // Skip first
+        .take(input.lines().count().saturating_sub(2)) // Skip last
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[derive(PartialEq, Eq)]
+pub enum TestStatus {
+    Failed(Duration, Answer),
+    Error(Duration),
+    Success(Duration, Answer),
+    Timeout(Duration),
+    /// Ran to completion, but there is no expected answer for this input in answers.toml
+    Unverified(Duration, Answer),
+    /// Did not run, the answer of this version of the part for this input was in the cache
+    Cached(Answer),
+    Unknown,
+}
+
+impl Debug for TestStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Failed(duration, answer) => {
+                write!(
+                    f,
+                    "[TestStatus::{}] [{}] {:.2?}",
+                    "Failed".red(),
+                    answer,
+                    duration
+                )
+            }
+            Self::Error(duration) => write!(f, "[TestStatus::{}] {:.2?}", "Error".red(), duration),
+            Self::Success(duration, answer) => {
+                write!(
+                    f,
+                    "[TestStatus::{}] [{}] {:.2?}",
+                    "Success".green(),
+                    answer,
+                    duration
+                )
+            }
+            Self::Timeout(duration) => {
+                write!(f, "[TestStatus::{}] {:.2?}", "Timeout".red(), duration)
+            }
+            Self::Unverified(duration, answer) => {
+                write!(
+                    f,
+                    "[TestStatus::{}] [{}] {:.2?}",
+                    "Unverified".yellow(),
+                    answer,
+                    duration
+                )
+            }
+            Self::Cached(answer) => write!(f, "[TestStatus::{}] [{}]", "Cached".cyan(), answer),
+            Self::Unknown => write!(f, "[TestStatus::Unknown]"),
+        }
+    }
+}
+
+impl TestStatus {
+    /// Name of the variant, e.g. `Success`, without its duration and answer
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Failed(..) => "Failed",
+            Self::Error(_) => "Error",
+            Self::Success(..) => "Success",
+            Self::Timeout(_) => "Timeout",
+            Self::Unverified(..) => "Unverified",
+            Self::Cached(_) => "Cached",
+            Self::Unknown => "Unknown",
+        }
+    }
+
+    /// Duration of a part that ran to completion, or was abandoned after its timeout, a cached part did not run
+    pub fn duration(&self) -> Option<Duration> {
+        match self {
+            Self::Success(duration, _)
+            | Self::Failed(duration, _)
+            | Self::Unverified(duration, _)
+            | Self::Timeout(duration) => Some(*duration),
+            Self::Error(_) | Self::Cached(_) | Self::Unknown => None,
+        }
+    }
+
+    /// The Answer of a part that ran to completion, whether it was correct or not
+    pub fn answer(&self) -> Option<&Answer> {
+        match self {
+            Self::Failed(_, answer)
+            | Self::Success(_, answer)
+            | Self::Unverified(_, answer)
+            | Self::Cached(answer) => Some(answer),
+            _ => None,
+        }
+    }
+}
+
+pub struct TestResult {
+    pub year: i32,
+    pub day: u32,
+    /// Duration of the parse step, if it finished
+    pub parse: Option<Duration>,
+    pub p1: TestStatus,
+    pub p2: TestStatus,
+    pub examples: TestStatus,
+    /// Peak heap memory of each part in bytes, if memory is tracked, see `memory::measure`
+    pub p1_memory: Option<usize>,
+    pub p2_memory: Option<usize>,
+}
+
+impl Debug for TestResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TestResult {{\n",).unwrap();
+        write!(
+            f,
+            "\t[Ex] [{}] [{}] {:?}\n",
+            self.year, self.day, self.examples
+        )
+        .unwrap();
+        if let Some(parse) = self.parse {
+            writeln!(f, "\t[Parse] [{}] [{}] {:.2?}", self.year, self.day, parse).unwrap();
+        }
+        let memory = |memory: Option<usize>| match memory {
+            Some(memory) => format!(" [{}]", crate::memory::format_bytes(memory)),
+            None => String::new(),
+        };
+        write!(
+            f,
+            "\t[P1] [{}] [{}] {:?}{}\n",
+            self.year,
+            self.day,
+            self.p1,
+            memory(self.p1_memory)
+        )
+        .unwrap();
+        write!(
+            f,
+            "\t[P2] [{}] [{}] {:?}{}\n}}",
+            self.year,
+            self.day,
+            self.p2,
+            memory(self.p2_memory)
+        )
+    }
+}
+
+/// Technique or topic of an Advent of Code problem, used to select related solutions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, clap::ValueEnum)]
+pub enum Tag {
+    DynamicProgramming,
+    Geometry,
+    Graph,
+    Grid,
+    Hashing,
+    Intervals,
+    Math,
+    Parsing,
+    Simulation,
+    Sorting,
+    Strings,
+}
+
+impl Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use clap::ValueEnum;
+        write!(f, "{}", self.to_possible_value().unwrap().get_name())
+    }
+}
+
+/// Parameters of a solve, so an example can use other constants than the real input, like fewer steps
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Params {
+    is_example: bool,
+    values: &'static [(&'static str, Int)],
+    artifacts: &'static [(&'static str, ExampleInput)],
+}
+
+impl Params {
+    /// Params of the real puzzle input
+    pub const INPUT: Params = Params {
+        is_example: false,
+        values: &[],
+        artifacts: &[],
+    };
+
+    /// Params of an example, of which `values` override the constants of the solver
+    pub const fn example(values: &'static [(&'static str, Int)]) -> Params {
+        Params {
+            is_example: true,
+            values,
+            artifacts: &[],
+        }
+    }
+
+    /// These Params, with the expected artifacts of an Example
+    fn with_artifacts(self, artifacts: &'static [(&'static str, ExampleInput)]) -> Params {
+        Params { artifacts, ..self }
+    }
+
+    pub fn is_example(&self) -> bool {
+        self.is_example
+    }
+
+    /// The value of parameter `name`, or `default` if it is not overridden
+    pub fn get(&self, name: &str, default: Int) -> Int {
+        self.values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map_or(default, |&(_, value)| value)
+    }
+
+    /// Assert that `actual` is the expected artifact `label` of the Example that is solved, like the energized tiles
+    /// of 2023-16 drawn as a map. Does nothing if there is no such artifact, e.g. for the real input.
+    pub fn check_artifact(&self, label: &str, actual: impl Display) {
+        if let Some(&(_, expected)) = self.artifacts.iter().find(|(key, _)| *key == label) {
+            test!(trim_example_input(expected), actual.to_string(), label);
+        }
+    }
+}
+
+/// Trait for implementing an Advent of Code problem.
+///
+/// The input is parsed once by `parse` into `Parsed`, which both parts share, and the parse time is reported
+/// separately. A Solution that has no parse step uses `no_parse_step!()`, which hands both parts the input as a
+/// String. Malformed input is an `Err` of the parse step or the parts, which fails that part of the run instead of
+/// crashing it.
+///
+/// A Solution is `Send + Sync`, because its parts run on worker threads, and days run in parallel with `--jobs`,
+/// so any state that is shared between its parts needs a `Mutex` or an atomic instead of a `Cell`.
+pub trait Solution: Send + Sync {
+    /// The input after the parse step, like a Grid
+    type Parsed: Send + Sync + 'static;
+
+    /// Parse the input once for both parts
+    fn parse(&self, input: Input, params: &Params) -> AocResult<Self::Parsed>;
+
+    /// Solve AoC(`YEAR`, `DAY`) part one from the result of `parse`
+    fn solve_part_one(&self, parsed: &Self::Parsed, params: &Params) -> AocResult<Answer>;
+
+    /// Solve AoC(`YEAR`, `DAY`) part two from the result of `parse`
+    fn solve_part_two(&self, parsed: &Self::Parsed, params: &Params) -> AocResult<Answer>;
+
+    /// Whether `parse` does more than copy the input, so its time is worth reporting, false for `no_parse_step!()`
+    fn has_parse_step(&self) -> bool {
+        true
+    }
+
+    fn year(&self) -> Year;
+
+    fn day(&self) -> Day;
+
+    /// Techniques and topics of this problem, used by `--tag`
+    fn tags(&self) -> &'static [Tag] {
+        &[]
+    }
+
+    /// Narrated intermediate results of this Solution for `input`, e.g. the state after every stage,
+    /// which is printed under `--explain`
+    fn explain(&self, _input: Input) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Commands to explore the result of `parse` interactively, e.g. tilting the platform of 2023-14,
+    /// which `repl` runs
+    fn repl(&self, _parsed: &Self::Parsed) -> Option<Box<dyn Repl>> {
+        None
+    }
+
+    /// Override the `--timeout` after which a part of this Solution is abandoned
+    fn timeout(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Number of threads this Solution uses at once, e.g. `rayon::current_num_threads()` when it uses `ParLines`,
+    /// so running solutions in parallel does not use more than `--jobs` threads
+    fn threads(&self) -> usize {
+        1
+    }
+
+    /// Opt in to caching the answer of `part` across runs, for slow parts that are deterministic.
+    ///
+    /// The cached answer is only used for the same version and input, so change the version whenever the
+    /// part or its parse step changes.
+    fn cache_version(&self, _part: Part) -> Option<&'static str> {
+        None
+    }
+
+    /// Define Advent of Code examples
+    fn define_examples(&self) -> Vec<Example> {
+        Vec::new()
+    }
+
+    /// Run all given examples
+    fn run_examples(&self) -> bool {
+        let format = |part: usize| format!("[Ex] [{}] [{}] [{}]", self.year(), self.day(), part);
+
+        for (i, example) in self.define_examples().iter().enumerate() {
+            let input = &example.get_input();
+            let params = &example.params.with_artifacts(example.artifacts);
+            let solve = |part: Part| {
+                let answer = self.parse(input, params).and_then(|parsed| match part {
+                    Part::One => self.solve_part_one(&parsed, params),
+                    Part::Two => self.solve_part_two(&parsed, params),
+                });
+                answer.unwrap_or_else(|error| {
+                    panic!(
+                        "[Test Case] ❌ {:?} {}",
+                        format(part.number() as usize),
+                        error
+                    )
+                })
+            };
+            match &example.expect {
+                Expect::PartOne(one) => {
+                    test!(one.clone(), solve(Part::One), format(1));
+                }
+                Expect::PartTwo(two) => {
+                    test!(two.clone(), solve(Part::Two), format(2));
+                }
+                Expect::PartsOneAndTwo(one, two) => {
+                    test!(one.clone(), solve(Part::One), format(1));
+                    test!(two.clone(), solve(Part::Two), format(2));
+                }
+                Expect::PartOneWith(predicate) => {
+                    test_predicate(predicate, solve(Part::One), format(1));
+                }
+                Expect::PartTwoWith(predicate) => {
+                    test_predicate(predicate, solve(Part::Two), format(2));
+                }
+                Expect::PartOneInRange(low, high) => {
+                    test_range(*low..=*high, solve(Part::One), format(1));
+                }
+                Expect::PartTwoInRange(low, high) => {
+                    test_range(*low..=*high, solve(Part::Two), format(2));
+                }
+                Expect::Any => (),
+            }
+        }
+        true
+    }
+
+    fn create_box() -> SolutionBox
+    where
+        Self: Sized + Default + 'static,
+    {
+        Arc::new(Self::default())
+    }
+}
+
+/// Shared Solution, as stored in the registry of all Advent of Code solutions, of which the Parsed type is erased.
+///
+/// Solutions are shared with the worker threads that run their parts.
+pub type SolutionBox = Arc<dyn crate::registry::DynSolution>;
+
+/// Trait to allow a type to be parsed from Problem Input
+pub trait Parse {
+    fn parse(input: Input) -> Self;
+}
+
+/// Parse a single number
+pub fn parse_num(input: &str) -> IResult<&str, Int> {
+    map_res(digit1, str::parse::<Int>)(input)
+}
+
+/// Advent of Code ExampleInput expectation for Problem part one, part two, or both
+pub enum Expect {
+    PartOne(Answer),
+    PartTwo(Answer),
+    PartsOneAndTwo(Answer, Answer),
+    /// The answer satisfies a Predicate, for puzzles where several answers are acceptable
+    PartOneWith(Predicate),
+    PartTwoWith(Predicate),
+    /// The answer is in `low..=high`, for when only bounds are known before solving
+    PartOneInRange(Int, Int),
+    PartTwoInRange(Int, Int),
+    Any,
+}
+
+/// Check on an Answer, with a description that is printed when it fails, see `predicate!`
+#[derive(Clone, Copy)]
+pub struct Predicate {
+    pub description: &'static str,
+    pub check: fn(Answer) -> bool,
+}
+
+/// Create a Predicate from a closure, described by its own source code
+#[macro_export]
+macro_rules! predicate {
+    ($check:expr) => {
+        Predicate {
+            description: stringify!($check),
+            check: $check,
+        }
+    };
+}
+
+/// Assert that `answer` satisfies `predicate`, print both if it does not
+fn test_predicate(predicate: &Predicate, answer: Answer, name: String) {
+    assert!(
+        (predicate.check)(answer.clone()),
+        "[Test Case] ❌ ({:?}, {:?}) does not satisfy `{}`",
+        answer,
+        name,
+        predicate.description
+    );
+    println!("[Test Case] ✅ ({:?}, {:?})", predicate.description, name);
+}
+
+/// Assert that `answer` is a number in `range`, print both if it is not
+fn test_range(range: RangeInclusive<Int>, answer: Answer, name: String) {
+    let is_in_range = matches!(answer, Answer::Int(int) if range.contains(&int));
+    assert!(
+        is_in_range,
+        "[Test Case] ❌ ({:?}, {:?}) is not in {:?}",
+        answer, name, range
+    );
+    println!("[Test Case] ✅ ({:?}, {:?})", range, name);
+}
+
+/// Where the input of an Advent of Code Example comes from
+pub enum ExampleSource {
+    /// ExampleInput in the source, which is trimmed by `trim_example_input`
+    Inline(ExampleInput),
+    /// Absolute path of a file, see `example_file!`, which is used as is
+    File(&'static str),
+}
+
+impl From<ExampleInput> for ExampleSource {
+    fn from(input: ExampleInput) -> Self {
+        ExampleSource::Inline(input)
+    }
+}
+
+/// Read the input of an Example from a file, for examples that would bloat the source.
+///
+/// ```ignore
+/// example_file!("examples/y2023/d05/ex1.txt")
+/// ```
+///
+/// The path is relative to the root of the crate that calls it, so it does not depend on the working directory.
+#[macro_export]
+macro_rules! example_file {
+    ($path:expr) => {
+        $crate::solution::ExampleSource::File(concat!(env!("CARGO_MANIFEST_DIR"), "/", $path))
+    };
+}
+
+/// Advent of Code ExampleInput and expectation, and the Params to solve it with
+pub struct Example {
+    pub input: ExampleSource,
+    pub expect: Expect,
+    pub params: Params,
+    /// Expected intermediate results by label, which the solver checks with `Params::check_artifact`. They are
+    /// trimmed like an inline ExampleInput.
+    pub artifacts: &'static [(&'static str, ExampleInput)],
+}
+
+impl Example {
+    pub fn get_input(&self) -> String {
+        match self.input {
+            ExampleSource::Inline(input) => trim_example_input(input),
+            ExampleSource::File(path) => std::fs::read_to_string(path).unwrap_or_else(|error| {
+                panic!("Failed to read example file '{}': {}.", path, error)
+            }),
+        }
+    }
+}
+
+/// A single Advent of Code Example of `define_examples!`, of which the Params and the artifacts are optional
+#[macro_export]
+macro_rules! example {
+    (@build $input:expr, $expect:expr, $params:expr, $artifacts:expr) => {
+        Example {
+            input: $input.into(),
+            expect: $expect,
+            params: $params,
+            artifacts: $artifacts,
+        }
+    };
+    (
+        $input:expr,
+        Expect::$expect:ident $(($($answer:expr),* $(,)?))?,
+        artifacts: $artifacts:expr $(,)?
+    ) => {
+        $crate::example!(
+            @build $input,
+            Expect::$expect $(($($answer.into()),*))?,
+            Params::example(&[]),
+            &$artifacts
+        )
+    };
+    (
+        $input:expr,
+        Expect::$expect:ident $(($($answer:expr),* $(,)?))?,
+        $params:expr,
+        artifacts: $artifacts:expr $(,)?
+    ) => {
+        $crate::example!(@build $input, Expect::$expect $(($($answer.into()),*))?, $params, &$artifacts)
+    };
+    ($input:expr, Expect::$expect:ident $(($($answer:expr),* $(,)?))?, $params:expr $(,)?) => {
+        $crate::example!(@build $input, Expect::$expect $(($($answer.into()),*))?, $params, &[])
+    };
+    ($input:expr, Expect::$expect:ident $(($($answer:expr),* $(,)?))? $(,)?) => {
+        $crate::example!(
+            @build $input,
+            Expect::$expect $(($($answer.into()),*))?,
+            Params::example(&[]),
+            &[]
+        )
+    };
+}
+
+/// Declare that a Solution has no parse step, both parts get the input as a String
+#[macro_export]
+macro_rules! no_parse_step {
+    () => {
+        type Parsed = String;
+
+        fn parse(&self, input: Input, _params: &Params) -> AocResult<String> {
+            Ok(input.to_string())
+        }
+
+        fn has_parse_step(&self) -> bool {
+            false
+        }
+    };
+}
+
+/// Define Advent of Code Examples
+#[macro_export]
+macro_rules! define_examples {
+    ($(($($example:tt)*)),* $(,)?) => {
+        fn define_examples(&self) -> Vec<Example> {
+            vec![$($crate::example!($($example)*)),*]
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Solution that answers with the length of its input, plus the "offset" parameter in part one
+    #[derive(Default)]
+    struct Length {}
+
+    /// Params of an example that are not built in `define_examples!`
+    const OFFSET: Params = Params::example(&[("offset", 10)]);
+
+    impl Solution for Length {
+        no_parse_step!();
+
+        fn solve_part_one(&self, input: &String, params: &Params) -> AocResult<Answer> {
+            params.check_artifact("lines", input.lines().count());
+            Ok((input.len() as Int + params.get("offset", 0)).into())
+        }
+        fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+            Ok(input.as_str().into())
+        }
+        fn year(&self) -> Year {
+            2023
+        }
+        fn day(&self) -> Day {
+            0
+        }
+        define_examples! {
+            (
+                "
+                abc
+                ",
+                Expect::PartOneInRange(1, 3),
+            ),
+            (
+                "
+                abc
+                ",
+                Expect::PartTwoWith(predicate!(|answer| answer.to_string().starts_with('a'))),
+            ),
+            (
+                "
+                abc
+                def
+                ",
+                Expect::PartOne(7),
+                artifacts: [(
+                    "lines",
+                    "
+                    2
+                    ",
+                )],
+            ),
+            (
+                "
+                abc
+                ",
+                Expect::PartOne(13),
+                OFFSET,
+            ),
+            (
+                "
+                abc
+                def
+                ",
+                Expect::PartOne(17),
+                OFFSET,
+                artifacts: [(
+                    "lines",
+                    "
+                    2
+                    ",
+                )],
+            ),
+        }
+    }
+
+    /// Length, with an example of which the answer is out of range
+    #[derive(Default)]
+    struct OutOfRange {}
+
+    impl Solution for OutOfRange {
+        no_parse_step!();
+
+        fn solve_part_one(&self, input: &String, params: &Params) -> AocResult<Answer> {
+            Length {}.solve_part_one(input, params)
+        }
+        fn solve_part_two(&self, input: &String, params: &Params) -> AocResult<Answer> {
+            Length {}.solve_part_two(input, params)
+        }
+        fn year(&self) -> Year {
+            2023
+        }
+        fn day(&self) -> Day {
+            0
+        }
+        define_examples! {
+            (
+                "
+                abcd
+                ",
+                Expect::PartOneInRange(1, 3),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_expectations() {
+        test!(Length {}.run_examples());
+    }
+
+    #[test]
+    #[should_panic(expected = "\"lines\"")]
+    fn test_artifact_fails() {
+        let params = Params::example(&[]).with_artifacts(&[("lines", "\n3\n")]);
+        params.check_artifact("lines", 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not in 1..=3")]
+    fn test_range_expectation_fails() {
+        OutOfRange {}.run_examples();
+    }
+}
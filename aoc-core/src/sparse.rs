@@ -0,0 +1,71 @@
+// This module converts between a sparse set of Points, like the cells of an unbounded simulation, and a dense Map of
+// their bounding box, e.g. to render the simulation, or to run the grid algorithms on it
+
+use crate::grid::{Map, Point};
+use crate::hash::FastHashSet;
+use crate::Int;
+
+impl<T: Clone> Map<T> {
+    /// The Map of the bounding box of `points`, of which the cells of the points are `value(point)`, and the other
+    /// cells are `fill`. Also returns the Point that the top-left cell is at, which `to_points` needs to map the
+    /// cells back, the Map is empty if there are no points.
+    pub fn from_points<'a>(
+        points: impl IntoIterator<Item = &'a Point> + Clone,
+        fill: T,
+        value: impl Fn(&Point) -> T,
+    ) -> (Map<T>, Point) {
+        let Some(first) = points.clone().into_iter().next() else {
+            return (Map { grid: Vec::new() }, Point::new(0, 0));
+        };
+        let (mut min, mut max) = (*first, *first);
+        for point in points.clone() {
+            min = Point::new(min.x.min(point.x), min.y.min(point.y));
+            max = Point::new(max.x.max(point.x), max.y.max(point.y));
+        }
+        let (columns, rows) = ((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize);
+        let mut map = Map {
+            grid: vec![vec![fill; columns]; rows],
+        };
+        for point in points {
+            map.grid[(point.y - min.y) as usize][(point.x - min.x) as usize] = value(point);
+        }
+        (map, min)
+    }
+}
+
+impl<T> Map<T> {
+    /// The Points of the cells for which `predicate` holds, of which the top-left cell is at `origin`, the inverse
+    /// of `from_points`
+    pub fn to_points(&self, origin: Point, predicate: impl Fn(&T) -> bool) -> FastHashSet<Point> {
+        self.grid
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, value)| (x, y, value)))
+            .filter(|(_, _, value)| predicate(value))
+            .map(|(x, y, _)| origin.translate(x as Int, y as Int))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_from_points() {
+        let points: FastHashSet<Point> = [(-2, 1), (0, 0), (1, 2)]
+            .iter()
+            .map(|&(x, y)| Point::new(x, y))
+            .collect();
+        let (map, origin) = Map::from_points(&points, '.', |_| '#');
+        test!(Point::new(-2, 0), origin);
+        test!("..#.\n#...\n...#", map.to_string());
+        test!(points, map.to_points(origin, |&c| c == '#'));
+        let (map, _) = Map::from_points(&[Point::new(5, 5)], 0, |point| point.x);
+        test!("5", map.to_string());
+        let (map, origin) = Map::from_points(&Vec::<Point>::new(), '.', |_| '#');
+        test!(map.is_empty());
+        test!(map.to_points(origin, |_| true).is_empty());
+    }
+}
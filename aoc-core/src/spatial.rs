@@ -0,0 +1,239 @@
+#![allow(dead_code)]
+// This module contains a KD-tree over 2D and 3D points, for nearest neighbor and radius queries that would otherwise
+// compare every pair of points, like clustering constellations or matching beacons
+
+use crate::geometry::Point3;
+use crate::grid::Point;
+use crate::Int;
+
+/// A point with `D` integer coordinates
+pub trait Coordinates<const D: usize> {
+    fn coordinates(&self) -> [Int; D];
+}
+
+impl Coordinates<2> for Point {
+    fn coordinates(&self) -> [Int; 2] {
+        [self.x, self.y]
+    }
+}
+
+impl Coordinates<3> for Point3 {
+    fn coordinates(&self) -> [Int; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+/// How the distance between two points is measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    /// The squared Euclidean distance, so it stays an integer
+    SquaredEuclidean,
+    Manhattan,
+}
+
+impl Metric {
+    pub fn distance<const D: usize>(self, a: &[Int; D], b: &[Int; D]) -> Int {
+        a.iter()
+            .zip(b)
+            .map(|(a, b)| self.axis_distance(a - b))
+            .sum()
+    }
+
+    /// The distance of two points that differ by `difference` in a single axis, which is a lower bound of their
+    /// distance if they also differ in other axes
+    fn axis_distance(self, difference: Int) -> Int {
+        match self {
+            Metric::SquaredEuclidean => difference * difference,
+            Metric::Manhattan => difference.abs(),
+        }
+    }
+}
+
+/// A KD-tree, which splits the points at the median of every axis in turn.
+///
+/// The tree is implicit: the median of a range of `points` is in the middle of that range, with the points before it
+/// on one side, and the points after it on the other side.
+#[derive(Debug, Clone)]
+pub struct KdTree<P, const D: usize> {
+    points: Vec<P>,
+}
+
+impl<P: Coordinates<D>, const D: usize> KdTree<P, D> {
+    pub fn new(mut points: Vec<P>) -> KdTree<P, D> {
+        Self::build(&mut points, 0);
+        KdTree { points }
+    }
+
+    fn build(points: &mut [P], axis: usize) {
+        if points.len() <= 1 {
+            return;
+        }
+        let middle = points.len() / 2;
+        points.select_nth_unstable_by_key(middle, |point| point.coordinates()[axis]);
+        let (left, right) = points.split_at_mut(middle);
+        Self::build(left, (axis + 1) % D);
+        Self::build(&mut right[1..], (axis + 1) % D);
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &P> {
+        self.points.iter()
+    }
+
+    /// The point that is closest to `target`, which is `target` itself if it is in the tree
+    pub fn nearest(&self, target: &impl Coordinates<D>, metric: Metric) -> Option<&P> {
+        let mut best: Option<(Int, usize)> = None;
+        self.nearest_in(
+            0..self.points.len(),
+            0,
+            &target.coordinates(),
+            metric,
+            &mut best,
+        );
+        best.map(|(_, i)| &self.points[i])
+    }
+
+    fn nearest_in(
+        &self,
+        range: std::ops::Range<usize>,
+        axis: usize,
+        target: &[Int; D],
+        metric: Metric,
+        best: &mut Option<(Int, usize)>,
+    ) {
+        if range.is_empty() {
+            return;
+        }
+        let middle = range.start + range.len() / 2;
+        let coordinates = self.points[middle].coordinates();
+        let distance = metric.distance(&coordinates, target);
+        if best.is_none_or(|(best, _)| distance < best) {
+            *best = Some((distance, middle));
+        }
+        let difference = target[axis] - coordinates[axis];
+        let (near, far) = match difference < 0 {
+            true => (range.start..middle, middle + 1..range.end),
+            false => (middle + 1..range.end, range.start..middle),
+        };
+        let next_axis = (axis + 1) % D;
+        self.nearest_in(near, next_axis, target, metric, best);
+        // The far side can only be closer if the splitting plane is
+        if best.is_none_or(|(best, _)| metric.axis_distance(difference) < best) {
+            self.nearest_in(far, next_axis, target, metric, best);
+        }
+    }
+
+    /// The points within `radius` of `target`, inclusive, in no particular order. With `Metric::SquaredEuclidean`,
+    /// `radius` is a squared distance as well.
+    pub fn within(&self, target: &impl Coordinates<D>, radius: Int, metric: Metric) -> Vec<&P> {
+        let mut found = Vec::new();
+        let target = target.coordinates();
+        self.within_in(0..self.points.len(), 0, &target, radius, metric, &mut found);
+        found
+    }
+
+    fn within_in<'a>(
+        &'a self,
+        range: std::ops::Range<usize>,
+        axis: usize,
+        target: &[Int; D],
+        radius: Int,
+        metric: Metric,
+        found: &mut Vec<&'a P>,
+    ) {
+        if range.is_empty() {
+            return;
+        }
+        let middle = range.start + range.len() / 2;
+        let coordinates = self.points[middle].coordinates();
+        if metric.distance(&coordinates, target) <= radius {
+            found.push(&self.points[middle]);
+        }
+        let difference = target[axis] - coordinates[axis];
+        let next_axis = (axis + 1) % D;
+        let reaches_plane = metric.axis_distance(difference) <= radius;
+        if difference < 0 || reaches_plane {
+            self.within_in(
+                range.start..middle,
+                next_axis,
+                target,
+                radius,
+                metric,
+                found,
+            );
+        }
+        if difference >= 0 || reaches_plane {
+            self.within_in(
+                middle + 1..range.end,
+                next_axis,
+                target,
+                radius,
+                metric,
+                found,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use crate::testing::random_coordinates;
+
+    #[test]
+    fn test_nearest() {
+        let points: Vec<Point> = random_coordinates(1000)
+            .chunks(2)
+            .map(|c| Point::new(c[0], c[1]))
+            .collect();
+        let tree = KdTree::new(points.clone());
+        test!(points.len(), tree.len());
+        for metric in [Metric::SquaredEuclidean, Metric::Manhattan] {
+            for target in [Point::new(500, 500), Point::new(-20, 1200), points[7]] {
+                let distance = |p: &Point| metric.distance(&p.coordinates(), &target.coordinates());
+                let brute_force = points.iter().map(distance).min();
+                test!(brute_force, tree.nearest(&target, metric).map(distance));
+            }
+        }
+        test!(KdTree::<Point, 2>::new(Vec::new())
+            .nearest(&Point::new(0, 0), Metric::Manhattan)
+            .is_none());
+    }
+
+    #[test]
+    fn test_within() {
+        let points: Vec<Point3> = random_coordinates(1500)
+            .chunks(3)
+            .map(|c| Point3::new(c[0], c[1], c[2]))
+            .collect();
+        let tree = KdTree::new(points.clone());
+        let target = Point3::new(400, 600, 500);
+        for (metric, radius) in [
+            (Metric::Manhattan, 150),
+            (Metric::SquaredEuclidean, 100 * 100),
+        ] {
+            let mut found: Vec<Point3> = tree
+                .within(&target, radius, metric)
+                .into_iter()
+                .copied()
+                .collect();
+            found.sort();
+            let mut brute_force: Vec<Point3> = points
+                .iter()
+                .filter(|p| metric.distance(&p.coordinates(), &target.coordinates()) <= radius)
+                .copied()
+                .collect();
+            brute_force.sort();
+            test!(!brute_force.is_empty());
+            test!(brute_force, found);
+        }
+    }
+}
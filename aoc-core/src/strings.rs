@@ -0,0 +1,110 @@
+// This module contains the string handling that parsers keep needing: splitting a line into pieces of a fixed width
+// or on several delimiters, reading lines as columns, and the longest common prefix of several strings
+
+/// Extension methods of `str` for parsing puzzle inputs
+pub trait StrExt {
+    /// Pieces of `n` characters, the last piece is shorter if the length is not a multiple of `n`
+    fn chunks_of(&self, n: usize) -> Vec<&str>;
+
+    /// Pieces between any of the `delimiters`, without the empty pieces, the longest delimiter wins if several
+    /// start at the same position, e.g. `"Game 1: 3 blue, 4 red"` on `[": ", ", "]`
+    fn split_any<'a>(&'a self, delimiters: &[&str]) -> Vec<&'a str>;
+}
+
+impl StrExt for str {
+    fn chunks_of(&self, n: usize) -> Vec<&str> {
+        assert!(n > 0, "Chunks of 0 characters are not possible.");
+        let mut chunks = Vec::new();
+        let mut rest = self;
+        while !rest.is_empty() {
+            let end = rest.char_indices().nth(n).map_or(rest.len(), |(i, _)| i);
+            let (chunk, remainder) = rest.split_at(end);
+            chunks.push(chunk);
+            rest = remainder;
+        }
+        chunks
+    }
+
+    fn split_any<'a>(&'a self, delimiters: &[&str]) -> Vec<&'a str> {
+        let mut pieces = Vec::new();
+        let (mut start, mut i) = (0, 0);
+        while i < self.len() {
+            let delimiter = delimiters
+                .iter()
+                .filter(|delimiter| !delimiter.is_empty() && self[i..].starts_with(**delimiter))
+                .max_by_key(|delimiter| delimiter.len());
+            match delimiter {
+                Some(delimiter) => {
+                    pieces.push(&self[start..i]);
+                    i += delimiter.len();
+                    start = i;
+                }
+                None => i += self[i..].chars().next().map_or(1, char::len_utf8),
+            }
+        }
+        pieces.push(&self[start..]);
+        pieces.retain(|piece| !piece.is_empty());
+        pieces
+    }
+}
+
+/// The columns of `lines` as lines, the first column becomes the first line, panics if the lines are not equally long
+pub fn transpose<S: AsRef<str>>(lines: &[S]) -> Vec<String> {
+    let rows: Vec<Vec<char>> = lines
+        .iter()
+        .map(|line| line.as_ref().chars().collect())
+        .collect();
+    let columns = rows.first().map_or(0, Vec::len);
+    assert!(
+        rows.iter().all(|row| row.len() == columns),
+        "Only lines of equal length can be transposed."
+    );
+    (0..columns)
+        .map(|x| rows.iter().map(|row| row[x]).collect())
+        .collect()
+}
+
+/// The longest prefix that all `strings` start with, empty if there are no strings
+pub fn common_prefix<'a>(strings: &[&'a str]) -> &'a str {
+    let Some((first, rest)) = strings.split_first() else {
+        return "";
+    };
+    let end = first
+        .char_indices()
+        .map(|(i, c)| (i, i + c.len_utf8()))
+        .take_while(|&(i, end)| {
+            rest.iter()
+                .all(|string| string.get(i..end) == first.get(i..end))
+        })
+        .last()
+        .map_or(0, |(_, end)| end);
+    &first[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_strings() {
+        test!(vec!["abc", "def", "g"], "abcdefg".chunks_of(3));
+        test!(vec!["äö", "ü"], "äöü".chunks_of(2));
+        test!("".chunks_of(2).is_empty());
+        test!(
+            vec!["Game 1", "3 blue", "4 red", "1 red"],
+            "Game 1: 3 blue, 4 red; 1 red".split_any(&[": ", ", ", "; "])
+        );
+        test!(vec!["a", "b"], "a->b".split_any(&["-", "->"]));
+        test!(vec!["a", "b"], ",a,,b,".split_any(&[","]));
+        test!(vec!["#.", "..", "##"], transpose(&["#.#", "..#"]));
+        test!(transpose::<&str>(&[]).is_empty());
+        test!(
+            "inter",
+            common_prefix(&["interval", "internal", "interstellar"])
+        );
+        test!("", common_prefix(&["abc", "xyz"]));
+        test!("", common_prefix(&[]));
+        test!("ab", common_prefix(&["ab"]));
+    }
+}
@@ -0,0 +1,86 @@
+// This module finds the lines that mirror a grid, also with exactly k cells that do not mirror, like the smudged
+// mirrors of 2023-13
+
+/// A line between two rows or two columns of a grid, that mirrors the rows or columns on both sides of it, up to the
+/// edge of the grid on the nearest side
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mirror {
+    /// A horizontal line with this number of rows above it
+    Horizontal(usize),
+    /// A vertical line with this number of columns left of it
+    Vertical(usize),
+}
+
+/// Number of cells that differ between the rows or columns on both sides of the line after `before` of `len` rows or
+/// columns, of which `differences(a, b)` counts the differences between a single pair, stops counting above `limit`
+fn count_mismatches(
+    len: usize,
+    before: usize,
+    limit: usize,
+    differences: impl Fn(usize, usize) -> usize,
+) -> usize {
+    let mut mismatches = 0;
+    for j in 0..before.min(len - before) {
+        mismatches += differences(before - 1 - j, before + j);
+        if mismatches > limit {
+            break;
+        }
+    }
+    mismatches
+}
+
+/// Every Mirror of `grid` with exactly `mismatches` cells that differ from the cell they are mirrored onto, the
+/// horizontal ones from top to bottom first, then the vertical ones from left to right
+pub fn mirrors<T: PartialEq>(grid: &[Vec<T>], mismatches: usize) -> Vec<Mirror> {
+    let (rows, columns) = (grid.len(), grid.first().map_or(0, Vec::len));
+    let rows_differ =
+        |a: usize, b: usize| grid[a].iter().zip(&grid[b]).filter(|(a, b)| a != b).count();
+    let columns_differ = |a: usize, b: usize| grid.iter().filter(|row| row[a] != row[b]).count();
+    let horizontal = (1..rows)
+        .filter(|&before| count_mismatches(rows, before, mismatches, rows_differ) == mismatches)
+        .map(Mirror::Horizontal);
+    let vertical = (1..columns)
+        .filter(|&before| {
+            count_mismatches(columns, before, mismatches, columns_differ) == mismatches
+        })
+        .map(Mirror::Vertical);
+    horizontal.chain(vertical).collect()
+}
+
+/// The first Mirror of `grid` with exactly `mismatches` cells that differ, see `mirrors`
+pub fn mirror<T: PartialEq>(grid: &[Vec<T>], mismatches: usize) -> Option<Mirror> {
+    mirrors(grid, mismatches).into_iter().next()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    fn grid(rows: &str) -> Vec<Vec<char>> {
+        rows.lines().map(|row| row.chars().collect()).collect()
+    }
+
+    #[test]
+    fn test_mirrors() {
+        let columns =
+            grid("#.##..##.\n..#.##.#.\n##......#\n##......#\n..#.##.#.\n..##..##.\n#.#.##.#.");
+        test!(vec![Mirror::Vertical(5)], mirrors(&columns, 0));
+        test!(Some(Mirror::Horizontal(3)), mirror(&columns, 1));
+        let rows =
+            grid("#...##..#\n#....#..#\n..##..###\n#####.##.\n#####.##.\n..##..###\n#....#..#");
+        test!(vec![Mirror::Horizontal(4)], mirrors(&rows, 0));
+        test!(Some(Mirror::Horizontal(1)), mirror(&rows, 1));
+        // Every line between equal rows and columns mirrors them
+        test!(
+            vec![
+                Mirror::Horizontal(1),
+                Mirror::Vertical(1),
+                Mirror::Vertical(2)
+            ],
+            mirrors(&grid("aaa\naaa"), 0)
+        );
+        test!(mirror(&grid("ab\ncd"), 0).is_none());
+        test!(mirror(&Vec::<Vec<char>>::new(), 0).is_none());
+    }
+}
@@ -0,0 +1,14 @@
+// This module contains helpers that the tests of several modules share
+
+use crate::Int;
+
+/// Deterministic pseudo-random coordinates in 0..1000, of the Lehmer generator with multiplier 48271
+pub fn random_coordinates(count: usize) -> Vec<Int> {
+    let mut state: Int = 1;
+    (0..count)
+        .map(|_| {
+            state = state * 48271 % 2_147_483_647;
+            state % 1000
+        })
+        .collect()
+}
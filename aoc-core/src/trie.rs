@@ -0,0 +1,202 @@
+// This module contains a byte Trie, for puzzles that match a lot of words against the same text,
+// like composing a design out of towel patterns, or searching for words
+
+use crate::Int;
+
+const ROOT: usize = 0;
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// Children by byte, sorted by byte
+    children: Vec<(u8, usize)>,
+    is_word: bool,
+}
+
+/// Set of words stored by their common prefixes, strings are stored as their UTF-8 bytes
+#[derive(Debug, Clone)]
+pub struct Trie {
+    nodes: Vec<Node>,
+    len: usize,
+}
+
+impl Trie {
+    pub fn new() -> Trie {
+        Trie {
+            nodes: vec![Node::default()],
+            len: 0,
+        }
+    }
+
+    /// Number of words
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn child(&self, node: usize, byte: u8) -> Option<usize> {
+        let children = &self.nodes[node].children;
+        children
+            .binary_search_by_key(&byte, |&(byte, _)| byte)
+            .ok()
+            .map(|i| children[i].1)
+    }
+
+    /// Node at the end of `bytes`, if `bytes` is a prefix of any word
+    fn find(&self, bytes: &[u8]) -> Option<usize> {
+        bytes
+            .iter()
+            .try_fold(ROOT, |node, &byte| self.child(node, byte))
+    }
+
+    /// Insert `word`, returns true iff it was not in the Trie yet
+    pub fn insert(&mut self, word: impl AsRef<[u8]>) -> bool {
+        let mut node = ROOT;
+        for &byte in word.as_ref() {
+            node = match self.nodes[node]
+                .children
+                .binary_search_by_key(&byte, |&(byte, _)| byte)
+            {
+                Ok(i) => self.nodes[node].children[i].1,
+                Err(i) => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[node].children.insert(i, (byte, child));
+                    child
+                }
+            };
+        }
+        let is_new = !self.nodes[node].is_word;
+        self.nodes[node].is_word = true;
+        self.len += is_new as usize;
+        is_new
+    }
+
+    pub fn contains(&self, word: impl AsRef<[u8]>) -> bool {
+        self.find(word.as_ref())
+            .is_some_and(|node| self.nodes[node].is_word)
+    }
+
+    /// Returns true iff any word starts with `prefix`
+    pub fn starts_with(&self, prefix: impl AsRef<[u8]>) -> bool {
+        self.find(prefix.as_ref()).is_some()
+    }
+
+    /// All words that start with `prefix`, in lexicographic byte order
+    pub fn words_with_prefix(&self, prefix: impl AsRef<[u8]>) -> Vec<String> {
+        let prefix = prefix.as_ref();
+        let mut words = Vec::new();
+        if let Some(node) = self.find(prefix) {
+            let mut word = prefix.to_vec();
+            self.collect_words(node, &mut word, &mut words);
+        }
+        words
+    }
+
+    fn collect_words(&self, node: usize, word: &mut Vec<u8>, words: &mut Vec<String>) {
+        if self.nodes[node].is_word {
+            words.push(String::from_utf8_lossy(word).into_owned());
+        }
+        for &(byte, child) in &self.nodes[node].children {
+            word.push(byte);
+            self.collect_words(child, word, words);
+            word.pop();
+        }
+    }
+
+    /// Lengths of all words that are a prefix of `text`, in ascending order
+    pub fn prefix_matches<'a>(&'a self, text: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+        let mut node = Some(ROOT);
+        let root_is_word = self.nodes[ROOT].is_word;
+        let empty_match = root_is_word.then_some(0);
+        empty_match.into_iter().chain(
+            text.iter()
+                .enumerate()
+                .map_while(move |(i, &byte)| {
+                    node = self.child(node?, byte);
+                    node.map(|node| (i + 1, self.nodes[node].is_word))
+                })
+                .filter_map(|(length, is_word)| is_word.then_some(length)),
+        )
+    }
+
+    /// Length of the longest word that is a prefix of `text`
+    pub fn longest_match(&self, text: impl AsRef<[u8]>) -> Option<usize> {
+        self.prefix_matches(text.as_ref()).last()
+    }
+
+    /// Number of ways to compose `text` by concatenating words, which may be used more than once.
+    ///
+    /// Memoized on the start of the remaining text, so O(n * longest word).
+    pub fn count_compositions(&self, text: impl AsRef<[u8]>) -> Int {
+        let text = text.as_ref();
+        // ways[i] is the number of ways to compose text[i..]
+        let mut ways: Vec<Int> = vec![0; text.len() + 1];
+        ways[text.len()] = 1;
+        for start in (0..text.len()).rev() {
+            ways[start] = self
+                .prefix_matches(&text[start..])
+                .filter(|&length| length > 0)
+                .map(|length| ways[start + length])
+                .sum();
+        }
+        ways[0]
+    }
+
+    /// Returns true iff `text` can be composed by concatenating words
+    pub fn can_compose(&self, text: impl AsRef<[u8]>) -> bool {
+        self.count_compositions(text) > 0
+    }
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Trie::new()
+    }
+}
+
+impl<W: AsRef<[u8]>> FromIterator<W> for Trie {
+    fn from_iter<I: IntoIterator<Item = W>>(iter: I) -> Self {
+        let mut trie = Trie::new();
+        for word in iter {
+            trie.insert(word);
+        }
+        trie
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_trie() {
+        let mut trie: Trie = ["car", "cart", "cat", "dog"].into_iter().collect();
+        test!(4, trie.len());
+        test!(!trie.insert("cat"));
+        test!(trie.contains("cart"));
+        test!(!trie.contains("ca"));
+        test!(trie.starts_with("ca"));
+        test!(!trie.starts_with("cow"));
+        test!(vec!["car", "cart", "cat"], trie.words_with_prefix("ca"));
+        test!(Some(4), trie.longest_match("cartwheel"));
+        test!(Some(3), trie.longest_match("cars"));
+        test!(trie.longest_match("cow").is_none());
+    }
+
+    #[test]
+    fn test_count_compositions() {
+        let towels: Trie = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]
+            .into_iter()
+            .collect();
+        test!(2, towels.count_compositions("brwrr"));
+        test!(1, towels.count_compositions("bggr"));
+        test!(4, towels.count_compositions("gbbr"));
+        test!(6, towels.count_compositions("rrbgbr"));
+        test!(0, towels.count_compositions("ubwu"));
+        test!(!towels.can_compose("bbrgwb"));
+    }
+}
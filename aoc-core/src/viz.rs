@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+// This module writes visualizations of the data structures of solutions, like Graphviz DOT files, when `--viz` is
+// given, so e.g. a graph can be looked at as a picture instead of as a list of edges in debug output
+
+use crate::output::OutputDirectory;
+use crate::solution::Params;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory that visualizations are written to, nothing is written if it is not set
+static DIRECTORY: OutputDirectory = OutputDirectory::new();
+
+/// Write visualizations to `directory`, can only be set once
+pub fn init(directory: impl AsRef<Path>) {
+    DIRECTORY.init(directory);
+}
+
+/// Write the DOT file `<directory>/<name>.dot`, with an `-example` suffix for examples, using `write`.
+///
+/// `write` is only called if visualizations are written, so building the graph costs nothing otherwise.
+pub fn write_dot(params: &Params, name: &str, write: impl FnOnce(&mut String) -> std::fmt::Result) {
+    let Some(path) = DIRECTORY.path(params, name) else {
+        return;
+    };
+    let mut path = path.into_os_string();
+    path.push(".dot");
+    let path = PathBuf::from(path);
+    let mut dot = String::new();
+    write(&mut dot).expect("Writing to a String does not fail.");
+    dot.push('\n');
+    if let Err(error) =
+        fs::create_dir_all(path.parent().unwrap()).and_then(|_| fs::write(&path, dot))
+    {
+        tracing::warn!("Could not write '{}': {}.", path.display(), error);
+    }
+}
@@ -0,0 +1,163 @@
+// This module views a Map as a torus, of which every edge wraps around to the opposite edge, like the valley of the
+// blizzards, or as an infinite tiling of copies of the Map, like the garden of 2023-21, in which a Point also tells
+// which copy it is in
+
+use crate::grid::{Direction, Map, Point};
+use crate::Int;
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::North,
+    Direction::East,
+    Direction::South,
+    Direction::West,
+];
+
+/// A view of a Map of which every Point is within the Map, by wrapping it around the edges, see `Map::wrapping`
+#[derive(Clone, Copy)]
+pub struct Wrapping<'a, T> {
+    map: &'a Map<T>,
+}
+
+impl<'a, T> Wrapping<'a, T> {
+    /// The Point within the Map that `point` wraps around to
+    pub fn wrap(&self, point: &Point) -> Point {
+        Point::new(
+            point.x.rem_euclid(self.map.get_columns() as Int),
+            point.y.rem_euclid(self.map.get_rows() as Int),
+        )
+    }
+
+    pub fn point_get(&self, point: &Point) -> &'a T {
+        let point = self.wrap(point);
+        &self.map.grid[point.y as usize][point.x as usize]
+    }
+
+    /// The Point next to `point` in `direction`, wrapped around the edges
+    pub fn move_to(&self, point: &Point, direction: &Direction) -> Point {
+        self.wrap(&point.move_to(direction))
+    }
+
+    /// The 4 Points that are adjacent to `point`, wrapped around the edges, so some are equal on a Map that is only
+    /// one or two cells wide or high
+    pub fn get_adjacent(&self, point: &Point) -> Vec<Point> {
+        DIRECTIONS.iter().map(|d| self.move_to(point, d)).collect()
+    }
+}
+
+/// A view of a Map that is repeated infinitely in every direction, see `Map::tiled`. A Point in it is in the copy of
+/// the Map at its `tile`, of which (0, 0) is the Map itself, and at its `local` Point within that copy.
+#[derive(Clone, Copy)]
+pub struct Tiled<'a, T> {
+    map: &'a Map<T>,
+}
+
+impl<'a, T> Tiled<'a, T> {
+    /// The copy of the Map that `point` is in, e.g. (-1, 0) for the copy left of the Map
+    pub fn tile(&self, point: &Point) -> Point {
+        Point::new(
+            point.x.div_euclid(self.map.get_columns() as Int),
+            point.y.div_euclid(self.map.get_rows() as Int),
+        )
+    }
+
+    /// The Point within its copy of the Map that `point` is at
+    pub fn local(&self, point: &Point) -> Point {
+        self.wrapping().wrap(point)
+    }
+
+    /// The Point at `local` in the copy of the Map at `tile`
+    pub fn join(&self, tile: &Point, local: &Point) -> Point {
+        Point::new(
+            tile.x * self.map.get_columns() as Int + local.x,
+            tile.y * self.map.get_rows() as Int + local.y,
+        )
+    }
+
+    pub fn point_get(&self, point: &Point) -> &'a T {
+        self.wrapping().point_get(point)
+    }
+
+    /// The Map wrapping around, which has cells like every tiled Map
+    fn wrapping(&self) -> Wrapping<'a, T> {
+        Wrapping { map: self.map }
+    }
+
+    /// The 4 Points that are adjacent to `point`, which are never outside the tiling
+    pub fn get_adjacent(&self, point: &Point) -> Vec<Point> {
+        DIRECTIONS.iter().map(|d| point.move_to(d)).collect()
+    }
+}
+
+impl<T> Map<T> {
+    /// A view of this Map of which every edge wraps around to the opposite edge, None if the Map has no cells, which
+    /// nothing can wrap around to
+    pub fn wrapping(&self) -> Option<Wrapping<'_, T>> {
+        self.has_cells().then_some(Wrapping { map: self })
+    }
+
+    /// A view of this Map repeated infinitely in every direction, None if the Map has no cells
+    pub fn tiled(&self) -> Option<Tiled<'_, T>> {
+        self.has_cells().then_some(Tiled { map: self })
+    }
+
+    fn has_cells(&self) -> bool {
+        self.grid.first().is_some_and(|row| !row.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{East, North, West};
+    use crate::test;
+
+    fn map(rows: &str) -> Map<char> {
+        rows.lines().map(|row| row.chars().collect()).collect()
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let map = map("abc\ndef");
+        let wrapping = map.wrapping().unwrap();
+        test!(Point::new(0, 1), wrapping.move_to(&Point::new(2, 1), &East));
+        test!(
+            Point::new(1, 1),
+            wrapping.move_to(&Point::new(1, 0), &North)
+        );
+        test!('f', *wrapping.point_get(&Point::new(-1, -1)));
+        test!(Point::new(2, 0), wrapping.wrap(&Point::new(-4, 6)));
+        // North and South both wrap around to the other row
+        test!(
+            vec![
+                Point::new(0, 1),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(2, 0)
+            ],
+            wrapping.get_adjacent(&Point::new(0, 0))
+        );
+    }
+
+    #[test]
+    fn test_tiled() {
+        let map = map("abc\ndef");
+        let tiled = map.tiled().unwrap();
+        let point = Point::new(0, 0).move_to(&West);
+        test!(Point::new(-1, 0), tiled.tile(&point));
+        test!(Point::new(2, 0), tiled.local(&point));
+        test!('c', *tiled.point_get(&point));
+        test!(
+            Point::new(7, -1),
+            tiled.join(&Point::new(2, -1), &Point::new(1, 1))
+        );
+        test!(Point::new(2, -1), tiled.tile(&Point::new(7, -1)));
+        test!(Point::new(1, 1), tiled.local(&Point::new(7, -1)));
+    }
+
+    #[test]
+    fn test_empty() {
+        let empty: Map<char> = map("");
+        test!(empty.wrapping().is_none());
+        test!(empty.tiled().is_none());
+    }
+}
@@ -0,0 +1,171 @@
+#![allow(dead_code)]
+// This module writes the results of a run as shields.io endpoint badges, so the README can show the collected stars
+// and the runtime of the solutions without editing it by hand, see https://shields.io/badges/endpoint-badge
+
+use aoc::error::{AocError, AocResult};
+use aoc::solution::*;
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+/// Directory that `--badges` writes the badges to
+pub const BADGES_PATH: &str = "badges";
+
+/// Badges of which the color depends on a duration, with the upper bound of that color
+const RUNTIME_COLORS: [(Duration, &str); 3] = [
+    (Duration::from_secs(1), "brightgreen"),
+    (Duration::from_secs(10), "yellow"),
+    (Duration::MAX, "red"),
+];
+
+/// A shields.io endpoint badge
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Badge {
+    schema_version: u8,
+    label: String,
+    message: String,
+    color: String,
+}
+
+impl Badge {
+    fn new(label: &str, message: String, color: &str) -> Badge {
+        Badge {
+            schema_version: 1,
+            label: label.to_string(),
+            message,
+            color: color.to_string(),
+        }
+    }
+
+    fn runtime(label: &str, message: String, duration: Duration) -> Badge {
+        let color = RUNTIME_COLORS
+            .iter()
+            .find(|(bound, _)| duration < *bound)
+            .map_or("red", |&(_, color)| color);
+        Badge::new(label, message, color)
+    }
+}
+
+/// Number of stars collected in a run, a star for every part that gave the expected answer, of which `expected`
+/// returns the expected answer of a part. A cached part only counts if its answer is the expected one.
+fn stars(
+    test_results: &[TestResult],
+    expected: impl Fn(Year, Day, Part) -> Option<Answer>,
+) -> usize {
+    test_results
+        .iter()
+        .flat_map(|test_result| [Part::One, Part::Two].map(|part| (test_result, part)))
+        .filter(|&(test_result, part)| match test_result.part(part) {
+            TestStatus::Success(..) => true,
+            TestStatus::Cached(answer) => {
+                expected(test_result.year, test_result.day, part).as_ref() == Some(answer)
+            }
+            _ => false,
+        })
+        .count()
+}
+
+/// The badges of a run, by file name: the collected stars, the total runtime, and the fastest and slowest day.
+///
+/// Cached parts did not run, and do not count towards the runtime, see `stars` for `expected`.
+pub fn badges(
+    test_results: &[TestResult],
+    expected: impl Fn(Year, Day, Part) -> Option<Answer>,
+) -> Vec<(&'static str, Badge)> {
+    let stars = stars(test_results, expected);
+    let mut badges = vec![("stars", Badge::new("stars", stars.to_string(), "yellow"))];
+    let totals: Vec<(&TestResult, Duration)> = test_results
+        .iter()
+        .filter_map(|test_result| Some((test_result, test_result.total()?)))
+        .collect();
+    let runtime: Duration = totals.iter().map(|(_, total)| *total).sum();
+    badges.push((
+        "runtime",
+        Badge::runtime("runtime", format!("{:.2?}", runtime), runtime),
+    ));
+    let day = |label: &str, (test_result, total): (&TestResult, Duration)| {
+        let message = format!(
+            "{}-{:02} in {:.2?}",
+            test_result.year, test_result.day, total
+        );
+        Badge::runtime(label, message, total)
+    };
+    if let Some(&fastest) = totals.iter().min_by_key(|(_, total)| *total) {
+        badges.push(("fastest", day("fastest day", fastest)));
+    }
+    if let Some(&slowest) = totals.iter().max_by_key(|(_, total)| *total) {
+        badges.push(("slowest", day("slowest day", slowest)));
+    }
+    badges
+}
+
+/// Write the badges of a run to `<directory>/<name>.json`, see `stars` for `expected`
+pub fn write(
+    test_results: &[TestResult],
+    expected: impl Fn(Year, Day, Part) -> Option<Answer>,
+    directory: impl AsRef<Path>,
+) -> AocResult<()> {
+    let directory = directory.as_ref();
+    fs::create_dir_all(directory).map_err(AocError::io(directory))?;
+    for (name, badge) in badges(test_results, expected) {
+        let path = directory.join(format!("{}.json", name));
+        let json = serde_json::to_string_pretty(&badge).expect("A Badge is always serializable.");
+        fs::write(&path, json).map_err(AocError::io(&path))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_badges() {
+        let test_result = |day: Day, p1: TestStatus, p2: TestStatus| TestResult {
+            year: 2023,
+            day,
+            parse: None,
+            p1,
+            p2,
+            examples: TestStatus::Unknown,
+            p1_memory: None,
+            p2_memory: None,
+        };
+        let test_results = [
+            test_result(
+                1,
+                TestStatus::Success(Duration::from_millis(2), 1.into()),
+                TestStatus::Cached(1.into()),
+            ),
+            test_result(
+                2,
+                TestStatus::Unverified(Duration::from_secs(3), 1.into()),
+                TestStatus::Failed(Duration::from_secs(9), 1.into()),
+            ),
+            test_result(
+                3,
+                TestStatus::Cached(1.into()),
+                TestStatus::Cached(2.into()),
+            ),
+        ];
+        // Only the cached parts of day 1 and part one of day 3 have the expected answer
+        let expected = |_: Year, day: Day, part: Part| match (day, part) {
+            (2, _) | (3, Part::Two) => None,
+            _ => Some(1.into()),
+        };
+        test!(3, stars(&test_results, expected));
+        let badges = badges(&test_results[..2], expected);
+        let names: Vec<&str> = badges.iter().map(|(name, _)| *name).collect();
+        test!(vec!["stars", "runtime", "fastest", "slowest"], names);
+        test!(Badge::new("stars", "2".to_string(), "yellow"), badges[0].1);
+        test!("red", badges[1].1.color);
+        test!("2023-01 in 2.00ms", badges[2].1.message);
+        test!("2023-02 in 12.00s", badges[3].1.message);
+        test!(
+            "{\"schemaVersion\":1,\"label\":\"stars\",\"message\":\"2\",\"color\":\"yellow\"}",
+            serde_json::to_string(&badges[0].1).unwrap()
+        );
+    }
+}
@@ -0,0 +1,436 @@
+#![allow(dead_code)]
+// This module benchmarks the parse step and both parts of solutions separately, so a slow day shows
+// whether it needs a better parser or a better algorithm
+
+use aoc::error::{AocError, AocResult};
+use aoc::memory;
+use aoc::runner::{ExitStatus, Part};
+use aoc::solution::*;
+use aoc::Inputs;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+
+/// Durations of repeated runs of the same phase
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Samples(Vec<Duration>);
+
+impl Samples {
+    /// Run `phase` `count` times, and time every run
+    fn measure<T>(count: usize, mut phase: impl FnMut() -> T) -> Samples {
+        Samples(
+            (0..count)
+                .map(|_| {
+                    let instant = Instant::now();
+                    std::hint::black_box(phase());
+                    instant.elapsed()
+                })
+                .collect(),
+        )
+    }
+
+    pub fn min(&self) -> Duration {
+        self.0.iter().copied().min().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        let mut sorted = self.0.clone();
+        sorted.sort();
+        match sorted.len() {
+            0 => Duration::ZERO,
+            len if len % 2 == 1 => sorted[len / 2],
+            len => (sorted[len / 2 - 1] + sorted[len / 2]) / 2,
+        }
+    }
+}
+
+/// Benchmark of the phases of a Solution
+#[derive(Debug, Clone)]
+pub struct Bench {
+    pub year: Year,
+    pub day: Day,
+    pub parse: Samples,
+    pub part_one: Samples,
+    pub part_two: Samples,
+    /// Peak heap memory of part one in bytes, if memory is tracked
+    pub p1_memory: Option<usize>,
+    /// Peak heap memory of part two in bytes, if memory is tracked
+    pub p2_memory: Option<usize>,
+    /// Whether the Solution has a parse step, instead of parsing the input in its parts
+    pub has_parse_step: bool,
+}
+
+impl Bench {
+    /// Share of the parse step in the median duration of parsing and solving both parts
+    pub fn parse_share(&self) -> f64 {
+        let parse = self.parse.median().as_secs_f64();
+        let total =
+            parse + self.part_one.median().as_secs_f64() + self.part_two.median().as_secs_f64();
+        if total > 0.0 {
+            parse / total
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Benchmark `solution` on `input`, by parsing it `samples` times and solving both parts `samples` times from
+/// a single parse.
+///
+/// A Solution without a parse step parses in its parts, which is then included in their durations. Fails if the
+/// input can not be parsed or solved. The memory of both parts is measured in a run before the samples.
+pub fn bench(solution: &SolutionBox, input: &str, samples: usize) -> AocResult<Bench> {
+    let parsed = solution.parse(input, &Params::INPUT)?;
+    let memory = |part: Part| {
+        let (answer, memory) =
+            memory::measure(|| solution.solve_parsed(&parsed, part, &Params::INPUT));
+        answer.map(|_| memory)
+    };
+    let (p1_memory, p2_memory) = (memory(Part::One)?, memory(Part::Two)?);
+    let parse = Samples::measure(samples, || solution.parse(input, &Params::INPUT));
+    let solve = |part: Part| {
+        Samples::measure(samples, || {
+            solution.solve_parsed(&parsed, part, &Params::INPUT)
+        })
+    };
+    let (part_one, part_two) = (solve(Part::One), solve(Part::Two));
+    Ok(Bench {
+        year: solution.year(),
+        day: solution.day(),
+        parse,
+        part_one,
+        part_two,
+        p1_memory,
+        p2_memory,
+        has_parse_step: solution.has_parse_step(),
+    })
+}
+
+/// Export of the latest benchmark, which `bench accept` turns into the baseline
+pub const LATEST_PATH: &str = ".aoc/bench/latest.json";
+
+/// Export that `compare` compares the latest benchmark with by default
+pub const BASELINE_PATH: &str = ".aoc/bench/baseline.json";
+
+/// Name of the JSON format of `bench --export`, this project's own, which is versioned by `EXPORT_VERSION`
+pub const EXPORT_FORMAT: &str = "advent-of-code-bench";
+pub const EXPORT_VERSION: u32 = 1;
+
+/// The machine that a benchmark ran on
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Machine {
+    pub os: String,
+    pub arch: String,
+    pub cpu: Option<String>,
+    pub threads: usize,
+}
+
+impl Machine {
+    fn current() -> Machine {
+        // Only known on Linux, from the first processor in /proc/cpuinfo
+        let cpu = fs::read_to_string("/proc/cpuinfo")
+            .ok()
+            .and_then(|cpuinfo| {
+                cpuinfo
+                    .lines()
+                    .find_map(|line| line.strip_prefix("model name")?.split_once(':'))
+                    .map(|(_, model)| model.trim().to_string())
+            });
+        Machine {
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            cpu,
+            threads: std::thread::available_parallelism().map_or(1, usize::from),
+        }
+    }
+}
+
+impl Display for Machine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.os, self.arch)?;
+        if let Some(cpu) = &self.cpu {
+            write!(f, ", {}", cpu)?;
+        }
+        match self.threads {
+            1 => write!(f, ", 1 thread"),
+            threads => write!(f, ", {} threads", threads),
+        }
+    }
+}
+
+/// Timing of a single part in an Export, which includes the parse step, so it is comparable with implementations
+/// that parse in their parts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedPart {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    /// Median duration of the parse step plus the median duration of the part
+    pub nanoseconds: u128,
+    /// Median duration of the parse step alone
+    pub parse_nanoseconds: u128,
+    pub samples: usize,
+    /// Peak heap memory of the part in bytes, if it was measured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_bytes: Option<usize>,
+}
+
+/// Timings of a benchmark in the export format of this project, which implementations in other languages can write
+/// too, to be compared with these
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Export {
+    pub format: String,
+    pub version: u32,
+    pub language: String,
+    /// `release` or `debug`
+    pub profile: String,
+    pub machine: Machine,
+    pub parts: Vec<ExportedPart>,
+}
+
+impl Export {
+    pub fn new(benches: &[Bench]) -> Export {
+        let parts = benches
+            .iter()
+            .flat_map(|bench| {
+                let parse = bench.parse.median().as_nanos();
+                [
+                    (1, &bench.part_one, bench.p1_memory),
+                    (2, &bench.part_two, bench.p2_memory),
+                ]
+                .map(|(part, samples, memory_bytes)| ExportedPart {
+                    year: bench.year,
+                    day: bench.day,
+                    part,
+                    nanoseconds: parse + samples.median().as_nanos(),
+                    parse_nanoseconds: parse,
+                    samples: samples.0.len(),
+                    memory_bytes,
+                })
+            })
+            .collect();
+        Export {
+            format: EXPORT_FORMAT.to_string(),
+            version: EXPORT_VERSION,
+            language: "Rust".to_string(),
+            profile: match cfg!(debug_assertions) {
+                true => "debug".to_string(),
+                false => "release".to_string(),
+            },
+            machine: Machine::current(),
+            parts,
+        }
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> AocResult<()> {
+        let path = path.as_ref();
+        if let Some(directory) = path
+            .parent()
+            .filter(|directory| !directory.as_os_str().is_empty())
+        {
+            fs::create_dir_all(directory).map_err(AocError::io(directory))?;
+        }
+        let json = serde_json::to_string_pretty(self).expect("An Export is always serializable.");
+        fs::write(path, json).map_err(AocError::io(path))
+    }
+
+    /// Load an Export, which may have been written by another implementation
+    pub fn load(path: impl AsRef<Path>) -> AocResult<Export> {
+        let path = path.as_ref();
+        let what = || path.display().to_string();
+        let json = fs::read_to_string(path).map_err(AocError::io(path))?;
+        let export: Export = serde_json::from_str(&json).map_err(AocError::parse(what()))?;
+        match export.format == EXPORT_FORMAT && export.version <= EXPORT_VERSION {
+            true => Ok(export),
+            false => Err(AocError::parse(what())(format!(
+                "unsupported format '{}' version {}",
+                export.format, export.version
+            ))),
+        }
+    }
+
+    /// Duration of every day, the parse step counts once, although it is included in the duration of both parts
+    pub fn days(&self) -> BTreeMap<(Year, Day), Duration> {
+        let mut days: BTreeMap<(Year, Day), (u128, u128)> = BTreeMap::new();
+        for part in &self.parts {
+            let (parts, parse) = days.entry((part.year, part.day)).or_default();
+            *parts += part.nanoseconds.saturating_sub(part.parse_nanoseconds);
+            *parse = part.parse_nanoseconds.max(*parse);
+        }
+        days.into_iter()
+            .map(|(day, (parts, parse))| {
+                let nanoseconds = u64::try_from(parts + parse).unwrap_or(u64::MAX);
+                (day, Duration::from_nanos(nanoseconds))
+            })
+            .collect()
+    }
+}
+
+/// Benchmark the selected solutions and print the median and minimum duration of every phase,
+/// and write them to `latest`, like LATEST_PATH, and to `export` if given, in the export format
+pub fn run(
+    solutions: &[SolutionBox],
+    inputs: &Inputs,
+    samples: usize,
+    latest: &Path,
+    export: Option<&Path>,
+) -> ExitStatus {
+    println!(
+        "{:<8} {:>21} {:>21} {:>21} {:>8}",
+        "Day", "Parse (median/min)", "P1 (median/min)", "P2 (median/min)", "Parse%"
+    );
+    let phase = |samples: &Samples| format!("{:.2?}/{:.2?}", samples.median(), samples.min());
+    let mut benches = Vec::new();
+    for solution in solutions {
+        let input = match inputs.get(solution.year(), solution.day()) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitStatus::Error;
+            }
+        };
+        let bench = match bench(solution, &input, samples) {
+            Ok(bench) => bench,
+            Err(error) => {
+                eprintln!("{}", error);
+                return ExitStatus::Error;
+            }
+        };
+        let parse_share = match bench.has_parse_step {
+            true => format!("{:.1}%", bench.parse_share() * 100.0),
+            false => "in parts".to_string(),
+        };
+        println!(
+            "{}-{:02}  {:>21} {:>21} {:>21} {:>8}",
+            bench.year,
+            bench.day,
+            phase(&bench.parse),
+            phase(&bench.part_one),
+            phase(&bench.part_two),
+            parse_share
+        );
+        benches.push(bench);
+    }
+    let export_to = |path: &Path| Export::new(&benches).save(path);
+    if let Err(error) = export_to(latest).and(export.map_or(Ok(()), export_to)) {
+        eprintln!("{}", error);
+        return ExitStatus::Error;
+    }
+    ExitStatus::Success
+}
+
+/// Replace the export at `baseline` with the export at `latest`, after its timings have been reviewed
+pub fn accept(latest: &Path, baseline: &Path) -> ExitStatus {
+    match Export::load(latest).and_then(|export| export.save(baseline).map(|_| export)) {
+        Ok(export) => {
+            println!(
+                "Accepted the {} parts of '{}' ({}) as the baseline '{}'.",
+                export.parts.len(),
+                latest.display(),
+                export.machine,
+                baseline.display()
+            );
+            ExitStatus::Success
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitStatus::Error
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_samples() {
+        let millis =
+            |millis: &[u64]| Samples(millis.iter().map(|&m| Duration::from_millis(m)).collect());
+        test!(Duration::from_millis(2), millis(&[3, 1, 2]).median());
+        test!(Duration::from_millis(1), millis(&[3, 1, 2]).min());
+        test!(
+            Duration::from_millis(25),
+            millis(&[40, 10, 20, 30]).median()
+        );
+        test!(Duration::ZERO, millis(&[]).median());
+        test!(5, Samples::measure(5, || 1).0.len());
+    }
+
+    #[test]
+    fn test_has_parse_step() {
+        let has_parse_step = |solution: SolutionBox| {
+            let input = solution.define_examples()[0].get_input();
+            bench(&solution, &input, 1).unwrap().has_parse_step
+        };
+        test!(has_parse_step(crate::y2023::d14::Problem::create_box()));
+        test!(!has_parse_step(crate::y2023::d13::Problem::create_box()));
+    }
+
+    #[test]
+    fn test_parse_share() {
+        let millis = |m: u64| Samples(vec![Duration::from_millis(m)]);
+        let bench = Bench {
+            year: 2023,
+            day: 1,
+            parse: millis(1),
+            part_one: millis(1),
+            part_two: millis(2),
+            p1_memory: None,
+            p2_memory: None,
+            has_parse_step: true,
+        };
+        test!(0.25, bench.parse_share());
+    }
+
+    #[test]
+    fn test_export() {
+        let millis = |m: u64| Samples(vec![Duration::from_millis(m); 3]);
+        let bench = Bench {
+            year: 2023,
+            day: 1,
+            parse: millis(1),
+            part_one: millis(2),
+            part_two: millis(3),
+            p1_memory: Some(1024),
+            p2_memory: None,
+            has_parse_step: true,
+        };
+        let export = Export::new(&[bench]);
+        test!(EXPORT_FORMAT, export.format);
+        test!(
+            vec![3_000_000, 4_000_000],
+            export
+                .parts
+                .iter()
+                .map(|p| p.nanoseconds)
+                .collect::<Vec<_>>()
+        );
+        test!(1_000_000, export.parts[1].parse_nanoseconds);
+        test!(3, export.parts[1].samples);
+        test!(Some(1024), export.parts[0].memory_bytes);
+        test!(export.parts[1].memory_bytes.is_none());
+        test!(Duration::from_millis(6), export.days()[&(2023, 1)]);
+        let json = serde_json::to_string(&export).unwrap();
+        test!(export, serde_json::from_str::<Export>(&json).unwrap());
+        test!(json.contains("\"memory_bytes\":1024"));
+    }
+
+    #[test]
+    fn test_accept() {
+        let directory = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+        let (latest, baseline) = (
+            directory.join("latest.json"),
+            directory.join("b/baseline.json"),
+        );
+        test!(ExitStatus::Error, accept(&latest, &baseline));
+        let export = Export::new(&[]);
+        export.save(&latest).unwrap();
+        test!(ExitStatus::Success, accept(&latest, &baseline));
+        test!(export, Export::load(&baseline).unwrap());
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
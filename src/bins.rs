@@ -0,0 +1,77 @@
+#![allow(dead_code)]
+// This module generates a small binary per day in `src/bin`, for people who prefer `cargo run --bin y2023d14` over
+// the selection flags of the main runner, which stays the canonical way to run the solutions
+
+use aoc::runner::ExitStatus;
+use aoc::solution::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory in which Cargo discovers the extra binaries of the package
+pub const BIN_DIRECTORY: &str = "src/bin";
+
+/// Name of the binary of a day, like `y2023d14`
+pub fn name(year: Year, day: Day) -> String {
+    format!("y{}d{:02}", year, day)
+}
+
+/// Source of the binary of a day, which includes the module of the day and runs it with `aoc::runner::main`
+pub fn shim(year: Year, day: Day) -> String {
+    let module = format!("d{:02}", day);
+    format!(
+        "// Generated by `cargo run -- bins`, runs {year}-{day:02} with `cargo run --bin {name}`\n\
+         \n\
+         #[path = \"../y{year}/{module}.rs\"]\n\
+         mod {module};\n\
+         \n\
+         use aoc::solution::*;\n\
+         \n\
+         fn main() -> std::process::ExitCode {{\n    \
+             aoc::runner::main({module}::Problem::create_box())\n\
+         }}\n",
+        name = name(year, day),
+    )
+}
+
+/// Write the binary of every solution into `directory`, binaries that already exist are only replaced if `force`
+pub fn generate(solutions: &[SolutionBox], directory: &Path, force: bool) -> ExitStatus {
+    if let Err(error) = fs::create_dir_all(directory) {
+        eprintln!("Failed to create '{}'. {}", directory.display(), error);
+        return ExitStatus::Error;
+    }
+    let (mut generated, mut existing) = (0, 0);
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let path: PathBuf = directory.join(format!("{}.rs", name(year, day)));
+        if !force && path.exists() {
+            existing += 1;
+            continue;
+        }
+        if let Err(error) = fs::write(&path, shim(year, day)) {
+            eprintln!("Failed to write '{}'. {}", path.display(), error);
+            return ExitStatus::Error;
+        }
+        generated += 1;
+    }
+    println!(
+        "Generated {} binaries in '{}', {} already existed.",
+        generated,
+        directory.display(),
+        existing
+    );
+    ExitStatus::Success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_shim() {
+        test!("y2023d05", name(2023, 5));
+        let shim = shim(2023, 5);
+        test!(shim.contains("#[path = \"../y2023/d05.rs\"]\nmod d05;\n"));
+        test!(shim.contains("aoc::runner::main(d05::Problem::create_box())"));
+    }
+}
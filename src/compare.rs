@@ -0,0 +1,209 @@
+#![allow(dead_code)]
+// This module compares two benchmark exports of the same machine, e.g. of different branches, day by day, so the
+// effect of performance work can be reviewed concretely instead of eyeballing two runs
+
+use crate::bench::Export;
+use aoc::runner::ExitStatus;
+use aoc::solution::*;
+use colored::Colorize;
+use std::path::Path;
+
+/// Change in percent above which a day counts as a regression or an improvement, smaller changes are noise
+pub const DEFAULT_THRESHOLD: f64 = 5.0;
+
+/// How the duration of a day changed from the baseline to the candidate
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Regression,
+    Improvement,
+    Unchanged,
+    /// The day is missing from the baseline or the candidate
+    Missing,
+}
+
+/// Duration of a day in the baseline and in the candidate
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delta {
+    pub year: Year,
+    pub day: Day,
+    pub baseline: Option<Duration>,
+    pub candidate: Option<Duration>,
+}
+
+impl Delta {
+    /// Change of the duration in percent, positive if the candidate is slower
+    pub fn change(&self) -> Option<f64> {
+        let (baseline, candidate) = (self.baseline?.as_secs_f64(), self.candidate?.as_secs_f64());
+        (baseline > 0.0).then(|| (candidate / baseline - 1.0) * 100.0)
+    }
+
+    pub fn verdict(&self, threshold: f64) -> Verdict {
+        match self.change() {
+            None => Verdict::Missing,
+            Some(change) if change > threshold => Verdict::Regression,
+            Some(change) if change < -threshold => Verdict::Improvement,
+            Some(_) => Verdict::Unchanged,
+        }
+    }
+}
+
+/// Returns an error if `baseline` and `candidate` were benchmarked on different machines, of which the timings are not
+/// comparable, unless `force`
+pub fn check_machines(baseline: &Export, candidate: &Export, force: bool) -> Result<(), String> {
+    match force || baseline.machine == candidate.machine {
+        true => Ok(()),
+        false => Err(format!(
+            "The baseline was benchmarked on another machine ({}) than the candidate ({}), use --force to compare them anyway.",
+            baseline.machine, candidate.machine
+        )),
+    }
+}
+
+/// The Delta of every day in `baseline` or `candidate`, sorted by year and day
+pub fn deltas(baseline: &Export, candidate: &Export) -> Vec<Delta> {
+    let (baseline, candidate) = (baseline.days(), candidate.days());
+    let mut days: Vec<(Year, Day)> = baseline.keys().chain(candidate.keys()).copied().collect();
+    days.sort();
+    days.dedup();
+    days.into_iter()
+        .map(|(year, day)| Delta {
+            year,
+            day,
+            baseline: baseline.get(&(year, day)).copied(),
+            candidate: candidate.get(&(year, day)).copied(),
+        })
+        .collect()
+}
+
+/// Print a table of the Delta of every day in the exports at `baseline` and `candidate`, in which days that changed
+/// by more than `threshold` percent are highlighted. Fails if they are of different machines, unless `force`.
+pub fn compare(baseline: &Path, candidate: &Path, threshold: f64, force: bool) -> ExitStatus {
+    let exports = Export::load(baseline).and_then(|b| Ok((b, Export::load(candidate)?)));
+    let (baseline_export, candidate_export) = match exports {
+        Ok(exports) => exports,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitStatus::Error;
+        }
+    };
+    if let Err(error) = check_machines(&baseline_export, &candidate_export, force) {
+        eprintln!("{}", error);
+        return ExitStatus::Error;
+    }
+    for (name, path, export) in [
+        ("Baseline", baseline, &baseline_export),
+        ("Candidate", candidate, &candidate_export),
+    ] {
+        println!(
+            "{:<10} {} ({}, {}, {})",
+            format!("{}:", name),
+            path.display(),
+            export.language,
+            export.profile,
+            export.machine
+        );
+    }
+    println!(
+        "\n{:<8} {:>12} {:>12} {:>9}",
+        "Day", "Baseline", "Candidate", "Change"
+    );
+    let duration = |duration: Option<Duration>| {
+        duration.map_or("-".to_string(), |duration| format!("{:.2?}", duration))
+    };
+    let deltas = deltas(&baseline_export, &candidate_export);
+    for delta in &deltas {
+        let change = delta
+            .change()
+            .map_or("-".to_string(), |change| format!("{:+.1}%", change));
+        let change = match delta.verdict(threshold) {
+            Verdict::Regression => format!("{:>9}", change).red().bold(),
+            Verdict::Improvement => format!("{:>9}", change).green(),
+            Verdict::Unchanged | Verdict::Missing => format!("{:>9}", change).normal(),
+        };
+        println!(
+            "{}-{:02}  {:>12} {:>12} {}",
+            delta.year,
+            delta.day,
+            duration(delta.baseline),
+            duration(delta.candidate),
+            change
+        );
+    }
+    let count = |verdict: Verdict| {
+        deltas
+            .iter()
+            .filter(|delta| delta.verdict(threshold) == verdict)
+            .count()
+    };
+    println!(
+        "\n{} regressions and {} improvements of more than {}%, {} days are missing from one of the exports.",
+        count(Verdict::Regression),
+        count(Verdict::Improvement),
+        threshold,
+        count(Verdict::Missing)
+    );
+    ExitStatus::Success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::{ExportedPart, Machine, EXPORT_FORMAT, EXPORT_VERSION};
+    use crate::test;
+
+    fn export(parts: &[(Day, u8, u128)]) -> Export {
+        Export {
+            format: EXPORT_FORMAT.to_string(),
+            version: EXPORT_VERSION,
+            language: "Rust".to_string(),
+            profile: "release".to_string(),
+            machine: Machine {
+                os: "linux".to_string(),
+                arch: "x86_64".to_string(),
+                cpu: None,
+                threads: 8,
+            },
+            parts: parts
+                .iter()
+                .map(|&(day, part, nanoseconds)| ExportedPart {
+                    year: 2023,
+                    day,
+                    part,
+                    nanoseconds,
+                    parse_nanoseconds: 0,
+                    samples: 10,
+                    memory_bytes: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_deltas() {
+        let baseline = export(&[(1, 1, 100), (1, 2, 100), (2, 1, 1000), (3, 1, 50)]);
+        let candidate = export(&[(1, 1, 100), (1, 2, 130), (2, 1, 500), (4, 1, 10)]);
+        let deltas = deltas(&baseline, &candidate);
+        let verdicts: Vec<Verdict> = deltas.iter().map(|delta| delta.verdict(5.0)).collect();
+        test!(
+            vec![
+                Verdict::Regression,
+                Verdict::Improvement,
+                Verdict::Missing,
+                Verdict::Missing
+            ],
+            verdicts
+        );
+        test!(Some(15.0), deltas[0].change().map(f64::round));
+        test!(Verdict::Unchanged, deltas[0].verdict(20.0));
+        test!(deltas[3].baseline.is_none());
+    }
+
+    #[test]
+    fn test_check_machines() {
+        let (baseline, mut candidate) = (export(&[]), export(&[]));
+        test!(check_machines(&baseline, &candidate, false).is_ok());
+        candidate.machine.threads = 16;
+        test!(check_machines(&baseline, &candidate, false).is_err());
+        test!(check_machines(&baseline, &candidate, true).is_ok());
+    }
+}
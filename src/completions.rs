@@ -0,0 +1,67 @@
+// This module generates the shell completions of the CLI, in which the years and days that have a Solution are
+// completed as the values of `--years`, `--days` and `repl`, since the registered days are known when generating
+
+use aoc::solution::*;
+use clap::builder::PossibleValuesParser;
+use clap_complete::Shell;
+use std::collections::BTreeSet;
+use std::io::Write;
+
+/// The distinct `values` as the possible values of an argument, in order
+fn possible_values<T: Ord + ToString>(values: impl Iterator<Item = T>) -> PossibleValuesParser {
+    let values: BTreeSet<T> = values.collect();
+    PossibleValuesParser::new(values.iter().map(T::to_string).collect::<Vec<_>>())
+}
+
+/// `command` of which the years and days of `solutions` are the completed values of `--years`, `--days` and `repl`,
+/// which still accept any value when parsing, since `command` is only used to generate completions
+pub fn with_solutions(command: clap::Command, solutions: &[SolutionBox]) -> clap::Command {
+    let years = || possible_values(solutions.iter().map(|solution| solution.year()));
+    let days = || possible_values(solutions.iter().map(|solution| solution.day()));
+    command
+        .mut_arg("years", |arg| arg.value_parser(years()))
+        .mut_arg("days", |arg| arg.value_parser(days()))
+        .mut_subcommand("repl", |repl| {
+            repl.mut_arg("year", |arg| arg.value_parser(years()))
+                .mut_arg("day", |arg| arg.value_parser(days()))
+        })
+}
+
+/// Write the completions of `command` for `shell` to `out`
+pub fn generate(
+    shell: Shell,
+    command: clap::Command,
+    solutions: &[SolutionBox],
+    out: &mut dyn Write,
+) {
+    let mut command = with_solutions(command, solutions);
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, out);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use crate::Cli;
+    use clap::CommandFactory;
+
+    #[test]
+    #[cfg(feature = "y2023")]
+    fn test_generate() {
+        let solutions = crate::y2023::solutions();
+        let command = with_solutions(Cli::command(), &solutions);
+        let days = command
+            .get_arguments()
+            .find(|arg| arg.get_id() == "days")
+            .unwrap()
+            .get_possible_values();
+        test!(solutions.len(), days.len());
+        test!(days.iter().any(|day| day.get_name() == "14"));
+        let mut bash = Vec::new();
+        generate(Shell::Bash, Cli::command(), &solutions, &mut bash);
+        let bash = String::from_utf8(bash).unwrap();
+        test!(bash.contains("advent-of-code-2023"));
+        test!(bash.contains("completions"));
+    }
+}
@@ -0,0 +1,266 @@
+#![allow(dead_code)]
+// This module contains the layered configuration of a run, so personal defaults can be kept in `aoc.toml` instead of
+// being passed as flags every time. A command line flag overrides an `AOC_*` environment variable, which overrides
+// the config file, which overrides the built-in default
+
+use aoc::error::{AocError, AocResult};
+use aoc::runner::{self, Selection};
+use aoc::solution::*;
+use aoc::Inputs;
+use serde::{de::Error, Deserialize, Deserializer};
+use std::fs;
+use std::io::{self, IsTerminal};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Default path of the config file, relative to the working directory
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+/// Prefix of the environment variables that override the config file, e.g. `AOC_JOBS`
+const ENV_PREFIX: &str = "AOC_";
+
+/// When to color the output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Color {
+    /// Color the output if it is a terminal that understands ANSI escape codes, unless `NO_COLOR` is set
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(color: &str) -> Result<Self, Self::Err> {
+        <Color as clap::ValueEnum>::from_str(color, true)
+    }
+}
+
+impl Color {
+    /// Whether to color the output when `NO_COLOR` is (non-empty) `no_color`, the output goes to a `terminal`, and
+    /// that terminal understands ANSI escape codes
+    fn enabled(self, no_color: bool, terminal: bool, ansi: bool) -> bool {
+        match self {
+            Color::Auto => !no_color && terminal && ansi,
+            Color::Always => true,
+            Color::Never => false,
+        }
+    }
+
+    /// Make the `colored` crate follow this setting, and return whether the log messages on stderr are colored.
+    ///
+    /// All colored output goes through this check, so CI logs and the output panes of editors do not fill up with
+    /// escape codes. The `colored` crate has a single switch, but the TestResults go to stderr and the tables to
+    /// stdout, so both have to be a terminal.
+    pub fn apply(self) -> bool {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        let ansi = supports_ansi();
+        let (stdout, stderr) = (io::stdout().is_terminal(), io::stderr().is_terminal());
+        colored::control::set_override(self.enabled(no_color, stdout && stderr, ansi));
+        self.enabled(no_color, stderr, ansi)
+    }
+}
+
+/// Whether the terminal understands ANSI escape codes, a Windows console only does after enabling them
+fn supports_ansi() -> bool {
+    if std::env::var_os("TERM").is_some_and(|term| term == "dumb") {
+        return false;
+    }
+    #[cfg(windows)]
+    return colored::control::set_virtual_terminal(true).is_ok();
+    #[cfg(not(windows))]
+    true
+}
+
+/// The settings of one layer, a setting that it leaves out is taken from the layer below it
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct Layer {
+    /// Path of the adventofcode.com session cookie
+    pub session_file: Option<PathBuf>,
+    /// Directory of the cached puzzle inputs
+    pub cache_dir: Option<PathBuf>,
+    /// Years to run when `--years` is not given, e.g. `2023`
+    #[serde(deserialize_with = "years")]
+    pub years: Option<Selection<Year>>,
+    /// Number of threads to use
+    pub jobs: Option<usize>,
+    pub color: Option<Color>,
+    /// Abandon a part after this duration, e.g. `30s`
+    #[serde(deserialize_with = "timeout")]
+    pub timeout: Option<Duration>,
+}
+
+/// Deserialize a string or an integer with `parse`, so `years = 2023` and `years = "2022-2023"` both work
+fn parse_with<'de, D: Deserializer<'de>, T>(
+    deserializer: D,
+    parse: impl FnOnce(&str) -> Result<T, String>,
+) -> Result<Option<T>, D::Error> {
+    let value = match toml::Value::deserialize(deserializer)? {
+        toml::Value::String(value) => value,
+        toml::Value::Integer(value) => value.to_string(),
+        value => {
+            let message = format!("expected a string, found {}", value.type_str());
+            return Err(D::Error::custom(message));
+        }
+    };
+    parse(&value).map(Some).map_err(D::Error::custom)
+}
+
+fn years<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Selection<Year>>, D::Error> {
+    parse_with(deserializer, Selection::from_str)
+}
+
+fn timeout<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Duration>, D::Error> {
+    parse_with(deserializer, runner::parse_duration)
+}
+
+impl Layer {
+    /// The config file at `path`, an empty Layer if there is none
+    pub fn from_file(path: impl AsRef<Path>) -> AocResult<Layer> {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(toml) => toml::from_str(&toml).map_err(AocError::parse(path.display().to_string())),
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(Layer::default()),
+            Err(error) => Err(AocError::io(path)(error)),
+        }
+    }
+
+    /// The `AOC_*` environment variables, named after the keys of the config file, e.g. `AOC_SESSION_FILE`
+    pub fn from_env() -> AocResult<Layer> {
+        Layer::from_vars(|key| std::env::var(key).ok())
+    }
+
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> AocResult<Layer> {
+        fn parse<T>(
+            var: &impl Fn(&str) -> Option<String>,
+            key: &str,
+            parse: impl FnOnce(&str) -> Result<T, String>,
+        ) -> AocResult<Option<T>> {
+            let name = format!("{}{}", ENV_PREFIX, key);
+            var(&name)
+                .map(|value| parse(&value).map_err(AocError::parse(name)))
+                .transpose()
+        }
+        let number = |value: &str| value.trim().parse().map_err(|error| format!("{}", error));
+        Ok(Layer {
+            session_file: parse(&var, "SESSION_FILE", |value| Ok(value.into()))?,
+            cache_dir: parse(&var, "CACHE_DIR", |value| Ok(value.into()))?,
+            years: parse(&var, "YEARS", Selection::from_str)?,
+            jobs: parse(&var, "JOBS", number)?,
+            color: parse(&var, "COLOR", Color::from_str)?,
+            timeout: parse(&var, "TIMEOUT", runner::parse_duration)?,
+        })
+    }
+
+    /// Every setting of this Layer, and the settings of `below` that this Layer leaves out
+    pub fn or(self, below: Layer) -> Layer {
+        Layer {
+            session_file: self.session_file.or(below.session_file),
+            cache_dir: self.cache_dir.or(below.cache_dir),
+            years: self.years.or(below.years),
+            jobs: self.jobs.or(below.jobs),
+            color: self.color.or(below.color),
+            timeout: self.timeout.or(below.timeout),
+        }
+    }
+}
+
+/// The resolved settings of a run, loaded once at startup
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    pub inputs: Inputs,
+    /// Years to run, all years if None
+    pub years: Option<Selection<Year>>,
+    pub jobs: usize,
+    pub color: Color,
+    pub timeout: Option<Duration>,
+}
+
+impl From<Layer> for Config {
+    fn from(layer: Layer) -> Config {
+        let default = Inputs::default();
+        Config {
+            inputs: Inputs {
+                session_path: layer.session_file.unwrap_or(default.session_path),
+                session: default.session,
+                cache_directory: layer.cache_dir.unwrap_or(default.cache_directory),
+            },
+            years: layer.years,
+            jobs: layer.jobs.unwrap_or(1),
+            color: layer.color.unwrap_or_default(),
+            timeout: layer.timeout,
+        }
+    }
+}
+
+impl Config {
+    /// Layer the command line flags `cli` over the environment, over the config file at `path` or `CONFIG_PATH`.
+    ///
+    /// A missing config file at `CONFIG_PATH` is fine, but one given by `--config` must exist.
+    pub fn load(cli: Layer, path: Option<&Path>) -> AocResult<Config> {
+        let file = match path {
+            Some(path) => fs::metadata(path)
+                .map_err(AocError::io(path))
+                .and_then(|_| Layer::from_file(path))?,
+            None => Layer::from_file(CONFIG_PATH)?,
+        };
+        Ok(cli.or(Layer::from_env()?).or(file).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_layers() {
+        let file: Layer = toml::from_str(
+            "session-file = \"../aoc/cookie\"\nyears = 2023\njobs = 4\ncolor = \"never\"\ntimeout = \"30s\"\n",
+        )
+        .unwrap();
+        test!(
+            Some(Selection::<Year>::from_str("2023").unwrap()),
+            file.years
+        );
+        test!(Some(Duration::from_secs(30)), file.timeout);
+        let env = Layer::from_vars(|key| match key {
+            "AOC_JOBS" => Some("8".to_string()),
+            "AOC_CACHE_DIR" => Some("/tmp/aoc".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        let cli = Layer {
+            jobs: Some(2),
+            ..Layer::default()
+        };
+        let config = Config::from(cli.or(env).or(file));
+        test!(PathBuf::from("../aoc/cookie"), config.inputs.session_path);
+        test!(PathBuf::from("/tmp/aoc"), config.inputs.cache_directory);
+        test!(2, config.jobs);
+        test!(Color::Never, config.color);
+        test!(Config::from(Layer::default()).inputs, Inputs::default());
+    }
+
+    #[test]
+    fn test_invalid_settings() {
+        test!(toml::from_str::<Layer>("jobs = 4\nparallelism = 4\n").is_err());
+        test!(toml::from_str::<Layer>("years = \"2023-\"\n").is_err());
+        test!(toml::from_str::<Layer>("color = \"sometimes\"\n").is_err());
+        let env = Layer::from_vars(|key| (key == "AOC_TIMEOUT").then(|| "soon".to_string()));
+        test!(matches!(env, Err(AocError::Parse { what, .. }) if what == "AOC_TIMEOUT"));
+    }
+
+    #[test]
+    fn test_color_enabled() {
+        test!(Color::Auto.enabled(false, true, true));
+        test!(!Color::Auto.enabled(true, true, true));
+        test!(!Color::Auto.enabled(false, false, true));
+        test!(!Color::Auto.enabled(false, true, false));
+        test!(Color::Always.enabled(true, false, false));
+        test!(!Color::Never.enabled(false, true, true));
+    }
+}
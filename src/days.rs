@@ -0,0 +1,62 @@
+// This module contains the days! macro, which declares every day of a year once, and generates the module of every
+// day and the lists of days that the runner uses, so registering a day is a matter of moving it between two lists
+
+/// Declare the modules of the days of a year, of which the days before `unsolved:` are registered, and generate:
+/// - `solutions`, the Solution of every registered day
+/// - `sources`, the source of every registered day, by day
+/// - `unregistered`, the unsolved days of which the module does define a Solution
+/// - a test of every registered day in `days`, like `y2023::days::d14`
+///
+/// The module of every registered day must define a `Problem` that implements Solution.
+#[macro_export]
+macro_rules! days {
+    ($($day:ident),* $(,)? ; unsolved: $($unsolved:ident),* $(,)?) => {
+        $(pub mod $day;)*
+        $(pub mod $unsolved;)*
+
+        /// Day of the module named `module`, like `d05`
+        fn day_of(module: &str) -> aoc::solution::Day {
+            module[1..].parse().unwrap()
+        }
+
+        /// Every Solution of this year
+        pub fn solutions() -> Vec<aoc::solution::SolutionBox> {
+            use aoc::solution::Solution;
+            vec![$($day::Problem::create_box()),*]
+        }
+
+        /// Source of every solution in `solutions`, by day, to tell which days changed since they were verified
+        pub fn sources() -> Vec<(aoc::solution::Day, &'static str)> {
+            vec![$((day_of(stringify!($day)), include_str!(concat!(stringify!($day), ".rs")))),*]
+        }
+
+        /// Unsolved days of which the module defines a Solution, so they are missing from `solutions`
+        pub fn unregistered() -> Vec<aoc::solution::Day> {
+            [$((day_of(stringify!($unsolved)), include_str!(concat!(stringify!($unsolved), ".rs")))),*]
+                .into_iter()
+                .filter(|(_, source)| source.contains("impl Solution for"))
+                .map(|(day, _)| day)
+                .collect()
+        }
+
+        #[cfg(test)]
+        #[test]
+        fn test_send_sync() {
+            /// Fails to compile unless `T` can be shared with the worker threads
+            fn assert_send_sync<T: Send + Sync>() {}
+            $(assert_send_sync::<$day::Problem>();)*
+            assert_send_sync::<aoc::solution::SolutionBox>();
+        }
+
+        #[cfg(test)]
+        mod days {
+            use aoc::solution::Solution;
+            $(
+                #[test]
+                fn $day() {
+                    $crate::tests::test_day(super::$day::Problem::create_box());
+                }
+            )*
+        }
+    };
+}
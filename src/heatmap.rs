@@ -0,0 +1,162 @@
+#![allow(dead_code)]
+// This module visualizes the durations of a run in the terminal, as an Advent calendar of which the days are
+// colored by duration, and a histogram of the durations of all parts, so slow outliers stand out
+
+use aoc::solution::*;
+use colored::{Color, Colorize};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Upper bounds of the histogram buckets, the last bucket holds everything slower
+const HISTOGRAM_BOUNDS: [Duration; 7] = [
+    Duration::from_micros(10),
+    Duration::from_micros(100),
+    Duration::from_millis(1),
+    Duration::from_millis(10),
+    Duration::from_millis(100),
+    Duration::from_secs(1),
+    Duration::from_secs(10),
+];
+
+/// Width of the longest bar of the histogram
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// Upper bounds of the calendar colors, and the color of a day that ran within that bound
+const CALENDAR_COLORS: [(Duration, Color); 4] = [
+    (Duration::from_millis(10), Color::Green),
+    (Duration::from_millis(100), Color::Yellow),
+    (Duration::from_secs(1), Color::Red),
+    (Duration::MAX, Color::BrightRed),
+];
+
+/// Index of the histogram bucket of `duration`
+fn bucket(duration: Duration) -> usize {
+    HISTOGRAM_BOUNDS
+        .iter()
+        .position(|&bound| duration < bound)
+        .unwrap_or(HISTOGRAM_BOUNDS.len())
+}
+
+fn bucket_label(bucket: usize) -> String {
+    match HISTOGRAM_BOUNDS.get(bucket) {
+        Some(bound) => format!("<{:?}", bound),
+        None => format!(">={:?}", HISTOGRAM_BOUNDS.last().unwrap()),
+    }
+}
+
+fn color(duration: Duration) -> Color {
+    CALENDAR_COLORS
+        .iter()
+        .find(|(bound, _)| duration < *bound)
+        .map_or(Color::BrightRed, |&(_, color)| color)
+}
+
+/// An Advent calendar per year, of which every day is colored by the total duration of both parts
+pub fn calendar(test_results: &[TestResult]) -> String {
+    let mut years: BTreeMap<Year, BTreeMap<Day, Duration>> = BTreeMap::new();
+    for test_result in test_results {
+        let days = years.entry(test_result.year).or_default();
+        if let Some(total) = test_result.total() {
+            days.insert(test_result.day, total);
+        }
+    }
+    let mut calendar = String::new();
+    for (year, days) in years {
+        writeln!(calendar, "{}", year).unwrap();
+        for week in 0..5 {
+            for day in week * 5 + 1..=week * 5 + 5 {
+                let cell = format!(" {:02} ", day);
+                match days.get(&day) {
+                    Some(&duration) => write!(calendar, "{}", cell.on_color(color(duration))),
+                    None => write!(calendar, "{}", cell.dimmed()),
+                }
+                .unwrap();
+            }
+            calendar.push('\n');
+        }
+    }
+    let legend: Vec<String> = CALENDAR_COLORS
+        .iter()
+        .map(|&(bound, color)| match bound {
+            Duration::MAX => " slower ".on_color(color).to_string(),
+            bound => format!(" <{:?} ", bound).on_color(color).to_string(),
+        })
+        .collect();
+    write!(calendar, "{}", legend.join(" ")).unwrap();
+    calendar
+}
+
+/// Number of parts per duration bucket, of all parts that ran
+fn histogram_counts(test_results: &[TestResult]) -> [usize; HISTOGRAM_BOUNDS.len() + 1] {
+    let mut counts = [0; HISTOGRAM_BOUNDS.len() + 1];
+    test_results
+        .iter()
+        .flat_map(|test_result| [&test_result.p1, &test_result.p2])
+        .filter_map(TestStatus::duration)
+        .for_each(|duration| counts[bucket(duration)] += 1);
+    counts
+}
+
+/// A histogram of the durations of all parts that ran, with a bar per power of ten
+pub fn histogram(test_results: &[TestResult]) -> String {
+    let counts = histogram_counts(test_results);
+    let max = counts.iter().copied().max().unwrap_or(0).max(1);
+    let mut histogram = String::new();
+    for (bucket, &count) in counts.iter().enumerate() {
+        let width = (count * HISTOGRAM_WIDTH).div_ceil(max);
+        writeln!(
+            histogram,
+            "{:>8} {:<width$} {}",
+            bucket_label(bucket),
+            "█".repeat(width),
+            count,
+            width = HISTOGRAM_WIDTH
+        )
+        .unwrap();
+    }
+    histogram.pop();
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_bucket() {
+        test!(0, bucket(Duration::from_micros(1)));
+        test!(2, bucket(Duration::from_micros(500)));
+        test!(3, bucket(Duration::from_millis(1)));
+        test!(7, bucket(Duration::from_secs(60)));
+        test!("<1ms", bucket_label(2));
+        test!(">=10s", bucket_label(7));
+    }
+
+    #[test]
+    fn test_histogram_counts() {
+        let test_result = |p1: TestStatus, p2: TestStatus| TestResult {
+            year: 2023,
+            day: 1,
+            parse: None,
+            p1,
+            p2,
+            examples: TestStatus::Unknown,
+            p1_memory: None,
+            p2_memory: None,
+        };
+        let test_results = [
+            test_result(
+                TestStatus::Success(Duration::from_micros(500), 1.into()),
+                TestStatus::Timeout(Duration::from_secs(30)),
+            ),
+            test_result(
+                TestStatus::Unverified(Duration::from_micros(700), 1.into()),
+                TestStatus::Cached(1.into()),
+            ),
+        ];
+        test!([0, 0, 2, 0, 0, 0, 0, 1], histogram_counts(&test_results));
+        test!(Some(Duration::from_micros(700)), test_results[1].total());
+        test!(8, histogram(&test_results).lines().count());
+    }
+}
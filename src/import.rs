@@ -0,0 +1,193 @@
+#![allow(dead_code)]
+// This module imports puzzle inputs that other Advent of Code tools already downloaded, like aocd and cargo-aoc,
+// into the input cache of this crate, so switching tools does not require downloading every input again
+
+use aoc::runner::{ExitStatus, Selection};
+use aoc::solution::{Day, Year};
+use aoc::Inputs;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable with the data directory of aocd, `~/.config/aocd` by default
+const AOCD_DIR_ENV: &str = "AOCD_DIR";
+
+/// A puzzle input that was found in the files of another tool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found {
+    pub year: Year,
+    pub day: Day,
+    pub path: PathBuf,
+}
+
+/// The data directory of aocd, if it exists
+pub fn aocd_directory() -> Option<PathBuf> {
+    let directory = match std::env::var_os(AOCD_DIR_ENV) {
+        Some(directory) => PathBuf::from(directory),
+        None => PathBuf::from(std::env::var_os("HOME")?).join(".config/aocd"),
+    };
+    directory.is_dir().then_some(directory)
+}
+
+/// Year and day of an aocd input file name, like `2023_05_input.txt`
+fn parse_aocd_name(name: &str) -> Option<(Year, Day)> {
+    let (year, rest) = name.split_once('_')?;
+    let (day, rest) = rest.split_once('_')?;
+    (rest == "input.txt").then_some(())?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Year and day of a cargo-aoc input file, like `input/2023/day5.txt`
+fn parse_cargo_aoc_path(year: &str, name: &str) -> Option<(Year, Day)> {
+    let day = name.strip_prefix("day")?.strip_suffix(".txt")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Files in `directory`, or nothing if it can not be read
+fn files(directory: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(directory) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+    files.sort();
+    files
+}
+
+/// File name of `path` as a str, or "" if it has none
+fn name(path: &Path) -> &str {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default()
+}
+
+/// Inputs in the data directory of aocd, which keeps the inputs of every account in its own directory
+pub fn aocd_inputs(directory: &Path) -> Vec<Found> {
+    files(directory)
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .flat_map(|account| files(&account))
+        .filter_map(|path| {
+            let (year, day) = parse_aocd_name(name(&path))?;
+            Some(Found { year, day, path })
+        })
+        .collect()
+}
+
+/// Inputs in the `input` directory of a cargo-aoc project
+pub fn cargo_aoc_inputs(project: &Path) -> Vec<Found> {
+    files(&project.join("input"))
+        .into_iter()
+        .filter(|path| path.is_dir())
+        .flat_map(|year| files(&year))
+        .filter_map(|path| {
+            let year = path.parent().map(name).unwrap_or_default();
+            let (year, day) = parse_cargo_aoc_path(year, name(&path))?;
+            Some(Found { year, day, path })
+        })
+        .collect()
+}
+
+/// Read the input of `found`, with a trailing newline like a downloaded input, so its hash matches answers.toml
+fn read_input(found: &Found) -> Option<String> {
+    let mut input = fs::read_to_string(&found.path).ok()?;
+    if input.trim().is_empty() {
+        return None;
+    }
+    if !input.ends_with('\n') {
+        input.push('\n');
+    }
+    Some(input)
+}
+
+/// Import the `found` inputs of the selected years and days into the cache of `inputs`, and print what was imported.
+///
+/// Inputs that are already cached are kept, unless `force`. A day with different inputs, e.g. of several aocd
+/// accounts, is skipped, since it is unknown which one belongs to the session cookie.
+pub fn import(
+    found: Vec<Found>,
+    inputs: &Inputs,
+    years: Option<&Selection<Year>>,
+    days: Option<&Selection<Day>>,
+    force: bool,
+) -> ExitStatus {
+    let mut by_day: BTreeMap<(Year, Day), Vec<(PathBuf, String)>> = BTreeMap::new();
+    for found in found {
+        let is_selected = years.is_none_or(|years| years.contains(&found.year))
+            && days.is_none_or(|days| days.contains(&found.day));
+        if let Some(input) = read_input(&found).filter(|_| is_selected) {
+            by_day
+                .entry((found.year, found.day))
+                .or_default()
+                .push((found.path, input));
+        }
+    }
+    let (mut imported, mut cached, mut conflicting) = (0, 0, 0);
+    for ((year, day), found) in by_day {
+        if !force && inputs.cache_path(year, day).exists() {
+            cached += 1;
+            continue;
+        }
+        let (path, input) = &found[0];
+        if found.iter().any(|(_, other)| other != input) {
+            let paths: Vec<String> = found
+                .iter()
+                .map(|(path, _)| path.display().to_string())
+                .collect();
+            eprintln!(
+                "Skipped {}-{:02}, its inputs differ: {}.",
+                year,
+                day,
+                paths.join(", ")
+            );
+            conflicting += 1;
+            continue;
+        }
+        if let Err(error) = inputs.store(year, day, input) {
+            eprintln!("{}", error);
+            return ExitStatus::Error;
+        }
+        println!("Imported {}-{:02} from '{}'.", year, day, path.display());
+        imported += 1;
+    }
+    println!(
+        "Imported {} inputs, kept {} inputs that were already cached, skipped {} days with differing inputs.",
+        imported, cached, conflicting
+    );
+    ExitStatus::Success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_parse_names() {
+        test!(Some((2023, 5)), parse_aocd_name("2023_05_input.txt"));
+        test!(parse_aocd_name("2023_05_answer_a.txt").is_none());
+        test!(parse_aocd_name("token2id.json").is_none());
+        test!(Some((2015, 25)), parse_cargo_aoc_path("2015", "day25.txt"));
+        test!(parse_cargo_aoc_path("2015", "day25.rs").is_none());
+    }
+
+    #[test]
+    fn test_inputs() {
+        let root = std::env::temp_dir().join(format!("aoc-import-{}", std::process::id()));
+        let write = |path: &str, contents: &str| {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        };
+        write("aocd/github.user.1/2023_01_input.txt", "1abc2");
+        write("aocd/github.user.1/2023_01_answer_a.txt", "12");
+        write("project/input/2022/day3.txt", "vJrwpWtwJgWr\n");
+        let found = aocd_inputs(&root.join("aocd"));
+        test!(1, found.len());
+        test!((2023, 1), (found[0].year, found[0].day));
+        test!("1abc2\n", read_input(&found[0]).unwrap());
+        let found = cargo_aoc_inputs(&root.join("project"));
+        test!(1, found.len());
+        test!((2022, 3), (found[0].year, found[0].day));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
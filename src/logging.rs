@@ -0,0 +1,157 @@
+// This module sets up tracing, which the debug! macro logs to
+
+use std::fmt::{self, Write};
+use std::sync::Mutex;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::EnvFilter;
+
+/// Environment variable with extra tracing directives, e.g. `AOC_LOG=y2023::d16=debug`
+pub const LOG_ENV: &str = "AOC_LOG";
+
+/// Name of the binary crate, which prefixes the target of every log message of a Solution
+const CRATE_NAME: &str = env!("CARGO_CRATE_NAME");
+
+/// Default directives for a verbosity level, `-v` enables debug! for this project, `-vv` for everything
+fn default_directives(verbosity: u8) -> String {
+    match verbosity {
+        0 => "warn".to_string(),
+        1 => format!("warn,aoc=debug,{}=debug", CRATE_NAME),
+        _ => "trace".to_string(),
+    }
+}
+
+/// Prefix module targets like `y2023::d16` with the crate name, so they can be written as they appear in the source tree
+fn expand_directive(directive: &str) -> String {
+    let directive = directive.trim();
+    let target = directive.split(['=', '[']).next().unwrap_or_default();
+    let is_level = target.parse::<tracing::Level>().is_ok() || target == "off";
+    let is_library = target == "aoc" || target.starts_with("aoc::");
+    if target.is_empty() || is_level || is_library || target.starts_with(CRATE_NAME) {
+        directive.to_string()
+    } else {
+        format!("{}::{}", CRATE_NAME, directive)
+    }
+}
+
+/// Parse the comma separated `AOC_LOG` directives
+fn expand_directives(directives: &str) -> Vec<String> {
+    directives
+        .split(',')
+        .filter(|directive| !directive.trim().is_empty())
+        .map(expand_directive)
+        .collect()
+}
+
+/// A log message that was captured, to show it in a report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Captured {
+    /// Module path of the caller of debug!, e.g. `advent_of_code_2023::y2023::d16`
+    pub target: String,
+    pub level: tracing::Level,
+    pub message: String,
+}
+
+/// Messages captured by `Capture` since the start of the run
+static CAPTURED: Mutex<Vec<Captured>> = Mutex::new(Vec::new());
+
+/// Return the messages that were captured so far, in the order in which they were logged
+pub fn take_captured() -> Vec<Captured> {
+    std::mem::take(&mut *CAPTURED.lock().unwrap())
+}
+
+/// Layer that keeps every enabled message in CAPTURED, besides writing it to stderr
+struct Capture;
+
+/// Formats the message and the other fields of an event, like the `fmt` layer does
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        let _ = match field.name() {
+            "message" => write!(self.0, "{:?}", value),
+            name => write!(self.0, "{}={:?}", name, value),
+        };
+    }
+}
+
+impl<S: Subscriber> Layer<S> for Capture {
+    fn on_event(&self, event: &Event<'_>, _context: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        CAPTURED.lock().unwrap().push(Captured {
+            target: event.metadata().target().to_string(),
+            level: *event.metadata().level(),
+            message: visitor.0,
+        });
+    }
+}
+
+/// Install a global tracing subscriber that writes to stderr, filtered by `verbosity` and `AOC_LOG`.
+///
+/// If `capture`, the messages are also kept, see `take_captured`. Colors are left out unless `ansi`, see `Color::apply`.
+pub fn init(verbosity: u8, capture: bool, ansi: bool) {
+    let mut filter = EnvFilter::new(default_directives(verbosity));
+    if let Ok(directives) = std::env::var(LOG_ENV) {
+        for directive in expand_directives(&directives) {
+            match directive.parse() {
+                Ok(directive) => filter = filter.add_directive(directive),
+                Err(error) => {
+                    eprintln!("Invalid {} directive '{}': {}.", LOG_ENV, directive, error)
+                }
+            }
+        }
+    }
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(std::io::stderr)
+                .with_ansi(ansi)
+                .without_time(),
+        )
+        .with(capture.then_some(Capture))
+        .init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_expand_directives() {
+        test!(
+            vec![
+                format!("{}::y2023::d16=debug", CRATE_NAME),
+                "info".to_string(),
+                "aoc=trace".to_string(),
+                "aoc::input=debug".to_string(),
+                format!("{}::runner=debug", CRATE_NAME),
+            ],
+            expand_directives(&format!(
+                "y2023::d16=debug, info,,aoc=trace,aoc::input=debug,{}::runner=debug",
+                CRATE_NAME
+            ))
+        );
+    }
+
+    #[test]
+    fn test_capture() {
+        let subscriber = tracing_subscriber::registry().with(Capture);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::debug!(steps = 3, "Spin cycle {}.", 2);
+        });
+        let captured = take_captured();
+        test!(1, captured.len());
+        test!("Spin cycle 2. steps=3", captured[0].message);
+        test!(tracing::Level::DEBUG, captured[0].level);
+        test!(captured[0].target.ends_with("logging::tests"));
+    }
+}
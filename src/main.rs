@@ -1,83 +1,931 @@
-mod grid;
-mod line_segment;
-mod macros;
-mod solution;
+mod badges;
+mod bench;
+mod bins;
+mod compare;
+mod completions;
+mod config;
+mod days;
+mod heatmap;
+mod import;
+mod logging;
+mod notes;
+mod preflight;
+mod report;
+mod revision;
+mod status;
+mod today;
+mod verify;
+#[cfg(feature = "y2023")]
 mod y2023;
 
-use solution::*;
+use aoc::answers::{self, Answers};
+use aoc::cache::{self, Cache};
+use aoc::history::{self, History, Outlier};
+use aoc::hooks::Hooks;
+use aoc::runner::{self, ExitStatus, Order, Part, RunOptions, Selection};
+use aoc::solution::*;
+use aoc::{frames, repl, snapshots, viz};
+use config::{Color, Config, Layer};
+use report::{Event, Output};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::sync::Mutex;
 
-use clap::Parser;
+use clap::parser::ValueSource;
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand};
+
+/// Counts the heap memory in use, so the peak memory of every part is reported
+#[cfg(feature = "track-memory")]
+#[global_allocator]
+static ALLOCATOR: aoc::memory::TrackingAllocator = aoc::memory::TrackingAllocator;
 
 #[derive(Parser)]
 #[command(name = "advent-of-code-2023")]
 #[command(about = "Advent of Code 2023 - By jortrr", long_about = None)]
 struct Cli {
-    /// Day to run, one of {1, ..., 25}
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Days to run, e.g. `5`, or `1-10,14,16`, all days by default
+    #[arg(short, long, visible_alias = "day", global = true)]
+    days: Option<Selection<Day>>,
+
+    /// Years to run, e.g. `2023`, or `2022-2023`, all years by default, see also `years` in `aoc.toml`
+    #[arg(short, long, global = true)]
+    years: Option<Selection<Year>>,
+
+    /// Only run days with one of these tags, e.g. `--tag grid --tag graph`
+    #[arg(short, long = "tag", value_enum, global = true)]
+    tags: Vec<Tag>,
+
+    /// Abandon a part after this duration, e.g. `30s`, `500ms` or `2m`, see also `timeout` in `aoc.toml`
+    #[arg(long, value_parser = runner::parse_duration)]
+    timeout: Option<Duration>,
+
+    /// Part of which the answer is used by `--copy` and `--raw`
+    #[arg(short, long, value_enum, default_value = "1")]
+    part: Part,
+
+    /// Copy the answer of `--part` to the clipboard, ready to paste into adventofcode.com
+    #[arg(short, long)]
+    copy: bool,
+
+    /// Only print the answer of `--part`, one line per day, for easy shell capture
+    #[arg(long)]
+    raw: bool,
+
+    /// Print the narrated intermediate results of the days that support it, e.g. the load after the first spin cycles
+    /// of 2023-14
+    #[arg(long)]
+    explain: bool,
+
+    /// Number of untimed iterations of each part before the measured one
+    #[arg(long, default_value_t = 0)]
+    warmup: usize,
+
+    /// Order in which the days are run, the fastest and slowest days are known from earlier runs
+    #[arg(long, value_enum, default_value_t = Order::Chronological)]
+    order: Order,
+
+    /// Seed for `--order shuffle`, a random seed is used and printed if omitted
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Solve every part again, instead of using the cached answers of parts that opted in with `cache_version`
+    #[arg(long)]
+    no_cache: bool,
+
+    /// Check that every selected day can run without solving or downloading anything: that its input is cached or
+    /// can be downloaded, that its examples parse, and that it has expected answers, and that no day is left
+    /// unregistered
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Skip the days of which both parts ran to their expected answer for the cached input before, and of which the
+    /// source did not change since
+    #[arg(long)]
+    skip_verified: bool,
+
+    /// Start no more days after the first wrong answer, failed example, timeout or error, and exit with its status,
+    /// the days that already started still finish
+    #[arg(long)]
+    fail_fast: bool,
+
+    /// When run without other arguments during December, scaffold and download the puzzle of today without asking,
+    /// if it is unlocked but not solved yet
+    #[arg(long)]
+    yes: bool,
+
+    /// After the run, show a calendar of the days colored by duration, and a histogram of the durations of all parts
+    #[arg(long)]
+    heatmap: bool,
+
+    /// Number of threads to use, for running days in parallel and for the thread pool of `ParLines`,
+    /// a day that uses several threads declares them with `fn threads(&self)`, 1 by default
     #[arg(short, long)]
-    day: Option<Day>,
+    jobs: Option<usize>,
+
+    /// Write every step of the simulations of days that record frames to numbered files in this directory,
+    /// e.g. the spin cycles of 2023-14 or the settling bricks of 2023-22
+    #[arg(long, value_name = "DIR")]
+    dump_frames: Option<PathBuf>,
+
+    /// Save the state of the simulations of days that support snapshots to this directory every `--snapshot-every`
+    /// steps, e.g. the spin cycles of 2023-14
+    #[arg(long, value_name = "DIR")]
+    snapshots: Option<PathBuf>,
+
+    /// Number of steps between snapshots
+    #[arg(long, value_name = "STEPS", default_value_t = 100)]
+    snapshot_every: Int,
+
+    /// Start the simulations from their snapshot of this step in `--snapshots`, instead of from step 0
+    #[arg(long, value_name = "STEP", requires = "snapshots")]
+    replay: Option<Int>,
+
+    /// Write visualizations of days that support them to this directory, e.g. the brick-support graph of
+    /// 2023-22 as a Graphviz DOT file
+    #[arg(long, value_name = "DIR")]
+    viz: Option<PathBuf>,
+
+    /// After the run, write shields.io endpoint badges of the collected stars and the runtime to `badges/`
+    #[arg(long)]
+    badges: bool,
+
+    /// Format of the output, `html` prints a self-contained HTML report after the run, including the debug output
+    #[arg(long, value_enum, default_value_t = Output::Text, conflicts_with = "raw")]
+    output: Output,
+
+    /// When to color the output, `auto` by default
+    #[arg(long, value_enum, global = true)]
+    color: Option<Color>,
+
+    /// Path of the adventofcode.com session cookie, `.aocf/cookie` by default
+    #[arg(long, value_name = "FILE", global = true)]
+    session_file: Option<PathBuf>,
+
+    /// Directory of the cached puzzle inputs, `.aocf/cache` by default
+    #[arg(long, value_name = "DIR", global = true)]
+    cache_dir: Option<PathBuf>,
+
+    /// Config file with defaults for the flags above, `aoc.toml` by default, its settings can be overridden by
+    /// `AOC_*` environment variables, which can be overridden by flags
+    #[arg(long, value_name = "FILE", global = true)]
+    config: Option<PathBuf>,
+
+    /// Show debug output, `-v` for this project and `-vv` for everything, see also `AOC_LOG`
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
 }
 
-type SolutionBox = Box<dyn Solution>;
+#[derive(Subcommand)]
+enum Command {
+    /// List the selected solutions and their tags, instead of running them
+    List,
+    /// Show a matrix of the days of every year, with which days are solved, have examples, have expected answers
+    /// for the real input, and got stars on adventofcode.com
+    Status,
+    /// Check the expected answers of the selected solutions against the answers confirmed on adventofcode.com,
+    /// and add confirmed answers that are missing from answers.toml
+    Verify,
+    /// Benchmark the parse step and both parts of the selected solutions separately, to tell whether a slow
+    /// day needs a better parser or a better algorithm
+    Bench {
+        /// Number of times every phase is run
+        #[arg(long, default_value_t = 10)]
+        samples: usize,
+        /// Also write the timings to this JSON file, in the export format of this project
+        #[arg(long, value_name = "FILE")]
+        export: Option<PathBuf>,
+        #[command(subcommand)]
+        command: Option<BenchCommand>,
+    },
+    /// Compare two exports of `bench --export` of the same machine, e.g. of different branches, day by day, and
+    /// highlight the regressions
+    Compare {
+        /// Export to compare against
+        #[arg(default_value = bench::BASELINE_PATH)]
+        baseline: PathBuf,
+        /// Export that is compared against the baseline
+        #[arg(default_value = bench::LATEST_PATH)]
+        candidate: PathBuf,
+        /// Change in percent above which a day counts as a regression or an improvement
+        #[arg(long, default_value_t = compare::DEFAULT_THRESHOLD)]
+        threshold: f64,
+        /// Compare exports of different machines, of which the timings are not comparable
+        #[arg(long)]
+        force: bool,
+    },
+    /// Parse the puzzle input of a day, and explore it with the commands of that day, e.g. `repl 2023 14`
+    Repl { year: Year, day: Day },
+    /// Import the puzzle inputs of the selected years and days that aocd or cargo-aoc already downloaded into
+    /// the input cache, instead of downloading them again
+    Import {
+        /// Data directory of aocd, `$AOCD_DIR` or `~/.config/aocd` by default
+        #[arg(long, value_name = "DIR")]
+        aocd: Option<PathBuf>,
+        /// cargo-aoc project of which to import the `input/` directory, can be given several times
+        #[arg(long = "cargo-aoc", value_name = "PROJECT")]
+        cargo_aoc: Vec<PathBuf>,
+        /// Replace inputs that are already cached
+        #[arg(long)]
+        force: bool,
+    },
+    /// Generate a small binary per selected day in `src/bin`, so a day can also be run with e.g.
+    /// `cargo run --bin y2023d14`
+    Bins {
+        /// Replace binaries that already exist
+        #[arg(long)]
+        force: bool,
+    },
+    /// List the selected days and the state of their writeups in `notes/`
+    Notes {
+        #[command(subcommand)]
+        command: Option<NotesCommand>,
+    },
+    /// Print the completions of the CLI for a shell, with the years and days that have a solution, e.g.
+    /// `completions bash > ~/.local/share/bash-completion/completions/advent-of-code-2023`
+    Completions { shell: clap_complete::Shell },
+}
 
-fn main() {
-    let instant = Instant::now();
-    let cli = Cli::parse();
-
-    let aoc_solutions: Vec<SolutionBox> = vec![
-        y2023::d01::Problem::create_box(),
-        y2023::d02::Problem::create_box(),
-        y2023::d03::Problem::create_box(),
-        y2023::d04::Problem::create_box(),
-        y2023::d05::Problem::create_box(),
-        y2023::d06::Problem::create_box(),
-        y2023::d07::Problem::create_box(),
-        y2023::d08::Problem::create_box(),
-        y2023::d09::Problem::create_box(),
-        y2023::d10::Problem::create_box(),
-        y2023::d11::Problem::create_box(),
-        y2023::d12::Problem::create_box(),
-        y2023::d13::Problem::create_box(),
-        y2023::d14::Problem::create_box(),
-        y2023::d15::Problem::create_box(),
-        y2023::d16::Problem::create_box(),
-        y2023::d18::Problem::create_box(),
-        y2023::d19::Problem::create_box(),
-        y2023::d20::Problem::create_box(),
-        y2023::d21::Problem::create_box(),
-        y2023::d22::Problem::create_box(),
+#[derive(Subcommand)]
+enum BenchCommand {
+    /// Make the latest benchmark the baseline that `compare` compares with, after reviewing its timings
+    Accept,
+    /// Compare two exports day by day like `compare`, or benchmark another git revision and the working tree, e.g.
+    /// `bench diff --against-git main` to validate an optimization
+    Diff {
+        /// Export to compare against
+        #[arg(required_unless_present = "against_git")]
+        baseline: Option<PathBuf>,
+        /// Export that is compared against the baseline
+        #[arg(required_unless_present = "against_git")]
+        candidate: Option<PathBuf>,
+        /// Benchmark this git revision in a temporary worktree as the baseline, and the working tree as the candidate
+        #[arg(long, value_name = "REF", conflicts_with_all = ["baseline", "candidate"])]
+        against_git: Option<String>,
+        /// Change in percent above which a day counts as a regression or an improvement
+        #[arg(long, default_value_t = compare::DEFAULT_THRESHOLD)]
+        threshold: f64,
+        /// Compare exports of different machines, of which the timings are not comparable
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum NotesCommand {
+    /// Create the writeup of every selected day in `notes/`, with its title, tags, links and timings, and sections
+    /// to write, e.g. `--days 14 notes new`
+    New {
+        /// Replace writeups that already exist
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+impl Cli {
+    /// Returns true iff the command line of `matches` has no subcommand, and no flags besides `--yes`
+    fn is_bare(matches: &ArgMatches) -> bool {
+        matches.subcommand().is_none()
+            && Cli::command()
+                .get_arguments()
+                .map(|arg| arg.get_id().as_str())
+                .filter(|&id| id != "yes")
+                .all(|id| matches.value_source(id) != Some(ValueSource::CommandLine))
+    }
+
+    /// The settings that were given as flags, the top Layer of the Config
+    fn layer(&self) -> Layer {
+        Layer {
+            session_file: self.session_file.clone(),
+            cache_dir: self.cache_dir.clone(),
+            years: self.years.clone(),
+            jobs: self.jobs,
+            color: self.color,
+            timeout: self.timeout,
+        }
+    }
+
+    /// The flags that select the same solutions as `is_selected`, e.g. in another revision of this repository
+    fn selection_args(&self, config: &Config) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(days) = &self.days {
+            args.extend(["--days".to_string(), days.to_string()]);
+        }
+        if let Some(years) = &config.years {
+            args.extend(["--years".to_string(), years.to_string()]);
+        }
+        for tag in &self.tags {
+            args.extend(["--tag".to_string(), tag.to_string()]);
+        }
+        args
+    }
+
+    /// Returns true iff `solution` is selected by `--days`, the years of the `config` and `--tag`
+    fn is_selected(&self, solution: &SolutionBox, config: &Config) -> bool {
+        let is_selected_day = self
+            .days
+            .as_ref()
+            .is_none_or(|days| days.contains(&solution.day()));
+        let is_selected_year = config
+            .years
+            .as_ref()
+            .is_none_or(|years| years.contains(&solution.year()));
+        let is_selected_tag =
+            self.tags.is_empty() || solution.tags().iter().any(|tag| self.tags.contains(tag));
+        is_selected_day && is_selected_year && is_selected_tag
+    }
+}
+
+/// All Advent of Code solutions, of every year that is enabled by its feature flag
+fn all_solutions() -> Vec<SolutionBox> {
+    let years: Vec<Vec<SolutionBox>> = vec![
+        #[cfg(feature = "y2023")]
+        y2023::solutions(),
+    ];
+    years.into_iter().flatten().collect()
+}
+
+/// Source of every solution of `all_solutions`, by year and day
+fn all_sources() -> HashMap<(Year, Day), &'static str> {
+    let years: Vec<(Year, Vec<(Day, &'static str)>)> = vec![
+        #[cfg(feature = "y2023")]
+        (2023, y2023::sources()),
+    ];
+    years
+        .into_iter()
+        .flat_map(|(year, sources)| {
+            sources
+                .into_iter()
+                .map(move |(day, source)| ((year, day), source))
+        })
+        .collect()
+}
+
+/// Days that define a Solution, but are missing from `all_solutions`
+fn all_unregistered() -> Vec<(Year, Day)> {
+    let years: Vec<(Year, Vec<Day>)> = vec![
+        #[cfg(feature = "y2023")]
+        (2023, y2023::unregistered()),
     ];
+    years
+        .into_iter()
+        .flat_map(|(year, days)| days.into_iter().map(move |day| (year, day)))
+        .collect()
+}
+
+/// Copy the answer of `part` of the last TestResult to the clipboard, and print it on its own line unless `quiet`
+fn copy_answer(test_results: &[TestResult], part: Part, quiet: bool) {
+    let Some(answer) = test_results
+        .last()
+        .and_then(|test_result| test_result.part(part).answer())
+    else {
+        eprintln!("No answer to copy to the clipboard.");
+        return;
+    };
+    let copied =
+        arboard::Clipboard::new().and_then(|mut clipboard| clipboard.set_text(answer.to_string()));
+    match copied {
+        Ok(()) if quiet => (),
+        Ok(()) => println!("{}", answer),
+        Err(error) => eprintln!("Failed to copy '{}' to the clipboard: {}.", answer, error),
+    }
+}
+
+/// Print the narrated intermediate results of `solution` for its puzzle input, if it has any
+fn explain(solution: &SolutionBox, inputs: &Inputs) {
+    let input = match inputs.get(solution.year(), solution.day()) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}", error);
+            return;
+        }
+    };
+    let explanation = solution.explain(&input);
+    if explanation.is_empty() {
+        return;
+    }
+    println!("Explanation of {}-{:02}:", solution.year(), solution.day());
+    for line in explanation {
+        println!("    {}", line);
+    }
+}
+
+/// Parse the puzzle input of AoC(`year`, `day`), and run the REPL of its Solution on stdin
+fn repl(year: Year, day: Day, inputs: &Inputs) -> ExitStatus {
+    let Some(solution) = all_solutions()
+        .into_iter()
+        .find(|solution| (solution.year(), solution.day()) == (year, day))
+    else {
+        eprintln!("There is no solution for AoC {}-{:02}.", year, day);
+        return ExitStatus::Error;
+    };
+    let input = match inputs.get(year, day) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitStatus::Error;
+        }
+    };
+    let parsed = match solution.parse(&input, &Params::INPUT) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitStatus::Error;
+        }
+    };
+    let Some(mut repl) = solution.repl(&parsed) else {
+        eprintln!("AoC {}-{:02} has no REPL, see `Solution::repl`.", year, day);
+        return ExitStatus::Error;
+    };
+    let prompt = format!("{}-{:02}", year, day);
+    let stdin = std::io::stdin().lock();
+    match repl::run(repl.as_mut(), &prompt, stdin, &mut std::io::stdout()) {
+        Ok(()) => ExitStatus::Success,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitStatus::Error
+        }
+    }
+}
+
+/// Print one line for every solution, with its tags and how long it took the last time it ran
+fn list(solutions: &[SolutionBox], history: &History) {
+    for solution in solutions {
+        let tags: Vec<String> = solution.tags().iter().map(Tag::to_string).collect();
+        let duration = history
+            .get(solution.year(), solution.day())
+            .and_then(|timing| timing.total())
+            .map(|duration| format!(" {:.2?}", duration))
+            .unwrap_or_default();
+        println!(
+            "{}-{:02} [{}]{}",
+            solution.year(),
+            solution.day(),
+            tags.join(", "),
+            duration
+        );
+    }
+}
+
+/// The hooks that fire at every step of a day, register extensions like notifications or metrics here, `output`
+/// decides whether the steps are streamed as Events
+fn hooks(output: Output) -> Hooks {
+    let mut hooks = Hooks::default();
+    hooks.after_part(|event| {
+        let status = event.test_result.part(event.part);
+        let answer = status.answer().map(Answer::to_string).unwrap_or_default();
+        debug!(
+            target: "hooks",
+            "{}-{:02} part {}: {} {}",
+            event.solution.year(),
+            event.solution.day(),
+            event.part.number(),
+            status.name(),
+            answer
+        );
+    });
+    if output == Output::Ndjson {
+        let day = |solution: &SolutionBox| (solution.year(), solution.day());
+        hooks
+            .after_examples(move |event| {
+                let (year, day) = day(event.solution);
+                let status = event.test_result.examples.name();
+                Event::ExamplesFinished { year, day, status }.emit();
+            })
+            .before_fetch(move |event| {
+                let (year, day) = day(event.solution);
+                Event::FetchStarted { year, day }.emit();
+            })
+            .before_part(move |event| {
+                let (year, day) = day(event.solution);
+                let part = event.part.number();
+                Event::PartStarted { year, day, part }.emit();
+            })
+            .after_part(move |event| Event::part_finished(event.test_result, event.part).emit())
+            .after_failure(move |event| {
+                let (year, day) = day(event.solution);
+                let error = event.error.to_string();
+                Event::DayFailed { year, day, error }.emit();
+            });
+    }
+    hooks
+}
+
+fn main() -> ExitCode {
+    let instant = Instant::now();
+    let matches = Cli::command().get_matches();
+    let cli = match Cli::from_arg_matches(&matches) {
+        Ok(cli) => cli,
+        Err(error) => error.exit(),
+    };
+    let config = match Config::load(cli.layer(), cli.config.as_deref()) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitStatus::Error.into();
+        }
+    };
+    let ansi = config.color.apply();
+    logging::init(cli.verbose, cli.output == Output::Html, ansi);
+    // Whether progress and results are printed as text, `--raw` and `--output html` print something else to stdout
+    let text = !cli.raw && cli.output == Output::Text;
+    if let Some(directory) = &cli.dump_frames {
+        frames::init(directory);
+    }
+    if let Some(directory) = &cli.snapshots {
+        snapshots::init(directory, cli.snapshot_every, cli.replay);
+    }
+    if let Some(directory) = &cli.viz {
+        viz::init(directory);
+    }
+
+    let mut history = History::load(history::HISTORY_PATH);
+    let mut answers = match Answers::load(answers::ANSWERS_PATH) {
+        Ok(answers) => answers,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitStatus::Error.into();
+        }
+    };
+    let cache = Cache::load(cache::CACHE_PATH);
+    let mut aoc_solutions: Vec<SolutionBox> = all_solutions();
+    aoc_solutions.retain(|solution| cli.is_selected(solution, &config));
+    let selection = cli.selection_args(&config);
+
+    match cli.command {
+        Some(Command::List) => {
+            list(&aoc_solutions, &history);
+            return ExitCode::SUCCESS;
+        }
+        Some(Command::Status) => {
+            let years = config.years.as_ref();
+            return status::status(&all_solutions(), &config.inputs, &answers, years).into();
+        }
+        Some(Command::Verify) => {
+            return verify::verify(&aoc_solutions, &config.inputs, &mut answers).into()
+        }
+        Some(Command::Bench {
+            command: Some(BenchCommand::Accept),
+            ..
+        }) => {
+            let (latest, baseline) = (bench::LATEST_PATH.as_ref(), bench::BASELINE_PATH.as_ref());
+            return bench::accept(latest, baseline).into();
+        }
+        Some(Command::Bench {
+            samples,
+            command:
+                Some(BenchCommand::Diff {
+                    baseline,
+                    candidate,
+                    against_git,
+                    threshold,
+                    force,
+                }),
+            ..
+        }) => {
+            let inputs = &config.inputs;
+            return match (against_git, baseline, candidate) {
+                (Some(revision), ..) => {
+                    let solutions = &aoc_solutions;
+                    revision::diff(
+                        &revision, solutions, &selection, inputs, samples, threshold, force,
+                    )
+                }
+                (None, Some(baseline), Some(candidate)) => {
+                    compare::compare(&baseline, &candidate, threshold, force)
+                }
+                _ => unreachable!("clap requires both exports without --against-git"),
+            }
+            .into();
+        }
+        Some(Command::Bench {
+            samples, export, ..
+        }) => {
+            let latest = bench::LATEST_PATH.as_ref();
+            return bench::run(
+                &aoc_solutions,
+                &config.inputs,
+                samples,
+                latest,
+                export.as_deref(),
+            )
+            .into();
+        }
+        Some(Command::Compare {
+            baseline,
+            candidate,
+            threshold,
+            force,
+        }) => return compare::compare(&baseline, &candidate, threshold, force).into(),
+        Some(Command::Repl { year, day }) => return repl(year, day, &config.inputs).into(),
+        Some(Command::Import {
+            aocd,
+            cargo_aoc,
+            force,
+        }) => {
+            let mut found = Vec::new();
+            if let Some(directory) = aocd.or_else(import::aocd_directory) {
+                found.extend(import::aocd_inputs(&directory));
+            }
+            for project in cargo_aoc {
+                found.extend(import::cargo_aoc_inputs(&project));
+            }
+            let (years, days) = (config.years.as_ref(), cli.days.as_ref());
+            return import::import(found, &config.inputs, years, days, force).into();
+        }
+        Some(Command::Bins { force }) => {
+            let directory = std::path::Path::new(bins::BIN_DIRECTORY);
+            return bins::generate(&aoc_solutions, directory, force).into();
+        }
+        Some(Command::Notes { command }) => {
+            let directory = std::path::Path::new(notes::NOTES_DIRECTORY);
+            return match command {
+                Some(NotesCommand::New { force }) => {
+                    notes::generate(&aoc_solutions, directory, &config.inputs, &history, force)
+                }
+                None => notes::index(&aoc_solutions, directory),
+            }
+            .into();
+        }
+        Some(Command::Completions { shell }) => {
+            let mut stdout = std::io::stdout();
+            completions::generate(shell, Cli::command(), &all_solutions(), &mut stdout);
+            return ExitCode::SUCCESS;
+        }
+        None => (),
+    }
+    if cli.dry_run {
+        let unregistered = all_unregistered();
+        return preflight::preflight(&aoc_solutions, &config.inputs, &answers, &unregistered)
+            .into();
+    }
+    if Cli::is_bare(&matches) {
+        let now = std::time::SystemTime::now();
+        if let Some(status) = today::offer(&all_solutions(), &config.inputs, now, cli.yes) {
+            return status.into();
+        }
+    }
+
+    // Fails if the global thread pool was already built, which only happens in tests
+    let _ = rayon::ThreadPoolBuilder::new()
+        .num_threads(config.jobs)
+        .build_global();
+    let seed = cli.seed.unwrap_or_else(rand::random);
+    if cli.order == Order::Shuffle && text {
+        println!("Shuffled the AoC solutions with `--seed {}`.", seed);
+    }
+    let sources = all_sources();
+    let source = |year: Year, day: Day| sources.get(&(year, day)).copied().unwrap_or_default();
+    if cli.skip_verified {
+        let count = aoc_solutions.len();
+        aoc_solutions.retain(|solution| {
+            let (year, day) = (solution.year(), solution.day());
+            !config
+                .inputs
+                .cached(year, day)
+                .is_some_and(|input| cache.is_verified(year, day, &input, source(year, day)))
+        });
+        if text {
+            println!(
+                "Skipped {} days that were verified, and did not change since.",
+                count - aoc_solutions.len()
+            );
+        }
+    }
+    runner::sort(&mut aoc_solutions, cli.order, &history, seed);
+    // With several jobs, the slowest days start first, so no slow day is left running alone at the end, the results
+    // are still shown in chronological order
+    let scheduled = config.jobs > 1 && cli.order == Order::Chronological;
+    if scheduled {
+        runner::sort(&mut aoc_solutions, Order::SlowestFirst, &history, seed);
+    }
+    let (eta, unknown) = runner::eta(&aoc_solutions, &history, config.jobs);
+    if text && unknown < aoc_solutions.len() {
+        match unknown {
+            0 => println!("Estimated to take {:.2?}.", eta),
+            _ => println!(
+                "Estimated to take {:.2?}, without the {} days that have no timing history.",
+                eta, unknown
+            ),
+        }
+    }
+    let options = RunOptions {
+        timeout: config.timeout,
+        skip_examples: cli.raw,
+        warmup: cli.warmup,
+        answers,
+        use_cache: !cli.no_cache,
+        hooks: hooks(cli.output),
+        inputs: config.inputs.clone(),
+        fail_fast: cli.fail_fast,
+    };
+
+    if cli.output == Output::Ndjson {
+        if let Err(error) = report::take_stdout() {
+            eprintln!("Failed to keep stdout for the events. {}", error);
+        }
+    }
 
     let mut test_results: Vec<TestResult> = Vec::new();
+    let mut outliers: Vec<Outlier> = Vec::new();
+    let mut failed_to_run = false;
 
-    for (i, aoc_solution) in aoc_solutions.iter().enumerate() {
-        if let Some(day) = cli.day {
-            if day != aoc_solution.day() {
+    let cache = Mutex::new(cache);
+    let on_start = |i: usize, aoc_solution: &SolutionBox| {
+        if cli.output == Output::Ndjson {
+            let (year, day) = (aoc_solution.year(), aoc_solution.day());
+            Event::DayStarted { year, day }.emit();
+        }
+        if text {
+            println!(
+                "[{}/{}] Running AoC: {}-{:02}",
+                i,
+                aoc_solutions.len(),
+                aoc_solution.year(),
+                aoc_solution.day()
+            );
+        }
+    };
+    let results = runner::run_all(&aoc_solutions, &options, &cache, config.jobs, on_start);
+    let not_run = aoc_solutions.len() - results.len();
+    let mut results: Vec<(&SolutionBox, AocResult<TestResult>)> =
+        aoc_solutions.iter().zip(results).collect();
+    if scheduled {
+        results.sort_by_key(|(aoc_solution, _)| (aoc_solution.year(), aoc_solution.day()));
+    }
+    for (aoc_solution, result) in results {
+        let test_result = match result {
+            Ok(test_result) => test_result,
+            Err(error) => {
+                eprintln!("{}", error);
+                failed_to_run = true;
                 continue;
             }
+        };
+        if cli.raw {
+            match test_result.part(cli.part).answer() {
+                Some(answer) => println!("{}", answer),
+                None => println!(),
+            }
+        } else if text {
+            if cli.explain {
+                explain(aoc_solution, &config.inputs);
+            }
+            println!();
+        }
+        outliers.extend(history.outliers(&test_result));
+        history.record(&test_result);
+        let (year, day) = (test_result.year, test_result.day);
+        if let Some(input) = config.inputs.cached(year, day) {
+            let mut cache = cache.lock().unwrap();
+            cache.record_verified(&test_result, &input, source(year, day));
+        }
+        test_results.push(test_result);
+    }
+
+    if let Err(error) = history.save() {
+        eprintln!("Failed to save the timing history. {}", error);
+    }
+    if let Err(error) = cache.into_inner().unwrap().save() {
+        eprintln!("Failed to save the cache. {}", error);
+    }
+    if cli.badges && !test_results.is_empty() {
+        let expected = |year: Year, day: Day, part: Part| {
+            let input = config.inputs.cached(year, day)?;
+            options.answers.get(year, day, part, &input).cloned()
+        };
+        if let Err(error) = badges::write(&test_results, expected, badges::BADGES_PATH) {
+            eprintln!("Failed to write the badges. {}", error);
         }
+    }
 
+    if text {
+        for test_result in &test_results {
+            println!("{:?}", test_result);
+        }
+        for outlier in &outliers {
+            println!("{}", outlier);
+        }
+        println!(
+            "Ran {} AoC solutions in {:.2?}.",
+            test_results.len(),
+            instant.elapsed()
+        );
+    }
+    if cli.heatmap && text {
+        println!("\n{}\n", heatmap::calendar(&test_results));
+        println!("{}", heatmap::histogram(&test_results));
+    }
+    if cli.output == Output::Ndjson {
+        let (days, seconds) = (test_results.len(), instant.elapsed().as_secs_f64());
+        Event::RunFinished { days, seconds }.emit();
+    }
+    if cli.output == Output::Html {
+        let captured = logging::take_captured();
         println!(
-            "[{}/{}] Running AoC: {}-{:02}",
-            i,
-            aoc_solutions.len(),
-            aoc_solution.year(),
-            aoc_solution.day()
+            "{}",
+            report::html(&test_results, instant.elapsed(), &captured)
         );
-        test_results.push(aoc_solution.run());
-        println!();
     }
 
-    dbg!(&test_results);
-    println!(
-        "Ran {} AoC solutions in {:.2?}.",
-        test_results.len(),
-        instant.elapsed()
-    );
+    if not_run > 0 {
+        let failed = test_results.iter().find(|test_result| {
+            ExitStatus::of(std::slice::from_ref(test_result)) != ExitStatus::Success
+        });
+        match failed {
+            Some(failed) => eprintln!(
+                "Stopped after the first failure, {} days did not run:\n{:?}",
+                not_run, failed
+            ),
+            None => eprintln!(
+                "Stopped after the first day that could not run, {} days did not run.",
+                not_run
+            ),
+        }
+    }
 
-    assert!(!test_results.is_empty());
-    let all_test_results_succeed = test_results.iter().all(|result| match result.p1 {
-        TestStatus::Success(_, _) => true,
-        _ => false,
-    });
-    assert!(all_test_results_succeed);
+    if cli.copy {
+        copy_answer(&test_results, cli.part, !text);
+    }
+
+    if test_results.is_empty() {
+        eprintln!("No AoC solutions were run.");
+        return ExitStatus::Error.into();
+    }
+    match failed_to_run {
+        true => ExitStatus::Error.into(),
+        false => ExitStatus::of(&test_results).into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::shard::Shard;
+    use aoc::Inputs;
+
+    #[test]
+    fn test_is_bare() {
+        let is_bare = |args: &[&str]| Cli::is_bare(&Cli::command().get_matches_from(args));
+        test!(is_bare(&["aoc"]));
+        test!(is_bare(&["aoc", "--yes"]));
+        test!(!is_bare(&["aoc", "--yes", "--days", "5"]));
+        test!(!is_bare(&["aoc", "-v"]));
+        test!(!is_bare(&["aoc", "list"]));
+    }
+
+    #[test]
+    fn test_selection_args() {
+        let cli = Cli::parse_from(["aoc", "--days", "1-10, 14", "--tag", "grid", "bench"]);
+        let config = Config {
+            years: Some("2023".parse().unwrap()),
+            ..Config::from(Layer::default())
+        };
+        test!(
+            vec!["--days", "1-10,14", "--years", "2023", "--tag", "grid"],
+            cli.selection_args(&config)
+        );
+    }
+
+    /// Environment variable that also verifies the cached puzzle input of every day against answers.toml, which
+    /// is only practical in release builds, e.g. `AOC_TEST_INPUTS=1 cargo test --release days`
+    const INPUTS_ENV: &str = "AOC_TEST_INPUTS";
+
+    /// Run the examples of `solution` if its day is in the shard of `AOC_TEST_SHARD`, e.g.
+    /// `AOC_TEST_SHARD=2/8 cargo test days`, the test of every day is generated by days!
+    pub(crate) fn test_day(solution: SolutionBox) {
+        let shard = Shard::from_env().unwrap();
+        let (year, day) = (solution.year(), solution.day());
+        let position = all_solutions()
+            .iter()
+            .position(|other| (other.year(), other.day()) == (year, day))
+            .unwrap();
+        if !shard.contains(position) {
+            return;
+        }
+        test!(solution.run_examples());
+        let verify_inputs = std::env::var_os(INPUTS_ENV).is_some_and(|value| !value.is_empty());
+        let inputs = Inputs::default();
+        if !verify_inputs || !inputs.cache_path(year, day).exists() {
+            return;
+        }
+        let answers = Answers::load(answers::ANSWERS_PATH).unwrap();
+        let input = inputs.get(year, day).unwrap();
+        for part in [Part::One, Part::Two] {
+            let Some(expected) = answers.get(year, day, part, &input) else {
+                continue;
+            };
+            let answer = solution.solve(&input, part, &Params::INPUT).unwrap();
+            test!(
+                expected,
+                &answer,
+                "[{}] [{}] [{}]",
+                year,
+                day,
+                part.number()
+            );
+        }
+    }
 }
@@ -0,0 +1,161 @@
+// This module scaffolds a writeup per day in `notes/`, filled in with what the crate knows about the day, like its
+// title, tags and timings, and lists which days have one, the writeups themselves are written by hand
+
+use aoc::history::History;
+use aoc::runner::ExitStatus;
+use aoc::solution::*;
+use aoc::Inputs;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory of the writeups, one subdirectory per year
+pub const NOTES_DIRECTORY: &str = "notes";
+
+/// Text of the sections of a stub that are not written yet, a writeup that still contains it is a draft
+pub const PLACEHOLDER: &str = "_Not written yet._";
+
+/// Path of the writeup of a day in `directory`, like `notes/y2023/d14.md`
+pub fn path(directory: &Path, year: Year, day: Day) -> PathBuf {
+    directory
+        .join(format!("y{}", year))
+        .join(format!("d{:02}.md", day))
+}
+
+/// Writeup of `solution` with every section still to be written, and a header of its `title`, its tags, the link to
+/// the puzzle and its source, and the durations of its parts in `history`
+pub fn stub(solution: &SolutionBox, title: Option<&str>, history: &History) -> String {
+    let (year, day) = (solution.year(), solution.day());
+    let title = title.map_or(String::new(), |title| format!(": {}", title));
+    let tags: Vec<String> = solution.tags().iter().map(Tag::to_string).collect();
+    let tags = match tags.is_empty() {
+        true => "none".to_string(),
+        false => tags.join(", "),
+    };
+    let timing = history.get(year, day);
+    let seconds = |seconds: Option<f64>| {
+        seconds.map_or("-".to_string(), |seconds| {
+            format!("{:.2?}", Duration::from_secs_f64(seconds))
+        })
+    };
+    let (one, two) = (
+        seconds(timing.and_then(|timing| timing.part_one)),
+        seconds(timing.and_then(|timing| timing.part_two)),
+    );
+    let mut stub = format!(
+        "# {year}-{day:02}{title}\n\
+         \n\
+         - Puzzle: https://adventofcode.com/{year}/day/{day}\n\
+         - Solution: [src/y{year}/d{day:02}.rs](../../src/y{year}/d{day:02}.rs)\n\
+         - Tags: {tags}\n\
+         - Durations of the last run: part 1 {one}, part 2 {two}\n"
+    );
+    for section in ["Part one", "Part two", "What I learned"] {
+        stub += &format!("\n## {}\n\n{}\n", section, PLACEHOLDER);
+    }
+    stub
+}
+
+/// Write the stub of every solution into `directory`, writeups that already exist are only replaced if `force`
+pub fn generate(
+    solutions: &[SolutionBox],
+    directory: &Path,
+    inputs: &Inputs,
+    history: &History,
+    force: bool,
+) -> ExitStatus {
+    let (mut generated, mut existing) = (0, 0);
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let path = path(directory, year, day);
+        if !force && path.exists() {
+            existing += 1;
+            continue;
+        }
+        let title = inputs.title(year, day);
+        let written = fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(&path, stub(solution, title.as_deref(), history)));
+        if let Err(error) = written {
+            eprintln!("Failed to write '{}'. {}", path.display(), error);
+            return ExitStatus::Error;
+        }
+        println!("Created '{}'.", path.display());
+        generated += 1;
+    }
+    println!(
+        "Generated {} writeups in '{}', {} already existed.",
+        generated,
+        directory.display(),
+        existing
+    );
+    ExitStatus::Success
+}
+
+/// How far the writeup of a day is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Writeup {
+    Missing,
+    /// A stub of which some sections are not written yet
+    Draft,
+    Written,
+}
+
+impl Writeup {
+    /// The Writeup of a day of which `notes` is the writeup, if it exists
+    pub fn of(notes: Option<&str>) -> Writeup {
+        match notes {
+            None => Writeup::Missing,
+            Some(notes) if notes.contains(PLACEHOLDER) => Writeup::Draft,
+            Some(_) => Writeup::Written,
+        }
+    }
+}
+
+/// Print one line for every solution, with the state of its writeup in `directory`
+pub fn index(solutions: &[SolutionBox], directory: &Path) -> ExitStatus {
+    let mut written = 0;
+    for solution in solutions {
+        let path = path(directory, solution.year(), solution.day());
+        let notes = fs::read_to_string(&path).ok();
+        let writeup = Writeup::of(notes.as_deref());
+        let state = match writeup {
+            Writeup::Missing => "-".to_string(),
+            Writeup::Draft => format!("{} (draft)", path.display()),
+            Writeup::Written => path.display().to_string(),
+        };
+        println!("{}-{:02} {}", solution.year(), solution.day(), state);
+        written += (writeup == Writeup::Written) as usize;
+    }
+    println!("{} of {} days are written up.", written, solutions.len());
+    ExitStatus::Success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    #[cfg(feature = "y2023")]
+    fn test_stub() {
+        test!(
+            Path::new("notes/y2023/d05.md"),
+            path(Path::new(NOTES_DIRECTORY), 2023, 5)
+        );
+        let solution = crate::y2023::d14::Problem::create_box();
+        let stub = stub(
+            &solution,
+            Some("Parabolic Reflector Dish"),
+            &History::default(),
+        );
+        test!(stub.starts_with("# 2023-14: Parabolic Reflector Dish\n"));
+        test!(stub.contains("- Puzzle: https://adventofcode.com/2023/day/14\n"));
+        test!(stub.contains("(../../src/y2023/d14.rs)"));
+        test!(stub.contains("part 1 -, part 2 -"));
+        test!(Writeup::Draft, Writeup::of(Some(&stub)));
+        test!(
+            Writeup::Written,
+            Writeup::of(Some("# 2023-14\n\nIt tilts."))
+        );
+        test!(Writeup::Missing, Writeup::of(None));
+    }
+}
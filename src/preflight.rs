@@ -0,0 +1,228 @@
+// This module checks that every selected day can run, without solving anything: that its input can be obtained,
+// that its examples parse, and that it has expected answers, so wiring mistakes show up before a long run
+
+use aoc::answers::Answers;
+use aoc::runner::{ExitStatus, Part};
+use aoc::solution::*;
+use aoc::Inputs;
+use std::fmt::{self, Display};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Outcome of a single check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Passed(String),
+    /// The day can run, but something is missing, like its expected answers
+    Warning(String),
+    /// The day can not run
+    Failed(String),
+}
+
+impl Check {
+    fn is_failed(&self) -> bool {
+        matches!(self, Check::Failed(_))
+    }
+}
+
+/// The Checks of a single day
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preflight {
+    pub year: Year,
+    pub day: Day,
+    pub input: Check,
+    pub examples: Check,
+    pub answers: Check,
+}
+
+impl Preflight {
+    pub fn is_failed(&self) -> bool {
+        [&self.input, &self.examples, &self.answers]
+            .iter()
+            .any(|check| check.is_failed())
+    }
+}
+
+impl Display for Preflight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:02}", self.year, self.day)?;
+        for (name, check) in [
+            ("input", &self.input),
+            ("examples", &self.examples),
+            ("answers", &self.answers),
+        ] {
+            let (icon, detail) = match check {
+                Check::Passed(detail) => ("✅", detail),
+                Check::Warning(detail) => ("⚠️", detail),
+                Check::Failed(detail) => ("❌", detail),
+            };
+            write!(f, " {} {} ({})", icon, name, detail)?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether the puzzle input of AoC(`year`, `day`) is cached, and the input if so. An input that is not cached is
+/// not downloaded, it only fails if there is no session cookie to download it with.
+fn check_input(year: Year, day: Day, inputs: &Inputs) -> (Check, Option<String>) {
+    if let Some(input) = inputs.cached(year, day) {
+        return (Check::Passed("cached".to_string()), Some(input));
+    }
+    match inputs.session_cookie() {
+        Ok(_) => (
+            Check::Warning("not cached, downloaded on run".to_string()),
+            None,
+        ),
+        Err(error) => (Check::Failed(error.to_string()), None),
+    }
+}
+
+/// Whether every example of `solution` can be read and parsed, without solving it. The examples of a Solution
+/// without a parse step are only parsed when they are solved, so they are not checked.
+fn check_examples(solution: &SolutionBox) -> Check {
+    let examples = solution.define_examples();
+    if examples.is_empty() {
+        return Check::Warning("none".to_string());
+    }
+    if !solution.has_parse_step() {
+        return Check::Warning(format!("{} not checked, no parse step", examples.len()));
+    }
+    for (i, example) in examples.iter().enumerate() {
+        let parsed = panic::catch_unwind(AssertUnwindSafe(|| {
+            solution.parse(&example.get_input(), &example.params)
+        }));
+        if !matches!(parsed, Ok(Ok(_))) {
+            return Check::Failed(format!("example {} does not parse", i + 1));
+        }
+    }
+    Check::Passed(examples.len().to_string())
+}
+
+/// Whether both parts of AoC(`year`, `day`) have an expected answer for `input`
+fn check_answers(year: Year, day: Day, input: Option<&str>, answers: &Answers) -> Check {
+    let Some(input) = input else {
+        return Check::Warning("no input".to_string());
+    };
+    let missing: Vec<String> = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|&part| answers.get(year, day, part, input).is_none())
+        .map(|part| part.number().to_string())
+        .collect();
+    match missing.len() {
+        0 => Check::Passed("both parts".to_string()),
+        _ => Check::Warning(format!("part {} missing", missing.join(" and "))),
+    }
+}
+
+/// The Preflight of `solution`
+pub fn check(solution: &SolutionBox, inputs: &Inputs, answers: &Answers) -> Preflight {
+    let (year, day) = (solution.year(), solution.day());
+    let (input, text) = check_input(year, day, inputs);
+    Preflight {
+        year,
+        day,
+        input,
+        examples: check_examples(solution),
+        answers: check_answers(year, day, text.as_deref(), answers),
+    }
+}
+
+/// Print the Preflight of every solution, and the `unregistered` days that define a Solution that is not run.
+/// Fails if a day can not run, or is not registered.
+pub fn preflight(
+    solutions: &[SolutionBox],
+    inputs: &Inputs,
+    answers: &Answers,
+    unregistered: &[(Year, Day)],
+) -> ExitStatus {
+    let mut failed = 0;
+    for solution in solutions {
+        let preflight = check(solution, inputs, answers);
+        println!("{}", preflight);
+        failed += preflight.is_failed() as usize;
+    }
+    for (year, day) in unregistered {
+        println!(
+            "{}-{:02} ❌ defines a Solution, but is missing from the solutions of {}",
+            year, day, year
+        );
+    }
+    println!(
+        "{} of {} days are ready to run, {} days are not registered.",
+        solutions.len() - failed,
+        solutions.len(),
+        unregistered.len()
+    );
+    match failed + unregistered.len() {
+        0 => ExitStatus::Success,
+        _ => ExitStatus::Error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_checks() {
+        let answers = Answers::default();
+        test!(
+            Check::Warning("part 1 and 2 missing".to_string()),
+            check_answers(2023, 5, Some("input"), &answers)
+        );
+        test!(
+            Check::Warning("no input".to_string()),
+            check_answers(2023, 5, None, &answers)
+        );
+        let preflight = Preflight {
+            year: 2023,
+            day: 5,
+            input: Check::Passed("cached".to_string()),
+            examples: Check::Failed("example 1 does not parse".to_string()),
+            answers: Check::Warning("part 2 missing".to_string()),
+        };
+        test!(preflight.is_failed());
+        test!(
+            "2023-05 ✅ input (cached) ❌ examples (example 1 does not parse) ⚠️ answers (part 2 missing)",
+            preflight.to_string()
+        );
+    }
+
+    #[test]
+    fn test_check_input() {
+        let directory = std::env::temp_dir().join(format!("aoc-preflight-{}", std::process::id()));
+        let mut inputs = Inputs {
+            session_path: directory.join("cookie"),
+            session: None,
+            cache_directory: directory.join("cache"),
+        };
+        test!(check_input(2023, 5, &inputs).0.is_failed());
+        inputs.session = Some("session".to_string());
+        test!(
+            Check::Warning("not cached, downloaded on run".to_string()),
+            check_input(2023, 5, &inputs).0
+        );
+        inputs.store(2023, 5, "input").unwrap();
+        test!(
+            (
+                Check::Passed("cached".to_string()),
+                Some("input".to_string())
+            ),
+            check_input(2023, 5, &inputs)
+        );
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "y2023")]
+    fn test_check_examples() {
+        test!(
+            Check::Passed("1".to_string()),
+            check_examples(&crate::y2023::d14::Problem::create_box())
+        );
+        test!(
+            Check::Warning("1 not checked, no parse step".to_string()),
+            check_examples(&crate::y2023::d13::Problem::create_box())
+        );
+    }
+}
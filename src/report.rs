@@ -0,0 +1,407 @@
+#![allow(dead_code)]
+// This module renders the results of a run as a single, self-contained HTML file, with a results table, a chart of
+// the timings of every day and the debug output of every day, to share the results of a run or archive a year, or
+// streams the steps of a run as they happen as one JSON event per line
+
+use crate::logging::Captured;
+use aoc::runner::Part;
+use aoc::solution::*;
+use serde::Serialize;
+use std::fmt::Write;
+use std::fs::File;
+use std::io::{self, Write as _};
+use std::sync::{Mutex, OnceLock};
+
+/// Format of the output of a run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Output {
+    /// Progress and results as text
+    #[default]
+    Text,
+    /// A self-contained HTML report on stdout, after the run
+    Html,
+    /// One JSON event per line on stdout, as soon as every step of the run happens
+    Ndjson,
+}
+
+/// A step of a run, which `--output ndjson` prints as a line of JSON, of which `event` is the snake case name
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    DayStarted {
+        year: Year,
+        day: Day,
+    },
+    /// The examples of a day ran, `status` is the name of the TestStatus, `Failed` if an example failed
+    ExamplesFinished {
+        year: Year,
+        day: Day,
+        status: &'static str,
+    },
+    /// The puzzle input is about to be read from the cache, or downloaded
+    FetchStarted {
+        year: Year,
+        day: Day,
+    },
+    PartStarted {
+        year: Year,
+        day: Day,
+        part: u8,
+    },
+    PartFinished {
+        year: Year,
+        day: Day,
+        part: u8,
+        status: &'static str,
+        answer: Option<String>,
+        seconds: Option<f64>,
+        /// Peak heap memory of the part in bytes, if memory is tracked
+        memory_bytes: Option<usize>,
+    },
+    /// A day could not run because of `error`, like an input that can not be downloaded
+    DayFailed {
+        year: Year,
+        day: Day,
+        error: String,
+    },
+    RunFinished {
+        days: usize,
+        seconds: f64,
+    },
+}
+
+impl Event {
+    /// The PartFinished Event of `part` of `test_result`
+    pub fn part_finished(test_result: &TestResult, part: Part) -> Event {
+        let status = test_result.part(part);
+        Event::PartFinished {
+            year: test_result.year,
+            day: test_result.day,
+            part: part.number(),
+            status: status.name(),
+            answer: status.answer().map(Answer::to_string),
+            seconds: status.duration().map(|duration| duration.as_secs_f64()),
+            memory_bytes: test_result.memory(part),
+        }
+    }
+
+    /// Print the Event as a single line of JSON on stdout, see `take_stdout`
+    pub fn emit(&self) {
+        let json = serde_json::to_string(self).expect("An Event is always serializable.");
+        match EVENTS.get() {
+            Some(events) => {
+                let _ = writeln!(events.lock().unwrap(), "{}", json);
+            }
+            None => println!("{}", json),
+        }
+    }
+}
+
+/// The original stdout, once `take_stdout` took it for the Events
+static EVENTS: OnceLock<Mutex<File>> = OnceLock::new();
+
+/// Keep stdout for the Events, so everything else that is printed to it from now on, like the output of the
+/// examples or a Solution, goes to stderr instead of mixing into the stream.
+///
+/// Only on Unix, elsewhere the Events share stdout with everything else.
+#[cfg(unix)]
+pub fn take_stdout() -> io::Result<()> {
+    use std::os::fd::{AsFd, AsRawFd};
+    let events = io::stdout().as_fd().try_clone_to_owned()?;
+    io::stdout().flush()?;
+    // SAFETY: both file descriptors stay open for the whole process, dup2 only makes 1 refer to what 2 refers to
+    if unsafe { libc::dup2(io::stderr().as_raw_fd(), io::stdout().as_raw_fd()) } < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    let _ = EVENTS.set(Mutex::new(File::from(events)));
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn take_stdout() -> io::Result<()> {
+    Ok(())
+}
+
+/// Styling of the report, the status classes are named after the TestStatus variants
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em; background: #0f0f23; color: #cccccc; }
+h1, h2 { color: #00cc00; }
+table { border-collapse: collapse; }
+th, td { padding: 0.3em 0.8em; border-bottom: 1px solid #333340; text-align: left; }
+td.duration { text-align: right; font-family: monospace; }
+.Success { color: #00cc00; }
+.Failed, .Error, .Timeout { color: #ff4444; }
+.Unverified { color: #ffff66; }
+.Cached { color: #66ffff; }
+.Unknown { color: #888888; }
+.chart { display: grid; grid-template-columns: max-content 1fr; gap: 0.2em 1em; align-items: center; }
+.bar { height: 0.7em; margin: 1px 0; }
+.bar.p1 { background: #9999cc; }
+.bar.p2 { background: #ffff66; }
+details { margin: 0.5em 0; }
+summary { cursor: pointer; }
+pre { background: #10101a; padding: 0.5em; overflow-x: auto; }
+";
+
+/// Shortest duration on the logarithmic scale of the timings chart
+const CHART_MIN: Duration = Duration::from_micros(1);
+
+/// Escape `text` for use in HTML text and attribute values
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Table cell of a TestStatus, colored by its variant
+fn status_cell(status: &TestStatus) -> String {
+    let answer = status
+        .answer()
+        .map(|answer| format!(" {}", escape(&answer.to_string())))
+        .unwrap_or_default();
+    format!("<td class=\"{0}\">{0}{1}</td>", status.name(), answer)
+}
+
+fn duration_cell(duration: Option<Duration>) -> String {
+    let duration = duration
+        .map(|duration| format!("{:.2?}", duration))
+        .unwrap_or_default();
+    format!("<td class=\"duration\">{}</td>", duration)
+}
+
+/// Cell with the duration of a part, and its peak memory below it if memory was tracked
+fn usage_cell(duration: Option<Duration>, memory: Option<usize>) -> String {
+    let mut usage = duration
+        .map(|duration| format!("{:.2?}", duration))
+        .unwrap_or_default();
+    if let Some(memory) = memory {
+        write!(usage, "<br>{}", aoc::memory::format_bytes(memory)).unwrap();
+    }
+    format!("<td class=\"duration\">{}</td>", usage)
+}
+
+/// Width of the bar of `duration` in the chart, in percent, on a logarithmic scale from CHART_MIN to `max`
+fn bar_width(duration: Duration, max: Duration) -> f64 {
+    let scale = |duration: Duration| {
+        (duration.max(CHART_MIN).as_secs_f64() / CHART_MIN.as_secs_f64()).log10()
+    };
+    match scale(max) {
+        max if max > 0.0 => 100.0 * scale(duration) / max,
+        _ => 0.0,
+    }
+}
+
+/// Module path of the Solution of `test_result`, which prefixes the target of its debug output
+fn module_path(test_result: &TestResult) -> String {
+    format!(
+        "{}::y{}::d{:02}",
+        env!("CARGO_CRATE_NAME"),
+        test_result.year,
+        test_result.day
+    )
+}
+
+/// Collapsible debug output, or nothing if there is none
+fn write_debug_output(out: &mut String, title: &str, captured: &[&Captured]) {
+    if captured.is_empty() {
+        return;
+    }
+    writeln!(
+        out,
+        "<details><summary>{} ({} messages)</summary><pre>",
+        escape(title),
+        captured.len()
+    )
+    .unwrap();
+    for message in captured {
+        writeln!(
+            out,
+            "{:>5} {}: {}",
+            message.level,
+            escape(&message.target),
+            escape(&message.message)
+        )
+        .unwrap();
+    }
+    out.push_str("</pre></details>\n");
+}
+
+/// Render the results of a run that took `elapsed` as a self-contained HTML document, with the `captured` debug
+/// output of every day
+pub fn html(test_results: &[TestResult], elapsed: Duration, captured: &[Captured]) -> String {
+    let mut out = String::new();
+    out.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    out.push_str("<title>Advent of Code results</title>\n");
+    writeln!(out, "<style>{}</style>\n</head>\n<body>", STYLE).unwrap();
+    writeln!(
+        out,
+        "<h1>Advent of Code results</h1>\n<p>Ran {} AoC solutions in {:.2?}.</p>",
+        test_results.len(),
+        elapsed
+    )
+    .unwrap();
+
+    out.push_str("<h2>Results</h2>\n<table>\n<tr><th>Day</th><th>Examples</th><th>Parse</th>");
+    out.push_str("<th>Part 1</th><th></th><th>Part 2</th><th></th></tr>\n");
+    for test_result in test_results {
+        writeln!(
+            out,
+            "<tr><td>{}-{:02}</td>{}{}{}{}{}{}</tr>",
+            test_result.year,
+            test_result.day,
+            status_cell(&test_result.examples),
+            duration_cell(test_result.parse),
+            status_cell(&test_result.p1),
+            usage_cell(test_result.p1.duration(), test_result.p1_memory),
+            status_cell(&test_result.p2),
+            usage_cell(test_result.p2.duration(), test_result.p2_memory),
+        )
+        .unwrap();
+    }
+    out.push_str("</table>\n");
+
+    let max = test_results
+        .iter()
+        .flat_map(|test_result| [test_result.p1.duration(), test_result.p2.duration()])
+        .flatten()
+        .max()
+        .unwrap_or_default();
+    writeln!(
+        out,
+        "<h2>Timings</h2>\n<p>Part 1 and part 2 on a logarithmic scale, from {:?} to {:.2?}.</p>",
+        CHART_MIN, max
+    )
+    .unwrap();
+    out.push_str("<div class=\"chart\">\n");
+    for test_result in test_results {
+        writeln!(
+            out,
+            "<span>{}-{:02}</span><div>",
+            test_result.year, test_result.day
+        )
+        .unwrap();
+        for (class, status) in [("p1", &test_result.p1), ("p2", &test_result.p2)] {
+            let (width, title) = match status.duration() {
+                Some(duration) => (bar_width(duration, max), format!("{:.2?}", duration)),
+                None => (0.0, status.name().to_string()),
+            };
+            writeln!(
+                out,
+                "<div class=\"bar {}\" style=\"width: {:.1}%\" title=\"{}\"></div>",
+                class, width, title
+            )
+            .unwrap();
+        }
+        out.push_str("</div>\n");
+    }
+    out.push_str("</div>\n");
+
+    if !captured.is_empty() {
+        out.push_str("<h2>Debug output</h2>\n");
+        let mut shown = vec![false; captured.len()];
+        for test_result in test_results {
+            let prefix = module_path(test_result);
+            let day: Vec<&Captured> = captured
+                .iter()
+                .zip(shown.iter_mut())
+                .filter(|(message, _)| message.target.starts_with(&prefix))
+                .map(|(message, shown)| {
+                    *shown = true;
+                    message
+                })
+                .collect();
+            let title = format!("{}-{:02}", test_result.year, test_result.day);
+            write_debug_output(&mut out, &title, &day);
+        }
+        let other: Vec<&Captured> = captured
+            .iter()
+            .zip(shown)
+            .filter(|(_, shown)| !shown)
+            .map(|(message, _)| message)
+            .collect();
+        write_debug_output(&mut out, "Other", &other);
+    }
+    out.push_str("</body>\n</html>");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_html() {
+        test!(
+            "&lt;a href=&quot;x&quot;&gt; &amp;",
+            escape("<a href=\"x\"> &")
+        );
+        test!(
+            50.0,
+            bar_width(Duration::from_millis(1), Duration::from_secs(1))
+        );
+        let test_results = [TestResult {
+            year: 2023,
+            day: 22,
+            parse: None,
+            p1: TestStatus::Success(Duration::from_millis(1), Answer::Int(5)),
+            p2: TestStatus::Cached(Answer::Int(7)),
+            examples: TestStatus::Unknown,
+            p1_memory: Some(1536),
+            p2_memory: None,
+        }];
+        let captured = |target: String| Captured {
+            target,
+            level: tracing::Level::DEBUG,
+            message: "<brick>".to_string(),
+        };
+        let html = html(
+            &test_results,
+            Duration::from_secs(1),
+            &[
+                captured(module_path(&test_results[0])),
+                captured("aoc".to_string()),
+            ],
+        );
+        test!(html.contains("<td class=\"Success\">Success 5</td>"));
+        test!(html.contains("<td class=\"Cached\">Cached 7</td>"));
+        test!(html.contains("<td class=\"duration\">1.00ms<br>1.50 KiB</td>"));
+        test!(html.contains("<summary>2023-22 (1 messages)</summary>"));
+        test!(html.contains("<summary>Other (1 messages)</summary>"));
+        test!(!html.contains("<brick>"));
+    }
+
+    #[test]
+    fn test_events() {
+        let test_result = TestResult {
+            year: 2023,
+            day: 22,
+            parse: None,
+            p1: TestStatus::Unknown,
+            p2: TestStatus::Success(Duration::from_millis(1500), Answer::Int(5)),
+            examples: TestStatus::Unknown,
+            p1_memory: None,
+            p2_memory: Some(2048),
+        };
+        test!(
+            r#"{"event":"part_finished","year":2023,"day":22,"part":2,"status":"Success","answer":"5","seconds":1.5,"memory_bytes":2048}"#,
+            serde_json::to_string(&Event::part_finished(&test_result, Part::Two)).unwrap()
+        );
+        let fetch = Event::FetchStarted {
+            year: 2023,
+            day: 22,
+        };
+        test!(
+            r#"{"event":"fetch_started","year":2023,"day":22}"#,
+            serde_json::to_string(&fetch).unwrap()
+        );
+    }
+}
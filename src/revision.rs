@@ -0,0 +1,121 @@
+// This module benchmarks another git revision of this repository in a temporary worktree, and compares it with the
+// working tree, so an optimization can be validated against the revision it started from without switching branches
+
+use crate::bench;
+use crate::compare;
+use aoc::runner::ExitStatus;
+use aoc::solution::*;
+use aoc::Inputs;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Export of the benchmark of the other revision, the baseline of `bench diff --against-git`
+pub const REVISION_PATH: &str = ".aoc/bench/revision.json";
+
+/// Export of the benchmark of the working tree, the candidate of `bench diff --against-git`
+pub const WORKING_TREE_PATH: &str = ".aoc/bench/working-tree.json";
+
+/// Target directory of the builds of other revisions, which is kept between runs, so only the crates that changed
+/// are built again
+pub const TARGET_PATH: &str = "target/revision";
+
+/// Run git with `args` in `directory`, and return its stdout, or its stderr if it fails
+fn git(directory: &Path, args: &[&str]) -> Result<String, String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(directory)
+        .output()
+        .map_err(|error| format!("Failed to run git: {}.", error))?;
+    match output.status.success() {
+        true => Ok(String::from_utf8_lossy(&output.stdout).trim().to_string()),
+        false => Err(format!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        )),
+    }
+}
+
+/// Benchmark the solutions that the flags in `selection` select at `revision` in a temporary worktree, with the puzzle
+/// inputs of this working tree, and write the export to `export`. The worktree is removed afterwards, also if the
+/// benchmark fails.
+pub fn bench_revision(
+    revision: &str,
+    selection: &[String],
+    inputs: &Inputs,
+    samples: usize,
+    export: &Path,
+) -> Result<(), String> {
+    let absolute = |path: &Path| {
+        std::path::absolute(path)
+            .map_err(|error| format!("Invalid path '{}': {}.", path.display(), error))
+    };
+    let root = PathBuf::from(git(Path::new("."), &["rev-parse", "--show-toplevel"])?);
+    let worktree = std::env::temp_dir().join(format!("aoc-bench-{}", std::process::id()));
+    let worktree_arg = worktree.to_string_lossy().to_string();
+    git(
+        &root,
+        &["worktree", "add", "--detach", &worktree_arg, revision],
+    )?;
+    // Cargo.lock is not committed, build the other revision with the same versions of the dependencies
+    let _ = fs::copy(root.join("Cargo.lock"), worktree.join("Cargo.lock"));
+    println!("Benchmarking {} in '{}'.", revision, worktree.display());
+    let benchmarked = (|| {
+        if let Some(directory) = export.parent() {
+            fs::create_dir_all(directory).map_err(|error| {
+                format!("Failed to create '{}': {}.", directory.display(), error)
+            })?;
+        }
+        let status = Command::new("cargo")
+            .args(["run", "--release", "--", "bench", "--samples"])
+            .arg(samples.to_string())
+            .arg("--export")
+            .arg(absolute(export)?)
+            .args(selection)
+            .arg("--session-file")
+            .arg(absolute(&inputs.session_path)?)
+            .arg("--cache-dir")
+            .arg(absolute(&inputs.cache_directory)?)
+            .env("CARGO_TARGET_DIR", root.join(TARGET_PATH))
+            .current_dir(&worktree)
+            .status()
+            .map_err(|error| format!("Failed to run cargo: {}.", error))?;
+        match status.success() {
+            true => Ok(()),
+            false => Err(format!("Benchmarking {} failed: {}.", revision, status)),
+        }
+    })();
+    let removed = git(&root, &["worktree", "remove", "--force", &worktree_arg]);
+    benchmarked.and(removed.map(|_| ()))
+}
+
+/// Benchmark the `solutions` in the working tree, and the solutions that the flags in `selection` select at
+/// `revision`, and print the comparison table of `compare`. The latest benchmark of `bench` is left as it is.
+pub fn diff(
+    revision: &str,
+    solutions: &[SolutionBox],
+    selection: &[String],
+    inputs: &Inputs,
+    samples: usize,
+    threshold: f64,
+    force: bool,
+) -> ExitStatus {
+    if solutions.is_empty() {
+        eprintln!("No solutions are selected to benchmark.");
+        return ExitStatus::Error;
+    }
+    let baseline = Path::new(REVISION_PATH);
+    if let Err(error) = bench_revision(revision, selection, inputs, samples, baseline) {
+        eprintln!("{}", error);
+        return ExitStatus::Error;
+    }
+    println!("Benchmarking the working tree.");
+    let candidate = Path::new(WORKING_TREE_PATH);
+    let status = bench::run(solutions, inputs, samples, candidate, None);
+    if status != ExitStatus::Success {
+        return status;
+    }
+    println!();
+    compare::compare(baseline, candidate, threshold, force)
+}
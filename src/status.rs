@@ -0,0 +1,158 @@
+// This module renders a matrix of the years and days, of which every cell shows how far a day is: whether it is
+// implemented, has examples, has expected answers for the real input, and how many stars it got on the website
+
+use aoc::answers::Answers;
+use aoc::runner::{ExitStatus, Part, Selection};
+use aoc::solution::*;
+use aoc::Inputs;
+use std::collections::BTreeSet;
+
+/// Number of days of every year
+pub const DAYS: Day = 25;
+
+/// How far a day is, all of it is known without running or downloading anything
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DayStatus {
+    pub implemented: bool,
+    /// Number of examples that the Solution defines
+    pub examples: usize,
+    /// Number of parts that have an expected answer in answers.toml for the cached input
+    pub verified: usize,
+    /// Stars on adventofcode.com, if aocf cached them
+    pub stars: Option<u8>,
+}
+
+impl DayStatus {
+    /// The DayStatus of AoC(`year`, `day`), of which `solution` is the Solution if it is implemented
+    pub fn of(
+        year: Year,
+        day: Day,
+        solution: Option<&SolutionBox>,
+        inputs: &Inputs,
+        answers: &Answers,
+    ) -> DayStatus {
+        let verified = inputs.cached(year, day).map_or(0, |input| {
+            [Part::One, Part::Two]
+                .into_iter()
+                .filter(|&part| answers.get(year, day, part, &input).is_some())
+                .count()
+        });
+        DayStatus {
+            implemented: solution.is_some(),
+            examples: solution.map_or(0, |solution| solution.define_examples().len()),
+            verified,
+            stars: inputs.stars(year, day),
+        }
+    }
+}
+
+/// The rows of the matrix of `year`, of which `days` are the DayStatus of day 1 to DAYS, a `.` is something that is
+/// missing, and a `?` is unknown
+pub fn render(year: Year, days: &[DayStatus]) -> String {
+    let row = |name: &str, cell: &dyn Fn(&DayStatus) -> String| {
+        let cells: String = days.iter().map(|day| format!("{:>3}", cell(day))).collect();
+        format!("{:<10}{}", name, cells)
+    };
+    let count = |count: usize| match count {
+        0 => ".".to_string(),
+        count => count.to_string(),
+    };
+    [
+        format!(
+            "{:<10}{}",
+            year,
+            (1..=days.len())
+                .map(|day| format!("{:>3}", day))
+                .collect::<String>()
+        ),
+        row("Solved", &|day| match day.implemented {
+            true => "x".to_string(),
+            false => ".".to_string(),
+        }),
+        row("Examples", &|day| count(day.examples)),
+        row("Verified", &|day| count(day.verified)),
+        row("Stars", &|day| match day.stars {
+            None => "?".to_string(),
+            Some(0) => ".".to_string(),
+            Some(stars) => "*".repeat(stars as usize),
+        }),
+    ]
+    .join("\n")
+}
+
+/// Print the matrix of every year in `years`, or of every year with a Solution or a cached input if None
+pub fn status(
+    solutions: &[SolutionBox],
+    inputs: &Inputs,
+    answers: &Answers,
+    years: Option<&Selection<Year>>,
+) -> ExitStatus {
+    let all: BTreeSet<Year> = solutions
+        .iter()
+        .map(|solution| solution.year())
+        .chain(inputs.cached_days().into_iter().map(|(year, _)| year))
+        .collect();
+    let years: Vec<Year> = all
+        .into_iter()
+        .filter(|year| years.is_none_or(|years| years.contains(year)))
+        .collect();
+    if years.is_empty() {
+        eprintln!("There are no solutions or cached inputs of the selected years.");
+        return ExitStatus::Error;
+    }
+    for (i, &year) in years.iter().enumerate() {
+        let days: Vec<DayStatus> = (1..=DAYS)
+            .map(|day| {
+                let solution = solutions
+                    .iter()
+                    .find(|solution| (solution.year(), solution.day()) == (year, day));
+                DayStatus::of(year, day, solution, inputs, answers)
+            })
+            .collect();
+        if i > 0 {
+            println!();
+        }
+        println!("{}", render(year, &days));
+        let solved = days.iter().filter(|day| day.implemented).count();
+        let stars: usize = days
+            .iter()
+            .filter_map(|day| day.stars)
+            .map(usize::from)
+            .sum();
+        println!("{} of {} days solved, {} stars.", solved, DAYS, stars);
+    }
+    ExitStatus::Success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_render() {
+        let days = [
+            DayStatus {
+                implemented: true,
+                examples: 2,
+                verified: 2,
+                stars: Some(2),
+            },
+            DayStatus {
+                implemented: true,
+                examples: 1,
+                verified: 1,
+                stars: Some(1),
+            },
+            DayStatus::default(),
+        ];
+        test!(
+            "2023        1  2  3\n\
+             Solved      x  x  .\n\
+             Examples    2  1  .\n\
+             Verified    2  1  .\n\
+             Stars      **  *  ?",
+            render(2023, &days)
+        );
+    }
+}
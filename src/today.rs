@@ -0,0 +1,300 @@
+// This module notices when the puzzle of today is unlocked but not solved yet, and scaffolds its module from a
+// template, registers it in the module of its year, and downloads its input, so a new day starts with one command
+
+use aoc::runner::ExitStatus;
+use aoc::solution::*;
+use aoc::Inputs;
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Directory of the modules of every year, like `src/y2023`
+pub const SOURCE_DIRECTORY: &str = "src";
+
+/// Puzzles unlock at midnight in the timezone of adventofcode.com, EST, which is UTC-5
+const UNLOCK_OFFSET: i64 = -5 * 60 * 60;
+
+/// Last day of Advent of Code
+const LAST_DAY: Day = 25;
+
+/// The (year, month, day) of the `days` since 1970-01-01, in the proleptic Gregorian calendar
+fn date(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    // Months start in March, so the leap day is the last day of the year
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    (
+        year_of_era + era * 400 + (month <= 2) as i64,
+        month as u32,
+        day as u32,
+    )
+}
+
+/// The puzzle that unlocked last at `now`, if `now` is during an Advent of Code
+pub fn unlocked(now: SystemTime) -> Option<(Year, Day)> {
+    let seconds = match now.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(error) => -(error.duration().as_secs() as i64),
+    };
+    let (year, month, day) = date((seconds + UNLOCK_OFFSET).div_euclid(24 * 60 * 60));
+    (month == 12 && day <= LAST_DAY).then_some((year as Year, day))
+}
+
+/// Source of the module of a new day, of which both parts still have to be solved
+pub fn template(year: Year, day: Day) -> String {
+    format!(
+        "use crate::*;\n\
+         \n\
+         #[derive(Default)]\n\
+         pub struct Problem {{}}\n\
+         \n\
+         impl Solution for Problem {{\n    \
+             no_parse_step!();\n\
+         \n    \
+             fn year(&self) -> Year {{\n        \
+                 {year}\n    \
+             }}\n    \
+             fn day(&self) -> Day {{\n        \
+                 {day}\n    \
+             }}\n\
+         \n    \
+             fn solve_part_one(&self, _input: &String, _params: &Params) -> AocResult<Answer> {{\n        \
+                 Ok(0.into())\n    \
+             }}\n\
+         \n    \
+             fn solve_part_two(&self, _input: &String, _params: &Params) -> AocResult<Answer> {{\n        \
+                 Ok(0.into())\n    \
+             }}\n\
+         }}\n"
+    )
+}
+
+/// Maximum width of a line of the module of a year, like rustfmt
+const MAX_WIDTH: usize = 100;
+
+/// Names of the modules in the comma separated `list`, like `d01, d02`
+fn modules(list: &str) -> Vec<&str> {
+    list.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// The `modules` after `prefix`, separated by commas and wrapped at MAX_WIDTH, every line after the first starts with
+/// `indent`
+fn wrapped(prefix: &str, modules: &[String], indent: &str) -> String {
+    let mut lines = vec![prefix.to_string()];
+    for (i, module) in modules.iter().enumerate() {
+        let item = match i + 1 < modules.len() {
+            true => format!("{},", module),
+            false => module.clone(),
+        };
+        let line = lines.last_mut().unwrap();
+        if line.trim().is_empty() {
+            line.push_str(&item);
+        } else if line.len() + 1 + item.len() > MAX_WIDTH {
+            lines.push(format!("{}{}", indent, item));
+        } else {
+            line.push(' ');
+            line.push_str(&item);
+        }
+    }
+    lines.join("\n")
+}
+
+/// Source of `module`, the module of a year, in which `day` is moved from the unsolved days to the registered days
+/// of its `days!` invocation, so it is declared and registered in `solutions` and `sources`. Returns None if the module
+/// has no `days!` invocation.
+pub fn register(module: &str, day: Day) -> Option<String> {
+    let invocation = "days!(";
+    let start = module.find(invocation)? + invocation.len();
+    let end = start + module[start..].find(')')?;
+    let (registered, unsolved) = module[start..end].split_once(';')?;
+    let unsolved = unsolved.trim().strip_prefix("unsolved:")?;
+    let name = format!("d{:02}", day);
+    let mut registered: Vec<String> = modules(registered)
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    registered.push(name.clone());
+    registered.sort();
+    registered.dedup();
+    let unsolved: Vec<String> = modules(unsolved)
+        .into_iter()
+        .filter(|&module| module != name)
+        .map(str::to_string)
+        .collect();
+    let indent = "    ";
+    Some(format!(
+        "{}\n{};\n{}\n{}",
+        &module[..start],
+        wrapped(indent, &registered, indent),
+        wrapped(&format!("{}unsolved:", indent), &unsolved, indent),
+        &module[end..]
+    ))
+}
+
+/// Write the module of AoC(`year`, `day`) in `directory` from its template, and register it in the module of its
+/// year, a module that already has a Solution is left alone
+pub fn scaffold(directory: &Path, year: Year, day: Day) -> Result<PathBuf, String> {
+    let year_directory = directory.join(format!("y{}", year));
+    let module_path = year_directory.join("mod.rs");
+    let module = fs::read_to_string(&module_path).map_err(|error| {
+        format!(
+            "There is no module of {} to register {}-{:02} in, '{}': {}.",
+            year,
+            year,
+            day,
+            module_path.display(),
+            error
+        )
+    })?;
+    let path = year_directory.join(format!("d{:02}.rs", day));
+    let existing = fs::read_to_string(&path).unwrap_or_default();
+    if !existing.trim().is_empty() {
+        return Err(format!(
+            "'{}' is not empty, it is left alone.",
+            path.display()
+        ));
+    }
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents)
+            .map_err(|error| format!("Failed to write '{}': {}.", path.display(), error))
+    };
+    let registered = register(&module, day).ok_or_else(|| {
+        format!(
+            "There is no `days!` invocation in '{}' to register {}-{:02} in.",
+            module_path.display(),
+            year,
+            day
+        )
+    })?;
+    write(&path, &template(year, day))?;
+    write(&module_path, &registered)?;
+    Ok(path)
+}
+
+/// Ask on stdin whether to go ahead with `question`, only a `y` or `yes` goes ahead
+fn confirm(question: &str) -> bool {
+    print!("{} [y/N] ", question);
+    let _ = io::stdout().flush();
+    let mut answer = String::new();
+    if io::stdin().lock().read_line(&mut answer).is_err() {
+        return false;
+    }
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+/// If the puzzle that unlocked last at `now` has none of the `solutions`, offer to scaffold it and download its
+/// input, without asking if `yes`. Returns None if there is nothing to do, the offer was declined, or its year has no
+/// module to register it in yet, so the run can go on as usual.
+pub fn offer(
+    solutions: &[SolutionBox],
+    inputs: &Inputs,
+    now: SystemTime,
+    yes: bool,
+) -> Option<ExitStatus> {
+    let (year, day) = unlocked(now)?;
+    if solutions
+        .iter()
+        .any(|solution| (solution.year(), solution.day()) == (year, day))
+    {
+        return None;
+    }
+    let module_path = Path::new(SOURCE_DIRECTORY)
+        .join(format!("y{}", year))
+        .join("mod.rs");
+    if !module_path.exists() {
+        println!(
+            "AoC {}-{:02} is unlocked, add the module of {} in '{}' to scaffold it.",
+            year,
+            day,
+            year,
+            module_path.display()
+        );
+        return None;
+    }
+    let question = format!(
+        "AoC {}-{:02} is unlocked, scaffold it and download its input?",
+        year, day
+    );
+    if !yes && !io::stdin().is_terminal() {
+        println!(
+            "AoC {}-{:02} is unlocked, run with `--yes` to scaffold it.",
+            year, day
+        );
+        return None;
+    }
+    if !yes && !confirm(&question) {
+        return None;
+    }
+    let path = match scaffold(Path::new(SOURCE_DIRECTORY), year, day) {
+        Ok(path) => path,
+        Err(error) => {
+            eprintln!("{}", error);
+            return Some(ExitStatus::Error);
+        }
+    };
+    println!("Scaffolded '{}'.", path.display());
+    if let Err(error) = inputs.get(year, day) {
+        eprintln!(
+            "Failed to download the input of {}-{:02}. {}",
+            year, day, error
+        );
+        return Some(ExitStatus::Error);
+    }
+    println!(
+        "Downloaded the input of {}-{:02}, solve it with `cargo run -- --days {}`.",
+        year, day, day
+    );
+    Some(ExitStatus::Success)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::time::Duration;
+
+    #[test]
+    fn test_unlocked() {
+        // 2023-12-01 00:00 UTC, 2023-11-30 19:00 EST
+        let midnight = UNIX_EPOCH + Duration::from_secs(1_701_388_800);
+        let unlock = midnight + Duration::from_secs(5 * 60 * 60);
+        let day = Duration::from_secs(24 * 60 * 60);
+        test!(unlocked(midnight).is_none());
+        test!(unlocked(unlock - Duration::from_secs(1)).is_none());
+        test!(Some((2023, 1)), unlocked(unlock));
+        test!(Some((2023, 25)), unlocked(unlock + day * 24));
+        test!(unlocked(unlock + day * 25).is_none());
+        test!((2024, 2, 29), date(19_782));
+    }
+
+    #[test]
+    fn test_register() {
+        let module = "crate::days!(\n    \
+                          d01, d03;\n    \
+                          unsolved: d02, d04\n\
+                      );\n";
+        let registered = register(module, 2).unwrap();
+        test!(
+            "crate::days!(\n    d01, d02, d03;\n    unsolved: d04\n);\n",
+            &registered
+        );
+        test!(&registered, &register(&registered, 2).unwrap());
+        test!(register("pub mod d01;\n", 2).is_none());
+        let days: Vec<String> = (1..=25).map(|day| format!("d{:02}", day)).collect();
+        let unsolved = wrapped("    unsolved:", &days, "    ");
+        test!(unsolved.starts_with("    unsolved: d01, d02,"));
+        test!(unsolved.lines().all(|line| line.len() <= MAX_WIDTH));
+        test!(2, unsolved.lines().count());
+        test!("    unsolved:", &wrapped("    unsolved:", &[], "    "));
+        test!(template(2023, 17).contains("fn day(&self) -> Day {\n        17\n    }"));
+    }
+}
@@ -0,0 +1,187 @@
+#![allow(dead_code)]
+// This module verifies the expected answers of solutions against the answers that adventofcode.com
+// confirmed, so hardcoded expectations that drift from reality are flagged
+
+use aoc::answers::Answers;
+use aoc::runner::{ExitStatus, Part};
+use aoc::solution::*;
+use aoc::Inputs;
+use colored::Colorize;
+use std::thread;
+
+/// Identifies this project to adventofcode.com, as requested by its maintainer
+const USER_AGENT: &str = "github.com/jortrr/advent-of-code";
+
+/// Pause between requests, to go easy on adventofcode.com
+const THROTTLE: Duration = Duration::from_secs(1);
+
+/// Answers that the puzzle page confirms, in part order, a part that is not solved yet has none
+pub fn parse_confirmed_answers(html: &str) -> Vec<String> {
+    const PREFIX: &str = "Your puzzle answer was <code>";
+    html.match_indices(PREFIX)
+        .filter_map(|(start, _)| {
+            let answer = &html[start + PREFIX.len()..];
+            answer.find("</code>").map(|end| answer[..end].to_string())
+        })
+        .collect()
+}
+
+/// Download the puzzle page of AoC(`year`, `day`), and return its confirmed answers
+fn fetch_confirmed_answers(year: Year, day: Day, cookie: &str) -> AocResult<Vec<String>> {
+    let network = |message: String| AocError::Network { year, day, message };
+    let url = format!("https://adventofcode.com/{}/day/{}", year, day);
+    let html = ureq::get(&url)
+        .set("Cookie", &format!("session={}", cookie))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .map_err(|error| network(error.to_string()))?
+        .into_string()
+        .map_err(|error| network(error.to_string()))?;
+    Ok(parse_confirmed_answers(&html))
+}
+
+/// Outcome of comparing the expected answer of a part with its confirmed answer
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Verified,
+    Drifted {
+        confirmed: String,
+    },
+    /// There is a confirmed answer, but no expected answer yet
+    Missing {
+        confirmed: String,
+    },
+    Unconfirmed,
+}
+
+impl Verdict {
+    pub fn of(expected: Option<&Answer>, confirmed: Option<&String>) -> Verdict {
+        match (expected, confirmed) {
+            (Some(expected), Some(confirmed)) if *confirmed == expected.to_string() => {
+                Verdict::Verified
+            }
+            (Some(_), Some(confirmed)) => Verdict::Drifted {
+                confirmed: confirmed.clone(),
+            },
+            (None, Some(confirmed)) => Verdict::Missing {
+                confirmed: confirmed.clone(),
+            },
+            (_, None) => Verdict::Unconfirmed,
+        }
+    }
+}
+
+/// Answer of a confirmed answer, which is a number if it looks like one
+fn to_answer(confirmed: &str) -> Answer {
+    match confirmed.parse::<Int>() {
+        Ok(int) => int.into(),
+        Err(_) => confirmed.into(),
+    }
+}
+
+/// Cross-check the expected answers of `solutions` with adventofcode.com, and print a line per part.
+///
+/// Confirmed answers that are missing from `answers` are added to it. Returns `ExitStatus::WrongAnswer`
+/// if any expectation drifted.
+pub fn verify(solutions: &[SolutionBox], inputs: &Inputs, answers: &mut Answers) -> ExitStatus {
+    let cookie = match inputs.session_cookie() {
+        Ok(cookie) => cookie,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitStatus::Error;
+        }
+    };
+    let mut status = ExitStatus::Success;
+    for (i, solution) in solutions.iter().enumerate() {
+        if i > 0 {
+            thread::sleep(THROTTLE);
+        }
+        let (year, day) = (solution.year(), solution.day());
+        let fetched = inputs.get(year, day).and_then(|input| {
+            fetch_confirmed_answers(year, day, &cookie).map(|confirmed| (input, confirmed))
+        });
+        let (input, confirmed) = match fetched {
+            Ok(fetched) => fetched,
+            Err(error) => {
+                eprintln!("{}", error);
+                status = status.max(ExitStatus::Error);
+                continue;
+            }
+        };
+        for (i, part) in [Part::One, Part::Two].into_iter().enumerate() {
+            let prefix = format!("{}-{:02} [P{}]", year, day, part.number());
+            let expected = answers.get(year, day, part, &input);
+            match Verdict::of(expected, confirmed.get(i)) {
+                Verdict::Verified => {
+                    println!("{} {} {}", prefix, "Verified".green(), expected.unwrap())
+                }
+                Verdict::Drifted { confirmed } => {
+                    println!(
+                        "{} {} expected {}, but adventofcode.com confirmed {}",
+                        prefix,
+                        "Drifted".red(),
+                        expected.unwrap(),
+                        confirmed
+                    );
+                    status = status.max(ExitStatus::WrongAnswer);
+                }
+                Verdict::Missing { confirmed } => {
+                    println!("{} {} {}", prefix, "Added".green(), confirmed);
+                    answers.insert(year, day, part, &input, to_answer(&confirmed));
+                }
+                Verdict::Unconfirmed => println!("{} {}", prefix, "Unconfirmed".yellow()),
+            }
+        }
+    }
+    if let Err(error) = answers.save() {
+        eprintln!("Failed to save the expected answers. {}", error);
+        status = status.max(ExitStatus::Error);
+    }
+    status
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[test]
+    fn test_parse_confirmed_answers() {
+        let solved = r#"<article class="day-desc"><h2>--- Day 5 ---</h2></article>
+            <p>Your puzzle answer was <code>388071289</code>.</p>
+            <article class="day-desc"><h2 id="part2">--- Part Two ---</h2></article>
+            <p>Your puzzle answer was <code>84206669</code>.</p>"#;
+        test!(
+            vec!["388071289", "84206669"],
+            parse_confirmed_answers(solved)
+        );
+        let half_solved = r#"<p>Your puzzle answer was <code>EFEKZRJB</code>.</p>
+            <p>The first half of this puzzle is complete! It provides one gold star: *</p>"#;
+        test!(vec!["EFEKZRJB"], parse_confirmed_answers(half_solved));
+        test!(parse_confirmed_answers("<p>To play, please identify yourself</p>").is_empty());
+    }
+
+    #[test]
+    fn test_verdict() {
+        let confirmed = "42".to_string();
+        test!(
+            Verdict::Verified,
+            Verdict::of(Some(&42.into()), Some(&confirmed))
+        );
+        test!(
+            Verdict::Drifted {
+                confirmed: "42".to_string()
+            },
+            Verdict::of(Some(&41.into()), Some(&confirmed))
+        );
+        test!(
+            Verdict::Missing {
+                confirmed: "42".to_string()
+            },
+            Verdict::of(None, Some(&confirmed))
+        );
+        test!(Verdict::Unconfirmed, Verdict::of(Some(&42.into()), None));
+        test!(Answer::Int(42), to_answer("42"));
+        test!(Answer::from("EFEKZRJB"), to_answer("EFEKZRJB"));
+    }
+}
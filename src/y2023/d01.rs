@@ -11,6 +11,8 @@ fn get_calibration_value(input: &String) -> Int {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
@@ -19,23 +21,19 @@ impl Solution for Problem {
         1
     }
 
-    fn expect_part_one(&self) -> Answer {
-        55386
-    }
-
-    fn expect_part_two(&self) -> Answer {
-        54824
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Strings]
     }
 
-    fn solve_part_one(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_one(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let input: Vec<String> = InputLines::from(input).filter_empty_lines().into();
-        let solution = input.iter().map(|line| get_calibration_value(line)).sum();
-        solution
+        let solution: Int = input.iter().map(|line| get_calibration_value(line)).sum();
+        Ok(solution.into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let input: Vec<String> = InputLines::from(input).filter_empty_lines().into();
-        let solution = input
+        let solution: Int = input
             .iter()
             .map(|line| {
                 line.replace("one", "o1e")
@@ -50,6 +48,6 @@ impl Solution for Problem {
             })
             .map(|line| get_calibration_value(&line))
             .sum();
-        solution
+        Ok(solution.into())
     }
 }
@@ -48,37 +48,36 @@ impl Game {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         2
     }
-    fn expect_part_one(&self) -> Answer {
-        1867
-    }
-    fn expect_part_two(&self) -> Answer {
-        84538
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Parsing]
     }
 
-    fn solve_part_one(&self, input: Input, _is_example: bool) -> Answer {
-        let input: Vec<String> = InputLines::from(input).filter_empty_lines().into();
-        let solution = input
+    fn solve_part_one(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+        let input: Vec<&str> = InputLines::from(input).filter_empty_lines().into();
+        let solution: Int = input
             .into_iter()
             .map(Game::parse)
             .filter(|game| game.max_red <= 12 && game.max_green <= 13 && game.max_blue <= 14)
             .map(|game| game.id)
             .sum();
-        solution
+        Ok(solution.into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
-        let input: Vec<String> = InputLines::from(input).filter_empty_lines().into();
-        let solution = input
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+        let input: Vec<&str> = InputLines::from(input).filter_empty_lines().into();
+        let solution: Int = input
             .into_iter()
             .map(Game::parse)
             .map(|game| game.max_red * game.max_green * game.max_blue)
             .sum();
-        solution
+        Ok(solution.into())
     }
 }
@@ -64,17 +64,16 @@ fn solve(g: Grid<char>, adjacent: Adjacent) -> Int {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         3
     }
-    fn expect_part_one(&self) -> Answer {
-        540131
-    }
-    fn expect_part_two(&self) -> Answer {
-        86879020
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Grid]
     }
 
     define_examples! {
@@ -95,13 +94,13 @@ impl Solution for Problem {
         )
     }
 
-    fn solve_part_one(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_one(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let grid: Grid<char> = InputLines::from(input).into();
-        solve(grid, Adjacent::Any)
+        Ok(solve(grid, Adjacent::Any).into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let grid: Grid<char> = InputLines::from(input).into();
-        solve(grid, Adjacent::Two)
+        Ok(solve(grid, Adjacent::Two).into())
     }
 }
@@ -50,17 +50,16 @@ impl Card {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         4
     }
-    fn expect_part_one(&self) -> Answer {
-        20667
-    }
-    fn expect_part_two(&self) -> Answer {
-        5833065
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Parsing]
     }
 
     define_examples! {
@@ -77,27 +76,28 @@ impl Solution for Problem {
         )
     }
 
-    fn solve_part_one(&self, input: Input, _is_example: bool) -> Answer {
-        let lines: Vec<String> = InputLines::from(input).into();
+    fn solve_part_one(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+        let lines: Vec<&str> = InputLines::from(input).into();
         let cards: Vec<Card> = lines.into_iter().map(Card::parse).collect();
-        let total = cards.iter().map(Card::get_value).sum();
-        total
+        let total: Int = cards.iter().map(Card::get_value).sum();
+        Ok(total.into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
-        let lines: Vec<String> = InputLines::from(input).into();
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+        let lines: Vec<&str> = InputLines::from(input).into();
         let cards: Vec<Card> = lines.into_iter().map(Card::parse).collect();
-        let mut card_count: HashMap<Int, Int> = HashMap::new();
+        // Every card starts out as a single copy
+        let mut card_count: DefaultMap<Int, Int> = DefaultMap::with_default(1);
         for card in &cards {
-            let count = *card_count.entry(card.id).or_insert(1);
+            let count = *card_count.entry(card.id);
             for i in 0..card.get_matches() {
                 let won_card = card.id + i + 1;
                 if won_card as usize <= cards.len() {
-                    *card_count.entry(won_card).or_insert(1) += count;
+                    card_count[won_card] += count;
                 }
             }
         }
-        let total = card_count.values().sum();
-        total
+        let total: Int = card_count.values().sum();
+        Ok(total.into())
     }
 }
@@ -52,13 +52,13 @@ impl Interval {
         self
     }
 
+    /// Split this interval by the source of `t`, and shift the part inside it, which is marked as transformed
     fn apply(&self, t: &Transform) -> Vec<Interval> {
         let mut result: Vec<Interval> = vec![self.clone()];
-        let mut transformed = false;
+        let mut transformed = None;
 
         if self.a >= t.source.a && self.b <= t.source.b {
-            result[0].shift(t.get_shift());
-            transformed = true;
+            transformed = Some(0);
         } else {
             let split_by_source_a = t.source.a > self.a && t.source.a <= self.b;
             let split_by_source_b = t.source.b < self.b && t.source.b >= self.a;
@@ -67,27 +67,20 @@ impl Interval {
                 let mut first = result.pop().unwrap();
                 let second = first.split(t.source.a);
                 result = vec![first, second];
-                transformed = true;
+                transformed = Some(1);
             }
             if split_by_source_b {
                 let mut last = result.pop().unwrap();
-                let second = last.split(t.source.b);
+                let second = last.split(t.source.b + 1);
                 result.push(last);
                 result.push(second);
-                transformed = true;
-            }
-            if split_by_source_a && split_by_source_b {
-                result[1].shift(t.get_shift());
-            } else if split_by_source_a {
-                result[1].shift(t.get_shift());
-            } else if split_by_source_b {
-                result[0].shift(t.get_shift());
+                transformed = transformed.or(Some(0));
             }
         }
-        if transformed {
-            for i in &mut result {
-                i.v = true;
-            }
+        // Only the shifted part is done for this stage, the other parts can still be transformed by another source
+        if let Some(i) = transformed {
+            result[i].shift(t.get_shift());
+            result[i].v = true;
         }
 
         result
@@ -103,8 +96,8 @@ struct Transform {
 impl Transform {
     fn new(destination: Int, source: Int, length: Int) -> Transform {
         Transform {
-            source: Interval::new(source, source + length),
-            destination: Interval::new(destination, destination + length),
+            source: Interval::new(source, source + length - 1),
+            destination: Interval::new(destination, destination + length - 1),
         }
     }
 
@@ -130,24 +123,56 @@ impl Transform {
     }
 }
 
+/// Apply a single stage of the almanac, every interval is transformed at most once
+fn apply_stage(s: Vec<Interval>, transforms: &[Transform]) -> Vec<Interval> {
+    let mut s = s;
+    for t in transforms {
+        let old_s = s.clone();
+        s = t.apply(s);
+        if old_s != s {
+            debug!(true, "({:?},{:?},{:?})", old_s, t, &s);
+        }
+    }
+    for i in &mut s {
+        i.v = false;
+    }
+    s
+}
+
 fn apply_almanac(s: Vec<Interval>, a: &Almanac) -> Vec<Interval> {
     let mut s = s;
     for (i, transforms) in a.iter().enumerate() {
-        for t in transforms {
-            let old_s = s.clone();
-            s = t.apply(s);
-            if old_s != s {
-                debug!(false, "({:?},{:?},{:?})", old_s, t, &s);
-            }
-        }
-        debug!(false, "[{}]: {:?}", i, &s);
-        for i in &mut s {
-            i.v = false;
-        }
+        s = apply_stage(s, transforms);
+        debug!(true, "[{}]: {:?}", i, &s);
     }
     s
 }
 
+/// Number of intervals that are shown of an interval set by `explain`
+const EXPLAINED_INTERVALS: usize = 5;
+
+/// Describe an interval set by its size, its lowest value and its first intervals
+fn describe(s: &[Interval]) -> String {
+    let mut sorted: Vec<&Interval> = s.iter().collect();
+    sorted.sort_by_key(|i| i.a);
+    let shown: Vec<String> = sorted
+        .iter()
+        .take(EXPLAINED_INTERVALS)
+        .map(|i| format!("[{}, {}]", i.a, i.b))
+        .collect();
+    let more = match sorted.len() > EXPLAINED_INTERVALS {
+        true => ", ...",
+        false => "",
+    };
+    format!(
+        "{} intervals, lowest {}: {}{}",
+        s.len(),
+        sorted.first().map_or(0, |i| i.a),
+        shown.join(", "),
+        more
+    )
+}
+
 fn parse(e: &Vec<String>, seeds_is_range: bool) -> (Vec<Interval>, Almanac) {
     let mut s: Vec<Interval> = Vec::new();
     let mut a: Almanac = Vec::new();
@@ -194,74 +219,124 @@ fn parse(e: &Vec<String>, seeds_is_range: bool) -> (Vec<Interval>, Almanac) {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         5
     }
-    fn expect_part_one(&self) -> Answer {
-        251346198
-    }
-    fn expect_part_two(&self) -> Answer {
-        72263011
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Intervals]
     }
 
     define_examples! {
         (
-            "
-            seeds: 79 14 55 13
-
-            seed-to-soil map:
-            50 98 2
-            52 50 48
-
-            soil-to-fertilizer map:
-            0 15 37
-            37 52 2
-            39 0 15
-
-            fertilizer-to-water map:
-            49 53 8
-            0 11 42
-            42 0 7
-            57 7 4
-
-            water-to-light map:
-            88 18 7
-            18 25 70
-
-            light-to-temperature map:
-            45 77 23
-            81 45 19
-            68 64 13
-
-            temperature-to-humidity map:
-            0 69 1
-            1 0 69
-
-            humidity-to-location map:
-            60 56 37
-            56 93 4
-
-            ",
+            example_file!("examples/y2023/d05/ex1.txt"),
             Expect::PartsOneAndTwo(35, 46),
         )
     }
 
-    fn solve_part_one(&self, input: Input, _is_example: bool) -> Answer {
+    fn explain(&self, input: Input) -> Vec<String> {
+        let input = input.lines().map(|s| s.to_string()).collect();
+        let (mut s, a) = parse(&input, true);
+        let mut explanation = vec![format!("Seed ranges: {}", describe(&s))];
+        for (i, transforms) in a.iter().filter(|t| !t.is_empty()).enumerate() {
+            s = apply_stage(s, transforms);
+            explanation.push(format!("After stage {}: {}", i + 1, describe(&s)));
+        }
+        explanation
+    }
+
+    fn solve_part_one(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let input = input.lines().map(|s| s.to_string()).collect(); // Todo: Make Input convertible to Vec<String>, and vice versa
         let (s, a) = parse(&input, false);
         let mut s = apply_almanac(s, &a);
         s.sort_by_key(|i| i.a);
-        s[0].a
+        Ok(s[0].a.into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let input = input.lines().map(|s| s.to_string()).collect();
         let (s, a) = parse(&input, true);
         let mut s = apply_almanac(s, &a);
         s.sort_by_key(|i| i.a);
-        s[0].a
+        Ok(s[0].a.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::differential::Differential;
+    use rand::Rng;
+
+    /// Sorted locations of the seed ranges of `input`, by mapping every seed on its own, without `parse`
+    fn locations_brute_force(input: &str) -> Vec<Int> {
+        let numbers = |line: &str| -> Vec<Int> {
+            line.split_whitespace()
+                .filter_map(|number| number.parse().ok())
+                .collect()
+        };
+        let mut blocks = input.split("\n\n");
+        let seeds = numbers(blocks.next().unwrap());
+        let maps: Vec<Vec<Vec<Int>>> = blocks
+            .map(|block| block.lines().skip(1).map(numbers).collect())
+            .collect();
+        let location = |seed: Int| {
+            maps.iter().fold(seed, |value, map| {
+                map.iter()
+                    .find(|t| (t[1]..t[1] + t[2]).contains(&value))
+                    .map_or(value, |t| value - t[1] + t[0])
+            })
+        };
+        let seeds = seeds
+            .chunks(2)
+            .flat_map(|range| range[0]..range[0] + range[1]);
+        let mut locations: Vec<Int> = seeds.map(location).collect();
+        locations.sort();
+        locations
+    }
+
+    /// Sorted locations of the seed ranges of `input`, by mapping the seed ranges with `apply_almanac`
+    fn locations(input: &str) -> Vec<Int> {
+        let (seeds, almanac) = parse(&input.lines().map(String::from).collect(), true);
+        let mut locations: Vec<Int> = apply_almanac(seeds, &almanac)
+            .iter()
+            .flat_map(|i| i.a..=i.b)
+            .collect();
+        locations.sort();
+        locations
+    }
+
+    /// An almanac with small numbers, of which the sources of a map do not overlap
+    fn generate_almanac(rng: &mut rand::rngs::StdRng) -> String {
+        let seeds: Vec<String> = (0..rng.gen_range(1..4))
+            .map(|_| format!("{} {}", rng.gen_range(0..50), rng.gen_range(1..10)))
+            .collect();
+        let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+        for stage in 0..rng.gen_range(1..5) {
+            almanac += &format!("\nstage-{} map:\n", stage);
+            let mut source = 0;
+            while source < 60 {
+                source += rng.gen_range(0..5);
+                let length = rng.gen_range(1..10);
+                almanac += &format!("{} {} {}\n", rng.gen_range(0..60), source, length);
+                source += length;
+            }
+        }
+        almanac
+    }
+
+    #[test]
+    fn test_intervals_against_brute_force() {
+        let differential = Differential::new(
+            |input: &String| locations_brute_force(input),
+            |input: &String| locations(input),
+        );
+        let example = Problem {}.define_examples()[0].get_input();
+        test!(differential.check(example).is_ok());
+        differential.assert_generated(0, 500, generate_almanac);
     }
 }
@@ -30,17 +30,19 @@ impl Race {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         6
     }
-    fn expect_part_one(&self) -> Answer {
-        160816
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Math]
     }
-    fn expect_part_two(&self) -> Answer {
-        46561107
+    fn cache_version(&self, part: Part) -> Option<&'static str> {
+        (part == Part::Two).then_some("1")
     }
 
     define_examples! {
@@ -53,7 +55,7 @@ impl Solution for Problem {
         )
     }
 
-    fn solve_part_one(&self, input: Input, is_example: bool) -> Answer {
+    fn solve_part_one(&self, input: &String, params: &Params) -> AocResult<Answer> {
         let input: Vec<String> = input.lines().map(|s| s.to_string()).collect();
         let times = input.get(0).unwrap().split_ascii_whitespace().skip(1);
         let distances = input.get(1).unwrap().split_ascii_whitespace().skip(1);
@@ -71,7 +73,7 @@ impl Solution for Problem {
             race.generate_wins();
             races.push(race);
         }
-        debug!(is_example, &races);
+        debug!(params.is_example(), "{:#?}", &races);
         let mut number_of_ways_to_beat_record = 0;
         for race in &races {
             if race.wins.len() > 0 && number_of_ways_to_beat_record == 0 {
@@ -80,10 +82,10 @@ impl Solution for Problem {
             number_of_ways_to_beat_record *= race.wins.len();
         }
 
-        number_of_ways_to_beat_record as Answer
+        Ok((number_of_ways_to_beat_record as Int).into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let input: Vec<String> = input.lines().map(|s| s.to_string()).collect();
         let convert_to_number = |s: &String| -> Uint {
             s.split_ascii_whitespace()
@@ -104,6 +106,6 @@ impl Solution for Problem {
         };
         race.generate_wins();
         let number_of_ways_to_beat_second_record = race.wins.len();
-        number_of_ways_to_beat_second_record as Answer
+        Ok((number_of_ways_to_beat_second_record as Int).into())
     }
 }
@@ -85,23 +85,13 @@ enum CardsType {
 
 impl CardsType {
     fn from_cards(cards: &Cards) -> Option<CardsType> {
-        use std::collections::HashMap;
         use CardsType::*;
-        let mut counts = HashMap::new();
         // Count the occurences of each card
-        cards
-            .iter()
-            .filter(|&card| *card != Card::Joker)
-            .for_each(|card| {
-                *counts.entry(card).or_insert(0) += 1;
-            });
-
-        let mut occurences: Vec<i32> = counts.values().map(|x| *x).collect();
-        occurences.sort();
+        let counts: Counter<&Card> = cards.iter().filter(|&card| *card != Card::Joker).collect();
+        let mut occurences: Vec<Int> = counts.sorted_counts();
 
         //Account for Jokers, by letting Jokers contribute to the best possible CardType
-        let total: i32 = occurences.iter().sum();
-        if (total as usize) < cards.len() {
+        if (counts.total() as usize) < cards.len() {
             // There are Jokers
             match occurences.len() {
                 0 => occurences.push(5),
@@ -241,16 +231,14 @@ impl Play {
 
 type Plays = Vec<Play>;
 
-impl Parse for Plays {
-    fn parse(input: Input) -> Self {
-        input
-            .lines()
-            .map(|s| s.split_ascii_whitespace().collect::<Vec<_>>())
-            .filter(|v| v.len() == 2)
-            .map(|t| (t[0], t[1]))
-            .map(|t| Play::from_tuple(&t))
-            .collect()
-    }
+fn parse_plays(input: Input) -> Plays {
+    input
+        .lines()
+        .map(|s| s.split_ascii_whitespace().collect::<Vec<_>>())
+        .filter(|v| v.len() == 2)
+        .map(|t| (t[0], t[1]))
+        .map(|t| Play::from_tuple(&t))
+        .collect()
 }
 
 fn get_total_winnings(plays: &Plays) -> u32 {
@@ -266,17 +254,16 @@ fn get_total_winnings(plays: &Plays) -> u32 {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         7
     }
-    fn expect_part_one(&self) -> Answer {
-        251806792
-    }
-    fn expect_part_two(&self) -> Answer {
-        252113488
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Sorting]
     }
 
     define_examples! {
@@ -292,15 +279,15 @@ impl Solution for Problem {
         )
     }
 
-    fn solve_part_one(&self, input: Input, _is_example: bool) -> Answer {
-        let mut plays = Plays::parse(input);
+    fn solve_part_one(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+        let mut plays = parse_plays(input);
         plays.sort();
         let total_winnings = get_total_winnings(&plays);
-        total_winnings as Answer
+        Ok((total_winnings as Int).into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
-        let mut plays = Plays::parse(input);
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+        let mut plays = parse_plays(input);
         plays = plays
             .iter()
             .map(|play| Play {
@@ -310,6 +297,6 @@ impl Solution for Problem {
             .collect();
         plays.sort();
         let total_winnings_with_jokers = get_total_winnings(&plays);
-        total_winnings_with_jokers as Answer
+        Ok((total_winnings_with_jokers as Int).into())
     }
 }
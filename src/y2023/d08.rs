@@ -1,4 +1,5 @@
 use crate::*;
+use aoc::simulation::{self, Cycle};
 
 #[derive(Debug)]
 enum Instruction {
@@ -19,62 +20,40 @@ impl Instruction {
 
 type Instructions = Vec<Instruction>;
 
-impl Parse for Instructions {
-    fn parse(input: Input) -> Self {
-        let first_line = input.lines().next().unwrap();
-        first_line
-            .chars()
-            .map(|c| Instruction::from_char(c))
-            .collect()
-    }
+fn parse_instructions(input: Input) -> Instructions {
+    let first_line = input.lines().next().unwrap();
+    first_line
+        .chars()
+        .map(|c| Instruction::from_char(c))
+        .collect()
 }
 
 #[derive(Debug)]
 struct Node {
-    #[allow(dead_code)]
-    from_string: String,
-    label: String,
-    left: String,
-    right: String,
+    label: Symbol,
+    left: Symbol,
+    right: Symbol,
 }
 
 impl Node {
-    fn from_string(string: &str) -> Option<Node> {
+    fn from_string(string: &str, interner: &mut Interner) -> Option<Node> {
         if string.len() != 16 {
             return None;
         }
-        let from_string = string.to_string();
-        let label = string[0..3].to_string();
-        let left = string[7..10].to_string();
-        let right = string[12..15].to_string();
-        Some(Node {
-            from_string,
-            label,
-            left,
-            right,
-        })
+        let label = interner.intern(&string[0..3]);
+        let left = interner.intern(&string[7..10]);
+        let right = interner.intern(&string[12..15]);
+        Some(Node { label, left, right })
     }
 }
 
-// Return the greatest common multiple of a and b
-fn gcd(a: u64, b: u64) -> u64 {
-    if b == 0 {
-        a
-    } else {
-        gcd(b, a % b)
-    }
-}
-
-/// Return the least common multiple of a and b
-fn lcm(a: u64, b: u64) -> u64 {
-    a * b / gcd(a, b)
-}
-
-type StepsFromNode<'a> = (&'a Node, i32);
+type StepsFromNode = (Symbol, i32);
 
 #[derive(Debug)]
 struct Network {
-    nodes: Vec<Node>,
+    interner: Interner,
+    /// Left and right neighbours of every node, indexed by the Symbol of its label
+    neighbours: Vec<(Symbol, Symbol)>,
 }
 
 impl Parse for Network {
@@ -84,77 +63,89 @@ impl Parse for Network {
 }
 
 impl Network {
-    fn find_node_from_string(&self, label: &str) -> Option<&Node> {
-        self.nodes.iter().find(|node| node.label == label)
-    }
-
-    fn find_node_from_string_end(&self, label_end: &str) -> Vec<&Node> {
-        self.nodes
+    fn find_node_from_string_end(&self, label_end: &str) -> Vec<Symbol> {
+        self.interner
             .iter()
-            .filter(|node| node.label.ends_with(label_end))
+            .filter(|(_, label)| label.ends_with(label_end))
+            .map(|(symbol, _)| symbol)
             .collect()
     }
 
     fn camel_steps_until_zzz_is_reached(&self, instructions: &Instructions) -> i32 {
-        let start_node = self.find_node_from_string("AAA").unwrap();
+        let start_node = self.interner.get("AAA").unwrap();
         let end_node_ends_with = "ZZZ";
 
         self.steps_until_zzz_is_reached(instructions, start_node, &end_node_ends_with)
     }
 
-    fn ghost_steps_until_zzz_is_reached(&self, instructions: &Instructions) -> u64 {
+    fn ghost_steps_until_zzz_is_reached(&self, instructions: &Instructions) -> Int {
         let start_nodes = self.find_node_from_string_end("A");
         let end_node_ends_with = "Z";
         //dbg!(&start_nodes);
         let steps_from_nodes: Vec<StepsFromNode> = start_nodes
             .iter()
-            .map(|node| {
+            .map(|&node| {
                 (
-                    *node,
+                    node,
                     self.steps_until_zzz_is_reached(instructions, node, end_node_ends_with),
                 )
             })
             .collect();
         dbg!(&steps_from_nodes);
-        let mut least_common_multiple = 1;
-        for (_, steps) in steps_from_nodes {
-            least_common_multiple = lcm(least_common_multiple, steps as u64);
-        }
-        least_common_multiple
+        // Every ghost reaches its end node after a whole number of the steps it takes to reach it the first time
+        let cycles: Vec<Cycle> = steps_from_nodes
+            .into_iter()
+            .map(|(_, steps)| Cycle {
+                start: steps as Int,
+                length: steps as Int,
+            })
+            .collect();
+        simulation::align(&cycles).unwrap()
     }
 
     fn steps_until_zzz_is_reached(
         &self,
         instructions: &Instructions,
-        start_node: &Node,
+        start_node: Symbol,
         end_node_ends_with: &str,
     ) -> i32 {
+        let is_end: Vec<bool> = self
+            .interner
+            .iter()
+            .map(|(_, label)| label.ends_with(end_node_ends_with))
+            .collect();
         let mut steps: i32 = 0;
-        let mut current_node: &Node = start_node;
+        let mut current_node = start_node;
         loop {
             for instruction in instructions {
-                if current_node.label.ends_with(end_node_ends_with) {
+                if is_end[current_node.index()] {
                     return steps;
                 }
 
-                let new_node = match instruction {
-                    Instruction::Left => &current_node.left,
-                    Instruction::Right => &current_node.right,
+                let (left, right) = self.neighbours[current_node.index()];
+                current_node = match instruction {
+                    Instruction::Left => left,
+                    Instruction::Right => right,
                 };
-
-                current_node = self.find_node_from_string(new_node).unwrap();
                 steps += 1;
             }
         }
     }
 
     fn from_strings(input: &Vec<String>) -> Network {
+        let mut interner = Interner::new();
+        let nodes: Vec<Node> = input
+            .iter()
+            .filter(|line| line.len() == 16)
+            .map(|line| Node::from_string(line, &mut interner).unwrap())
+            .collect();
+        let mut neighbours = vec![(nodes[0].label, nodes[0].label); interner.len()];
+        for node in nodes {
+            neighbours[node.label.index()] = (node.left, node.right);
+        }
         Network {
-            nodes: input
-                .iter()
-                .filter(|line| line.len() == 16)
-                .map(|line| Node::from_string(line).unwrap())
-                .collect(),
+            interner,
+            neighbours,
         }
     }
 }
@@ -163,17 +154,16 @@ impl Network {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         8
     }
-    fn expect_part_one(&self) -> Answer {
-        14257
-    }
-    fn expect_part_two(&self) -> Answer {
-        16187743689077
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Graph, Tag::Math]
     }
 
     define_examples! {
@@ -189,19 +179,19 @@ impl Solution for Problem {
         )
     }
 
-    fn solve_part_one(&self, input: Input, _is_example: bool) -> Answer {
-        let instructions = Instructions::parse(input.clone());
+    fn solve_part_one(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+        let instructions = parse_instructions(input);
         let network = Network::parse(input);
         let camel_steps_until_zzz_is_reached =
             network.camel_steps_until_zzz_is_reached(&instructions);
-        camel_steps_until_zzz_is_reached as Answer
+        Ok((camel_steps_until_zzz_is_reached as Int).into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
-        let instructions = Instructions::parse(input.clone());
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+        let instructions = parse_instructions(input);
         let network = Network::parse(input);
         let ghost_steps_until_zzz_is_reached =
             network.ghost_steps_until_zzz_is_reached(&instructions);
-        ghost_steps_until_zzz_is_reached as Answer
+        Ok(ghost_steps_until_zzz_is_reached.into())
     }
 }
@@ -127,17 +127,16 @@ impl OASIS {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         9
     }
-    fn expect_part_one(&self) -> Answer {
-        2005352194
-    }
-    fn expect_part_two(&self) -> Answer {
-        1077
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Math]
     }
 
     define_examples! {
@@ -151,17 +150,17 @@ impl Solution for Problem {
         )
     }
 
-    fn solve_part_one(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_one(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let mut oasis = OASIS::parse(input);
         oasis.extrapolate_histories();
         let sum_of_histories_last_values = oasis.sum_of_histories_last_values();
-        sum_of_histories_last_values as Answer
+        Ok(Answer::Int(sum_of_histories_last_values.into()))
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let mut oasis = OASIS::parse(input);
         oasis.extrapolate_histories();
         let sum_of_histories_first_values = oasis.sum_of_histories_first_values();
-        sum_of_histories_first_values as Answer
+        Ok(Answer::Int(sum_of_histories_first_values.into()))
     }
 }
@@ -2,6 +2,7 @@ use core::panic;
 use std::cmp::{max, min};
 
 use crate::*;
+use aoc::polygon;
 
 static PRINT_DISTANCES: bool = false;
 
@@ -189,42 +190,23 @@ impl Maze {
         new_distance
     }
 
-    fn get_area_enclosed_by_visited_tiles(&self) -> f64 {
-        // We use the Shoelace formule to find this area
-        // See: https://en.wikipedia.org/wiki/Shoelace_formula
-
-        let circular_visited: Vec<Position> = self
-            .visited
+    /// The visited tiles as the Points of the loop, in the order in which they are visited
+    fn get_loop(&self) -> Vec<grid::Point> {
+        self.visited
             .iter()
-            .chain(vec![self.visited.first().unwrap()])
-            .cloned()
-            .collect();
-        let area: f64 = circular_visited
-            .windows(2)
-            .map(|v| {
-                if let [a, b] = v {
-                    (a.0 * b.1 - b.0 * a.1) as f64
-                } else {
-                    panic!("This should never happen")
-                }
-            })
-            .sum::<f64>()
-            / 2.0;
-        area.abs()
+            .map(|&(x, y)| grid::Point::new(x as aoc::Int, y as aoc::Int))
+            .collect()
     }
 
     fn get_interior_points(&self) -> Int {
-        // Use Pick's theorem to find the interior points from the area and boundary points
-        // See: https://en.wikipedia.org/wiki/Pick%27s_theorem
-        // Let i be the number of integer points interior to the polygon
-        // Let b be the number of integer points on its boundary
-        // Then the area A of this polygon is: A = i + b/2 - 1
-        // So then i = A - b/2 + 1
-
-        let area = self.get_area_enclosed_by_visited_tiles();
-        let boundary_points = self.visited.len();
-        let interior_points = area - (boundary_points as f64 / 2.0) + 1.0;
-        interior_points as Int
+        // A tile is inside the loop iff a ray from it to the left crosses the loop an odd number of times
+        let tiles = polygon::loop_tiles(&self.get_loop());
+        let sides = polygon::sides(self.columns, self.rows, &tiles);
+        sides
+            .iter()
+            .flatten()
+            .filter(|side| **side == polygon::Side::Inside)
+            .count() as Int
     }
 
     fn print_visit_distance_to_start(position: &Position, tile: &Tile, distance: i32) {
@@ -340,8 +322,75 @@ mod tests {
             let mut maze = Maze::from_strings(&input.iter().map(|s| s.to_string()).collect());
             let _ = maze.find_longest_distance_from_animal_starting_position();
             let interior_points = maze.get_interior_points();
+            let interior_points_by_pick = maze.get_interior_points_by_pick();
+            let interior_points_by_squeezing = maze.get_interior_points_by_squeezing();
             dbg!(maze.to_strings);
             test!(expected_interior_points, interior_points);
+            test!(expected_interior_points, interior_points_by_pick);
+            test!(expected_interior_points, interior_points_by_squeezing);
+        }
+
+        // The visited tiles are the vertices of the loop, of which Pick's theorem gives the interior points
+        fn get_interior_points_by_pick(&self) -> Int {
+            polygon::interior(&self.get_loop()) as Int
+        }
+
+        // Squeeze between the pipes of the loop by flood filling the outside of the expanded Map, in which connected
+        // pipes are connected by a pipe in the gap between them, so the tiles that are not reached are inside
+        fn get_interior_points_by_squeezing(&self) -> Int {
+            use aoc::scaling::Gap;
+            use std::collections::VecDeque;
+            let tiles = polygon::loop_tiles(&self.get_loop());
+            let map: grid::Map<Option<[grid::Direction; 2]>> = (0..self.rows as aoc::Int)
+                .map(|y| {
+                    (0..self.columns as aoc::Int)
+                        .map(|x| tiles.get(&grid::Point::new(x, y)).copied())
+                        .collect()
+                })
+                .collect();
+            let connects = |tile: &Option<[grid::Direction; 2]>, direction| {
+                tile.is_some_and(|directions| directions.contains(&direction))
+            };
+            let expanded = map.expand(|gap| match gap {
+                Gap::Horizontal { left, right }
+                    if connects(left, grid::East) && connects(right, grid::West) =>
+                {
+                    Some([grid::West, grid::East])
+                }
+                Gap::Vertical { above, below }
+                    if connects(above, grid::South) && connects(below, grid::North) =>
+                {
+                    Some([grid::North, grid::South])
+                }
+                _ => None,
+            });
+
+            // Everything that can be reached from the edge without crossing a pipe of the loop is outside
+            let mut outside = FastHashSet::default();
+            let mut queue: VecDeque<grid::Point> = expanded
+                .map
+                .find(|tile| tile.is_none())
+                .into_iter()
+                .filter(|point| {
+                    point.x == 0
+                        || point.y == 0
+                        || point.x == expanded.map.get_columns() as aoc::Int - 1
+                        || point.y == expanded.map.get_rows() as aoc::Int - 1
+                })
+                .collect();
+            while let Some(point) = queue.pop_front() {
+                if expanded.map.point_get(&point) != Some(&None) || !outside.insert(point) {
+                    continue;
+                }
+                queue.extend(expanded.map.get_adjacent(&point));
+            }
+
+            expanded
+                .map
+                .find(|tile| tile.is_none())
+                .into_iter()
+                .filter(|point| !outside.contains(point) && expanded.original(point).is_some())
+                .count() as Int
         }
     }
 
@@ -446,29 +495,28 @@ mod tests {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         10
     }
-    fn expect_part_one(&self) -> Answer {
-        6951
-    }
-    fn expect_part_two(&self) -> Answer {
-        563
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Grid, Tag::Graph, Tag::Geometry]
     }
 
-    fn solve_part_one(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_one(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let mut maze = Maze::parse(input);
         let distance = maze.find_longest_distance_from_animal_starting_position();
-        distance as Answer
+        Ok(Answer::Int(distance.into()))
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let mut maze = Maze::parse(input);
         maze.find_longest_distance_from_animal_starting_position();
         let interior_points = maze.get_interior_points();
-        interior_points as Answer
+        Ok(Answer::Int(interior_points.into()))
     }
 }
@@ -35,8 +35,6 @@ impl Data {
     }
 }
 
-type Grid<T> = Vec<Vec<T>>;
-
 fn transpose_grid<T: Clone>(grid: &Grid<T>) -> Grid<T> {
     (0..grid.first().unwrap().len() - 1)
         .map(|i| {
@@ -339,17 +337,16 @@ mod tests {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         11
     }
-    fn expect_part_one(&self) -> Answer {
-        9918828
-    }
-    fn expect_part_two(&self) -> Answer {
-        692506533832
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Grid, Tag::Geometry]
     }
 
     define_examples! {
@@ -367,21 +364,22 @@ impl Solution for Problem {
             #...#.....
             ",
             Expect::PartsOneAndTwo(374, 8410),
+            Params::example(&[("scale", 100)]),
         )
     }
 
-    fn solve_part_one(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_one(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let image = Image::parse(input);
         let expanded_image = image.expand_universe();
         let sum_of_distances = expanded_image.compute_sum_of_distances_between_all_galaxies();
-        sum_of_distances
+        Ok(sum_of_distances.into())
     }
 
-    fn solve_part_two(&self, input: Input, is_example: bool) -> Answer {
+    fn solve_part_two(&self, input: &String, params: &Params) -> AocResult<Answer> {
         let image = Image::parse(input);
-        let scale = if is_example { 100 } else { 1_000_000 };
+        let scale = params.get("scale", 1_000_000);
         let scaled_image = image.expand_universe_with_factor(scale);
         let sum_of_distances = scaled_image.compute_sum_of_distances_between_all_galaxies();
-        sum_of_distances
+        Ok(sum_of_distances.into())
     }
 }
@@ -1,22 +1,20 @@
 use crate::*;
-
-type Key = (String, Vec<Int>);
-type Memo = HashMap<Key, Int>;
+use aoc::arrangements::{self, Cell};
 
 struct Record {
     springs: String,
-    damaged_spring_groups: Vec<Int>,
+    damaged_spring_groups: Vec<usize>,
 }
 
 impl Record {
     fn from_string(input: &str) -> Record {
         let mut iter = input.split_ascii_whitespace();
         let springs = iter.next().unwrap().to_string();
-        let damaged_spring_groups: Vec<Int> = iter
+        let damaged_spring_groups: Vec<usize> = iter
             .next()
             .unwrap()
             .split(",")
-            .map(|v| v.parse::<Int>().unwrap())
+            .map(|v| v.parse::<usize>().unwrap())
             .collect();
         Record {
             springs,
@@ -24,11 +22,18 @@ impl Record {
         }
     }
 
-    fn solve(&self, memo: &mut Memo) -> Int {
-        solve(
-            (self.springs.to_string() + ".").clone(),
-            self.damaged_spring_groups.clone(),
-            memo,
+    /// Return the amount of different valid records that are possible, e.g. 4 for `????.######..#####. 1,6,5`
+    fn solve(&self) -> Int {
+        let spring = |c: &u8| match c {
+            b'#' => Cell::Filled,
+            b'.' => Cell::Empty,
+            b'?' => Cell::Unknown,
+            _ => panic!("Invalid char '{}' in record.", *c as char),
+        };
+        arrangements::count_arrangements(
+            self.springs.as_bytes(),
+            &self.damaged_spring_groups,
+            spring,
         )
     }
 
@@ -45,9 +50,9 @@ impl Record {
     }
 
     #[cfg(test)]
-    fn test(input: &str, expected_arrangements: Int, memo: &mut Memo) {
+    fn test(input: &str, expected_arrangements: Int) {
         let record = Record::from_string(input);
-        let actual_arrangements = record.solve(memo);
+        let actual_arrangements = record.solve();
         test!(
             expected_arrangements,
             actual_arrangements,
@@ -58,9 +63,9 @@ impl Record {
     }
 
     #[cfg(test)]
-    fn test_expanded(input: &str, expected_arrangements: Int, memo: &mut Memo) {
+    fn test_expanded(input: &str, expected_arrangements: Int) {
         let record = Record::from_string(input).expand();
-        let actual_arrangements = record.solve(memo);
+        let actual_arrangements = record.solve();
         test!(
             expected_arrangements,
             actual_arrangements,
@@ -71,82 +76,27 @@ impl Record {
     }
 }
 
-/// # Brief
-/// Take in a record and a vector of groups of damaged strings, and return the amount of
-/// different valid records that are possible.
-/// # Parameters
-/// - record: e.g. `????.######..#####.`
-/// - groups: e.g. `vec![1,6,5]`
-/// # Returns
-/// - Int: e.g. 4
-///
-/// # Notes
-/// We will use dynamic programming to solve this problem.
-fn solve(record: String, groups: Vec<Int>, memo: &mut Memo) -> Int {
-    let key: Key = (record.clone(), groups.clone());
-    if memo.contains_key(&key) {
-        return *memo.get(&key).unwrap();
-    }
-    let mut result = 0;
-    if groups.is_empty() {
-        if !record.contains("#") || record.is_empty() {
-            result = 1;
-        }
-    } else {
-        for (i, c) in record.chars().enumerate() {
-            match c {
-                '.' => {
-                    if i == 0 {
-                        result = solve(record[1..].to_string(), groups, memo);
-                        break;
-                    } else if i as Int == *groups.first().unwrap() {
-                        result = solve(record[i..].to_string(), groups[1..].to_vec(), memo);
-                        break;
-                    } else {
-                        result = 0;
-                        break;
-                    }
-                }
-                '#' => {
-                    continue;
-                }
-                '?' => {
-                    let a = record.replacen("?", ".", 1);
-                    let b = record.replacen("?", "#", 1);
-                    result = solve(a, groups.clone(), memo) + solve(b, groups, memo);
-                    break;
-                }
-                _ => panic!("Invalid char '{}' in record.", c),
-            };
-        }
-    }
-    memo.insert(key, result);
-    result
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     #[test]
     fn test_part_one_example() {
-        let mut memo = Memo::new();
-        Record::test("???.### 1,1,3", 1, &mut memo);
-        Record::test(".??..??...?##. 1,1,3", 4, &mut memo);
-        Record::test("?#?#?#?#?#?#?#? 1,3,1,6", 1, &mut memo);
-        Record::test("????.#...#... 4,1,1", 1, &mut memo);
-        Record::test("????.######..#####. 1,6,5", 4, &mut memo);
-        Record::test("?###???????? 3,2,1", 10, &mut memo);
+        Record::test("???.### 1,1,3", 1);
+        Record::test(".??..??...?##. 1,1,3", 4);
+        Record::test("?#?#?#?#?#?#?#? 1,3,1,6", 1);
+        Record::test("????.#...#... 4,1,1", 1);
+        Record::test("????.######..#####. 1,6,5", 4);
+        Record::test("?###???????? 3,2,1", 10);
     }
 
     #[test]
     fn test_part_two_example() {
-        let mut memo = Memo::new();
-        Record::test_expanded("???.### 1,1,3", 1, &mut memo);
-        Record::test_expanded(".??..??...?##. 1,1,3", 16384, &mut memo);
-        Record::test_expanded("?#?#?#?#?#?#?#? 1,3,1,6", 1, &mut memo);
-        Record::test_expanded("????.#...#... 4,1,1", 16, &mut memo);
-        Record::test_expanded("????.######..#####. 1,6,5", 2500, &mut memo);
-        Record::test_expanded("?###???????? 3,2,1", 506250, &mut memo);
+        Record::test_expanded("???.### 1,1,3", 1);
+        Record::test_expanded(".??..??...?##. 1,1,3", 16384);
+        Record::test_expanded("?#?#?#?#?#?#?#? 1,3,1,6", 1);
+        Record::test_expanded("????.#...#... 4,1,1", 16);
+        Record::test_expanded("????.######..#####. 1,6,5", 2500);
+        Record::test_expanded("?###???????? 3,2,1", 506250);
     }
 }
 
@@ -154,17 +104,22 @@ mod tests {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         12
     }
-    fn expect_part_one(&self) -> Answer {
-        6935
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::DynamicProgramming]
+    }
+    fn threads(&self) -> usize {
+        rayon::current_num_threads()
     }
-    fn expect_part_two(&self) -> Answer {
-        3920437278260
+    fn cache_version(&self, part: Part) -> Option<&'static str> {
+        (part == Part::Two).then_some("1")
     }
 
     define_examples! {
@@ -181,23 +136,13 @@ impl Solution for Problem {
         )
     }
 
-    fn solve_part_one(&self, input: Input, _is_example: bool) -> Answer {
-        let mut memo = Memo::new();
-        let sum: Int = input
-            .lines()
-            .filter(|s| !s.is_empty())
-            .map(|s| Record::from_string(s).solve(&mut memo))
-            .sum();
-        sum
+    fn solve_part_one(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+        let sum: Int = input.par_lines_sum(|s| Record::from_string(s).solve());
+        Ok(sum.into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
-        let mut memo = Memo::new();
-        let sum: Int = input
-            .lines()
-            .filter(|s| !s.is_empty())
-            .map(|s| Record::from_string(s).expand().solve(&mut memo))
-            .sum();
-        sum
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+        let sum: Int = input.par_lines_sum(|s| Record::from_string(s).expand().solve());
+        Ok(sum.into())
     }
 }
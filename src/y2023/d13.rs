@@ -1,23 +1,15 @@
 use colored::Colorize;
 
-use crate::{define_examples, solution::*};
+use aoc::symmetry::{self, Mirror};
+use aoc::{define_examples, solution::*};
 
 type Int = i32;
-type Grid<T> = Vec<Vec<T>>;
 
-#[derive(Debug, Clone)]
-enum Line {
-    Row(Int),
-    Column(Int),
-}
-
-impl Line {
-    fn in_line(&self, row: Int, column: Int) -> bool {
-        use Line::*;
-        match &self {
-            Row(k) => *k == row,
-            Column(k) => *k == column,
-        }
+/// Returns true iff the cell at `row` and `column` is next to `mirror`
+fn next_to(mirror: &Mirror, row: usize, column: usize) -> bool {
+    match *mirror {
+        Mirror::Horizontal(above) => row + 1 == above || row == above,
+        Mirror::Vertical(left) => column + 1 == left || column == left,
     }
 }
 
@@ -26,9 +18,9 @@ struct Pattern {
     grid: Grid<char>,
     rows: usize,
     columns: usize,
-    smudges: Int,
+    smudges: usize,
     summary: Option<Int>,
-    reflection: Option<Line>,
+    reflection: Option<Mirror>,
 }
 
 impl Debug for Pattern {
@@ -39,9 +31,7 @@ impl Debug for Pattern {
         for i in 0..self.rows {
             grid_string.push_str("  ");
             for j in 0..self.columns {
-                let element = if reflection.in_line(i as Int, j as Int)
-                    || reflection.in_line(i as Int - 1, j as Int - 1)
-                {
+                let element = if next_to(reflection, i, j) {
                     self.grid[i][j].to_string().red()
                 } else {
                     self.grid[i][j].to_string().normal()
@@ -61,12 +51,12 @@ impl Debug for Pattern {
 }
 
 impl Pattern {
-    fn parse(input: Input, smudges: Int) -> Vec<Pattern> {
+    fn parse(input: Input, smudges: usize) -> Vec<Pattern> {
         Pattern::parse_patterns(&input.lines().map(|s| s.to_string()).collect(), smudges)
     }
 
     /// Parse a single Pattern
-    fn parse_pattern(input: Vec<String>, smudges: Int) -> Pattern {
+    fn parse_pattern(input: Vec<String>, smudges: usize) -> Pattern {
         let rows = input.len();
         let columns = input.first().unwrap().len();
         let grid: Grid<char> = input.iter().map(|s| s.chars().collect()).collect();
@@ -85,7 +75,7 @@ impl Pattern {
     }
 
     /// Parse Patterns separated by empty lines
-    fn parse_patterns(input: &Vec<String>, smudges: Int) -> Vec<Pattern> {
+    fn parse_patterns(input: &Vec<String>, smudges: usize) -> Vec<Pattern> {
         let mut result: Vec<Pattern> = Vec::new();
         let mut current: Vec<String> = Vec::new();
         for line in input {
@@ -104,83 +94,16 @@ impl Pattern {
         result
     }
 
-    fn compare(a: &Vec<char>, b: &Vec<char>) -> Int {
-        assert!(a.len() == b.len());
-        let mut differences = 0;
-        for i in 0..a.len() {
-            if a[i] != b[i] {
-                differences += 1;
-            }
-        }
-        differences
-    }
-
-    fn get_row(&self, index: usize) -> &Vec<char> {
-        assert!(index < self.rows);
-        &self.grid[index]
-    }
-
-    fn get_column(&self, index: usize) -> Vec<char> {
-        assert!(index < self.columns);
-        let mut result: Vec<char> = Vec::new();
-        for i in 0..self.rows {
-            result.push(self.grid[i][index]);
-        }
-        result
-    }
-
-    fn find_reflection(&self) -> Option<Line> {
-        let columns: Grid<char> = (0..self.columns).map(|i| self.get_column(i)).collect();
-        let mut result = None;
-
-        // Check whether any 2 rows form a reflection, with exactly smudges amount of imperfections
-        for i in 0..self.rows - 1 {
-            let a = self.get_row(i);
-            let b = self.get_row(i + 1);
-            let mut diff = Pattern::compare(a, b);
-            for j in 1..self.rows - 1 - i {
-                if diff > self.smudges || i < j {
-                    break;
-                }
-                let c = self.get_row(i - j);
-                let d = self.get_row(i + 1 + j);
-                diff += Pattern::compare(&c, &d);
-            }
-            if diff == self.smudges {
-                result = Some(Line::Row(i as Int));
-                break;
-            }
-        }
-
-        if result.is_none() {
-            // Check whether any 2 columns form a reflection, with exactly smudges amount of imperfections
-            for i in 0..self.columns - 1 {
-                let a = &columns[i];
-                let b = &columns[i + 1];
-                let mut diff = Pattern::compare(&a, &b);
-                for j in 1..self.columns - 1 - i {
-                    if diff > self.smudges || i < j {
-                        break;
-                    }
-                    let c = &columns[i - j];
-                    let d = &columns[i + 1 + j];
-                    diff += Pattern::compare(&c, &d);
-                }
-                if diff == self.smudges {
-                    result = Some(Line::Column(i as Int));
-                    break;
-                }
-            }
-        }
-
-        result
+    /// The reflection with exactly `smudges` cells that do not mirror
+    fn find_reflection(&self) -> Option<Mirror> {
+        symmetry::mirror(&self.grid, self.smudges)
     }
 
     fn summarize(&self) -> Int {
         let reflection = self.find_reflection();
         match reflection {
-            Some(Line::Row(i)) => return (i + 1) * 100,
-            Some(Line::Column(i)) => return i + 1,
+            Some(Mirror::Horizontal(above)) => return above as Int * 100,
+            Some(Mirror::Vertical(left)) => return left as Int,
             _ => panic!("No valid relection found: '{:?}'.", reflection),
         }
     }
@@ -190,17 +113,16 @@ impl Pattern {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         13
     }
-    fn expect_part_one(&self) -> Answer {
-        30535
-    }
-    fn expect_part_two(&self) -> Answer {
-        30844
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Grid]
     }
 
     define_examples! {
@@ -227,17 +149,17 @@ impl Solution for Problem {
         )
     }
 
-    fn solve_part_one(&self, input: Input, is_example: bool) -> Answer {
-        debug!(is_example, &input);
+    fn solve_part_one(&self, input: &String, params: &Params) -> AocResult<Answer> {
+        debug!(params.is_example(), "{:#?}", &input);
         let part_1_patterns = Pattern::parse(input, 0);
-        debug!(is_example, &part_1_patterns);
+        debug!(params.is_example(), "{:#?}", &part_1_patterns);
         let sum: Int = part_1_patterns.iter().map(|p| p.summary.unwrap()).sum();
-        sum as Answer
+        Ok(Answer::Int(sum.into()))
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let part_2_patterns = Pattern::parse(input, 1);
         let sum: Int = part_2_patterns.iter().map(|p| p.summary.unwrap()).sum();
-        sum as Answer
+        Ok(Answer::Int(sum.into()))
     }
 }
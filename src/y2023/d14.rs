@@ -1,11 +1,20 @@
-use crate::define_examples;
 use crate::*;
+use aoc::byte_grid::{count_byte, find_byte};
+use aoc::define_examples;
+use aoc::frames::{Frame, Frames};
+use aoc::simulation::Simulation;
+use aoc::snapshots::{Snapshot, Snapshots};
 
 static NUMBER_OF_CYCLES: Int = 1000000000;
 
-type Grid<T> = Vec<Vec<T>>;
+/// Number of spin cycles of which `explain` shows the load
+const EXPLAINED_SPIN_CYCLES: Int = 5;
 
-#[derive(PartialEq, Debug, Clone)]
+const ROUNDED_ROCK: u8 = b'O';
+const CUBE_SHAPED_ROCK: u8 = b'#';
+const EMPTY_SPACE: u8 = b'.';
+
+#[derive(PartialEq, Debug, Clone, Copy)]
 enum Direction {
     North,
     East,
@@ -13,76 +22,35 @@ enum Direction {
     West,
 }
 
-use std::{collections::HashMap, fmt::Debug};
+use std::fmt::Debug;
 
 use Direction::*;
 
-#[derive(PartialEq, Debug, Clone, Eq, Hash)]
-struct Point {
-    x: Int,
-    y: Int,
-}
-
-impl Point {
-    fn move_to(&self, direction: Direction) -> Point {
-        match direction {
-            North => Point {
-                x: self.x,
-                y: self.y - 1,
-            },
-            East => Point {
-                x: self.x + 1,
-                y: self.y,
-            },
-            South => Point {
-                x: self.x,
-                y: self.y + 1,
-            },
-            West => Point {
-                x: self.x - 1,
-                y: self.y,
-            },
-        }
-    }
-
-    fn new(x: Int, y: Int) -> Point {
-        Point { x, y }
-    }
-}
-
-#[derive(PartialEq, Debug, Clone, Eq, Hash)]
-enum Terrain {
-    RoundedRock(Option<Point>),
-    CubeShapedRock(Option<Point>),
-    EmptySpace(Option<Point>),
-}
-
-impl Terrain {
-    fn from_char(c: char) -> Terrain {
-        use Terrain::*;
-        match c {
-            'O' => RoundedRock(None),
-            '#' => CubeShapedRock(None),
-            '.' => EmptySpace(None),
-            _ => panic!("Invalid Terrain char: '{}'.", c),
-        }
-    }
-
-    fn to_char(&self) -> char {
-        use Terrain::*;
-        match &self {
-            RoundedRock(_) => 'O',
-            CubeShapedRock(_) => '#',
-            EmptySpace(_) => '.',
-        }
+/// Roll every rounded rock in `line` towards its start or its end, until it hits a cube-shaped rock
+fn roll(line: &mut [u8], towards_start: bool) {
+    let mut start = 0;
+    while start < line.len() {
+        let end = find_byte(&line[start..], CUBE_SHAPED_ROCK).map_or(line.len(), |i| start + i);
+        let segment = &mut line[start..end];
+        let rounded_rocks = count_byte(segment, ROUNDED_ROCK);
+        let split = match towards_start {
+            true => rounded_rocks,
+            false => segment.len() - rounded_rocks,
+        };
+        let (before, after) = segment.split_at_mut(split);
+        match towards_start {
+            true => (before.fill(ROUNDED_ROCK), after.fill(EMPTY_SPACE)),
+            false => (before.fill(EMPTY_SPACE), after.fill(ROUNDED_ROCK)),
+        };
+        start = end + 1;
     }
 }
 
-#[derive(PartialEq, Clone)]
-struct Platform {
+#[derive(PartialEq, Eq, Clone, Hash)]
+pub struct Platform {
     rows: usize,
     columns: usize,
-    grid: Grid<Terrain>,
+    grid: ByteGrid,
 }
 
 impl Debug for Platform {
@@ -90,217 +58,147 @@ impl Debug for Platform {
         write!(
             f,
             "Platform({}x{}):\n{}",
-            self.rows,
-            self.columns,
-            grid_to_string(&self.grid)
+            self.rows, self.columns, self.grid
         )
     }
 }
 
+impl Frame for Platform {
+    fn write_frame(&self, out: &mut String) -> std::fmt::Result {
+        use std::fmt::Write;
+        write!(out, "{}", self.grid)
+    }
+}
+
+impl Snapshot for Platform {
+    fn save(&self) -> String {
+        self.grid.to_string()
+    }
+
+    fn restore(snapshot: &str) -> Self {
+        Platform::parse(snapshot)
+    }
+}
+
 impl Platform {
     fn parse(input: Input) -> Platform {
-        Platform::from_strings(input.lines().map(|s| s.to_string()).collect::<Vec<_>>())
+        let grid = ByteGrid::from(input);
+        Platform {
+            rows: grid.height(),
+            columns: grid.width(),
+            grid,
+        }
     }
 
     fn run_spin_cycle(&mut self) {
-        vec![North, West, South, East]
-            .iter()
-            .for_each(|d| self.tilt(d.clone()));
+        [North, West, South, East]
+            .into_iter()
+            .for_each(|d| self.tilt(d));
     }
 
     fn tilt(&mut self, direction: Direction) {
         match direction {
-            North => {
+            West | East => {
                 for y in 0..self.rows {
-                    for x in 0..self.columns {
-                        self.tilt_terrain(x, y, &direction);
-                    }
-                }
-            }
-            South => {
-                for y in (0..self.rows).rev() {
-                    for x in 0..self.columns {
-                        self.tilt_terrain(x, y, &direction);
-                    }
-                }
-            }
-            East => {
-                for x in (0..self.columns).rev() {
-                    for y in 0..self.rows {
-                        self.tilt_terrain(x, y, &direction);
-                    }
+                    roll(self.grid.row_mut(y), direction == West);
                 }
             }
-            West => {
+            North | South => {
+                let mut column = Vec::with_capacity(self.rows);
                 for x in 0..self.columns {
-                    for y in 0..self.rows {
-                        self.tilt_terrain(x, y, &direction);
-                    }
+                    self.grid.read_column(x, &mut column);
+                    roll(&mut column, direction == North);
+                    self.grid.write_column(x, &column);
                 }
             }
         }
-        self.assign_points();
-    }
-
-    fn tilt_terrain(&mut self, x: usize, y: usize, direction: &Direction) {
-        let point = Point::new(x as Int, y as Int);
-        self.tilt_terrain_at_point(&point, direction);
-    }
-
-    fn tilt_terrain_at_point(&mut self, point: &Point, direction: &Direction) {
-        let mut point = point.clone();
-        while self.can_move(&point, direction.clone()) {
-            let to = point.move_to(direction.clone());
-            self.move_to(&point, direction.clone());
-            point = to;
-        }
     }
 
-    fn move_to(&mut self, from_point: &Point, direction: Direction) {
-        let from = self.get(from_point).unwrap().clone();
-        let to = self.get(&from_point.move_to(direction)).unwrap().clone();
-        match (&from, &to) {
-            (Terrain::RoundedRock(_), Terrain::EmptySpace(Some(to_point))) => {
-                self.set(&to_point, &Terrain::RoundedRock(Some(to_point.clone())));
-                self.set(from_point, &Terrain::EmptySpace(Some(from_point.clone())));
-            }
-            (Terrain::EmptySpace(_) | Terrain::CubeShapedRock(_), _) => (),
-            _ => panic!("Not able to move from '{:?}' to '{:?}'.", from, to),
-        }
+    fn get_total_load(&self) -> Int {
+        (0..self.rows)
+            .map(|y| (self.rows - y) as Int * self.grid.count_in_row(y, ROUNDED_ROCK) as Int)
+            .sum()
     }
+}
 
-    fn can_move(&self, point: &Point, direction: Direction) -> bool {
-        let from = self.get(&point).unwrap();
-        let to = self.get(&point.move_to(direction));
-        match (from, to) {
-            (Terrain::RoundedRock(_), Some(Terrain::EmptySpace(_))) => true,
-            _ => false,
-        }
-    }
+impl Simulation for Platform {
+    type Key = ByteGrid;
 
-    fn point_outside_grid(&self, point: &Point) -> bool {
-        point.x < 0 || point.x >= self.columns as Int || point.y < 0 || point.y >= self.rows as Int
+    fn step(&mut self) {
+        self.run_spin_cycle();
     }
 
-    fn get(&self, point: &Point) -> Option<&Terrain> {
-        let point_outside_grid = self.point_outside_grid(point);
-        if point_outside_grid {
-            None
-        } else {
-            Some(&self.grid[point.y as usize][point.x as usize])
-        }
+    fn key(&self) -> Self::Key {
+        self.grid.clone()
     }
+}
 
-    fn set(&mut self, point: &Point, terrain: &Terrain) {
-        assert!(
-            !self.point_outside_grid(point),
-            "Point not inside grid: '{:?}'.",
-            point
-        );
-        self.grid[point.y as usize][point.x as usize] = terrain.clone();
-    }
+/// The platform of the puzzle input, which can be tilted and spun in the REPL
+struct PlatformRepl {
+    parsed: Platform,
+    platform: Platform,
+}
 
-    fn load_at_row(&self, row: Int) -> Option<Int> {
-        if row < 0 || row >= self.rows as Int {
-            None
-        } else {
-            Some(self.rows as Int - row)
-        }
+impl Repl for PlatformRepl {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            (
+                "tilt <north|east|south|west>",
+                "Roll the rounded rocks in a direction",
+            ),
+            ("cycle [N]", "Run N spin cycles, 1 by default"),
+            ("load", "Print the total load on the north support beams"),
+            ("show", "Print the platform"),
+            ("reset", "Go back to the platform of the puzzle input"),
+        ]
     }
 
-    fn get_total_load(&self) -> Int {
-        let mut total_load = 0;
-        for y in 0..self.rows {
-            let load_at_row = self.load_at_row(y as Int).unwrap();
-            for x in 0..self.columns {
-                let point = Point::new(x as Int, y as Int);
-                let terrain = self.get(&point).unwrap();
-                match terrain {
-                    Terrain::RoundedRock(_) => total_load += load_at_row,
-                    _ => (),
-                }
+    fn run(&mut self, name: &str, args: &[&str]) -> Result<String, String> {
+        match (name, args) {
+            ("tilt", [direction]) => {
+                let direction = match *direction {
+                    "north" => North,
+                    "east" => East,
+                    "south" => South,
+                    "west" => West,
+                    _ => return Err(format!("Unknown direction '{}'.", direction)),
+                };
+                self.platform.tilt(direction);
+                Ok(format!("{}", self.platform.grid))
             }
-        }
-        total_load
-    }
-
-    fn assign_points(&mut self) {
-        for y in 0..self.rows {
-            for x in 0..self.columns {
-                let point = Point::new(x as Int, y as Int);
-                let terrain = self.get(&point).unwrap();
-                let new_terrain = match terrain {
-                    Terrain::CubeShapedRock(_) => Terrain::CubeShapedRock(Some(point.clone())),
-                    Terrain::RoundedRock(_) => Terrain::RoundedRock(Some(point.clone())),
-                    Terrain::EmptySpace(_) => Terrain::EmptySpace(Some(point.clone())),
+            ("cycle", [] | [_]) => {
+                let spin_cycles = match args.first() {
+                    Some(n) => n.parse().map_err(|_| format!("Invalid number '{}'.", n))?,
+                    None => 1,
                 };
-                self.set(&point, &new_terrain);
+                self.platform.advance(spin_cycles);
+                Ok(format!("Load: {}", self.platform.get_total_load()))
             }
-        }
-    }
-
-    fn get_total_load_after_cycles(&mut self, number_of_cycles: Int) -> Int {
-        let mut grid_at: HashMap<Grid<Terrain>, Int> = HashMap::new();
-        for current_cycle in 1..number_of_cycles {
-            self.run_spin_cycle();
-            if let Some(previous_grid_at) = grid_at.insert(self.grid.clone(), current_cycle) {
-                let cycles_left = number_of_cycles - current_cycle;
-                let number_of_cycles_in_loop = current_cycle - previous_grid_at;
-                if cycles_left % number_of_cycles_in_loop == 0 {
-                    break;
-                }
+            ("load", []) => Ok(self.platform.get_total_load().to_string()),
+            ("show", []) => Ok(format!("{}", self.platform.grid)),
+            ("reset", []) => {
+                self.platform = self.parsed.clone();
+                Ok(format!("{}", self.platform.grid))
             }
+            _ => Err(format!("Invalid arguments for `{}`, try `help`.", name)),
         }
-        self.get_total_load()
     }
-
-    fn from_strings(input: Vec<String>) -> Platform {
-        let grid: Grid<Terrain> = input
-            .iter()
-            .filter(|s| !s.is_empty())
-            .map(|s| s.chars().map(|c| Terrain::from_char(c)).collect())
-            .collect();
-        let rows = grid.len();
-        let columns = grid.first().unwrap().len();
-        let mut result = Platform {
-            rows,
-            columns,
-            grid,
-        };
-        result.assign_points();
-        result
-    }
-}
-
-fn grid_to_string(grid: &Grid<Terrain>) -> String {
-    let mut result: String = String::new();
-    let rows = grid.len();
-    let columns = grid.first().unwrap().len();
-    for y in 0..rows {
-        for x in 0..columns {
-            let terrain = &grid[y][x];
-            result.push(terrain.to_char());
-        }
-        result.push('\n');
-    }
-    result
 }
 
 #[derive(Default)]
 pub struct Problem {}
 
 impl Solution for Problem {
+    type Parsed = Platform;
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         14
     }
-    fn expect_part_one(&self) -> Answer {
-        109098
-    }
-    fn expect_part_two(&self) -> Answer {
-        100064
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Grid, Tag::Simulation]
     }
 
     define_examples! {
@@ -321,108 +219,152 @@ impl Solution for Problem {
         )
     }
 
-    fn solve_part_one(&self, input: Input, _is_example: bool) -> Answer {
+    fn explain(&self, input: Input) -> Vec<String> {
         let mut platform = Platform::parse(input);
+        let mut explanation = vec![format!(
+            "Load before spinning: {}",
+            platform.get_total_load()
+        )];
+        for spin_cycle in 1..=EXPLAINED_SPIN_CYCLES {
+            platform.run_spin_cycle();
+            explanation.push(format!(
+                "Load after spin cycle {}: {}",
+                spin_cycle,
+                platform.get_total_load()
+            ));
+        }
+        let cycle = Platform::parse(input).find_cycle();
+        let equivalent = cycle.start + (NUMBER_OF_CYCLES - cycle.start) % cycle.length;
+        explanation.push(format!(
+            "The platform repeats every {} spin cycles from spin cycle {}, so spin cycle {} is spin cycle {}",
+            cycle.length, cycle.start, NUMBER_OF_CYCLES, equivalent
+        ));
+        explanation
+    }
+
+    fn parse(&self, input: Input, _params: &Params) -> AocResult<Platform> {
+        Ok(Platform::parse(input))
+    }
+
+    fn repl(&self, platform: &Platform) -> Option<Box<dyn Repl>> {
+        Some(Box::new(PlatformRepl {
+            parsed: platform.clone(),
+            platform: platform.clone(),
+        }))
+    }
+
+    fn solve_part_one(&self, platform: &Platform, _params: &Params) -> AocResult<Answer> {
+        let mut platform = platform.clone();
         platform.tilt(North);
         let total_load = platform.get_total_load();
-        total_load
+        Ok(total_load.into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
-        let total_load_after_many_cycles =
-            Platform::parse(input).get_total_load_after_cycles(NUMBER_OF_CYCLES);
-        total_load_after_many_cycles
+    fn solve_part_two(&self, platform: &Platform, params: &Params) -> AocResult<Answer> {
+        let mut frames = Frames::new(params, "y2023-d14");
+        let mut snapshots = Snapshots::new(params, "y2023-d14");
+        let mut platform = platform.clone();
+        let start = snapshots.replay(&mut platform);
+        frames.record(&platform);
+        snapshots.record(start, &platform);
+        platform.advance_numbered(start, NUMBER_OF_CYCLES, |cycle, platform| {
+            frames.record(platform);
+            snapshots.record(cycle, platform);
+        });
+        let total_load_after_many_cycles = platform.get_total_load();
+        Ok(total_load_after_many_cycles.into())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc::differential::{self, Differential};
+    use rand::Rng;
+
+    /// The platform of `input` tilted in `direction`, by moving every rounded rock a single step at a time
+    fn tilt_brute_force((input, direction): &(String, Direction)) -> String {
+        let mut grid: Vec<Vec<u8>> = input.lines().map(|line| line.bytes().collect()).collect();
+        let (dx, dy): (isize, isize) = match direction {
+            North => (0, -1),
+            East => (1, 0),
+            South => (0, 1),
+            West => (-1, 0),
+        };
+        let mut moved = true;
+        while moved {
+            moved = false;
+            for y in 0..grid.len() {
+                for x in 0..grid[y].len() {
+                    let (to_x, to_y) = (x.wrapping_add_signed(dx), y.wrapping_add_signed(dy));
+                    let to = grid.get(to_y).and_then(|row| row.get(to_x));
+                    if grid[y][x] == ROUNDED_ROCK && to == Some(&EMPTY_SPACE) {
+                        grid[y][x] = EMPTY_SPACE;
+                        grid[to_y][to_x] = ROUNDED_ROCK;
+                        moved = true;
+                    }
+                }
+            }
+        }
+        grid.iter()
+            .map(|row| String::from_utf8_lossy(row) + "\n")
+            .collect()
+    }
+
+    fn tilt((input, direction): &(String, Direction)) -> String {
+        let mut platform = Platform::parse(input);
+        platform.tilt(*direction);
+        platform.grid.to_string()
+    }
+
+    #[test]
+    fn test_tilt_against_brute_force() {
+        let differential = Differential::new(tilt_brute_force, tilt);
+        differential.assert_generated(0, 500, |rng| {
+            let (width, height) = (rng.gen_range(1..10), rng.gen_range(1..10));
+            let symbols = [ROUNDED_ROCK, CUBE_SHAPED_ROCK, EMPTY_SPACE];
+            let direction = [North, East, South, West][rng.gen_range(0..4)];
+            (differential::grid(rng, width, height, &symbols), direction)
+        });
+    }
 
     #[test]
     fn test_tilt_part_one() {
         let d14 = Problem {};
         let mut example_platform =
-            Platform::parse(d14.define_examples().first().unwrap().get_input());
+            Platform::parse(&d14.define_examples().first().unwrap().get_input());
         dbg!(&example_platform);
         example_platform.tilt(North);
         dbg!(&example_platform);
         let example_total_load = example_platform.get_total_load();
         test!(136, example_total_load);
-        let example_input_tilted = vec_of_strings![
-            "OOOO.#.O..",
-            "OO..#....#",
-            "OO..O##..O",
-            "O..#.OO...",
-            "........#.",
-            "..#....#.#",
-            "..O..#.O.O",
-            "..O.......",
-            "#....###..",
-            "#....#....",
-        ];
-        let example_platform_tilted = Platform::from_strings(example_input_tilted);
-        //dbg!(&example_platform_tilted);
-        for y in 0..example_platform_tilted.rows {
-            for x in 0..example_platform_tilted.columns {
-                let point = Point::new(x as Int, y as Int);
-                let tilted_terrain = example_platform_tilted.get(&point).unwrap();
-                test!(
-                    tilted_terrain,
-                    example_platform.get(&point).unwrap(),
-                    "Tilt: {:?}",
-                    point
-                );
-            }
-        }
+        assert_matches_golden!("y2023/d14/tilted_north.txt", example_platform.grid);
+    }
+
+    #[test]
+    fn test_repl() {
+        let d14 = Problem {};
+        let input = d14.define_examples().first().unwrap().get_input();
+        let platform = d14.parse(&input, &Params::example(&[])).unwrap();
+        let mut repl = d14.repl(&platform).unwrap();
+        repl.run("tilt", &["north"]).unwrap();
+        test!("136", repl.run("load", &[]).unwrap());
+        test!("Load: 64", repl.run("cycle", &["1000000000"]).unwrap());
+        test!(repl.run("tilt", &["up"]).is_err());
+        test!(repl.run("cycle", &["x"]).is_err());
+        test!(repl.run("reset", &[]).is_ok());
+        test!("104", repl.run("load", &[]).unwrap());
     }
 
     #[test]
     fn test_tilt_part_two() {
         let d14 = Problem {};
         let mut example_platform =
-            Platform::parse(d14.define_examples().first().unwrap().get_input());
-        let example_platform_1_cycle = Platform::from_strings(vec_of_strings![
-            ".....#....",
-            "....#...O#",
-            "...OO##...",
-            ".OO#......",
-            ".....OOO#.",
-            ".O#...O#.#",
-            "....O#....",
-            "......OOOO",
-            "#...O###..",
-            "#..OO#....",
-        ]);
-        let example_platform_2_cycle = Platform::from_strings(vec_of_strings![
-            ".....#....",
-            "....#...O#",
-            ".....##...",
-            "..O#......",
-            ".....OOO#.",
-            ".O#...O#.#",
-            "....O#...O",
-            ".......OOO",
-            "#..OO###..",
-            "#.OOO#...O",
-        ]);
-        let example_platform_3_cycle = Platform::from_strings(vec_of_strings![
-            ".....#....",
-            "....#...O#",
-            ".....##...",
-            "..O#......",
-            ".....OOO#.",
-            ".O#...O#.#",
-            "....O#...O",
-            ".......OOO",
-            "#...O###.O",
-            "#.OOO#...O",
-        ]);
-        example_platform.run_spin_cycle();
-        test!(example_platform_1_cycle, example_platform);
-        example_platform.run_spin_cycle();
-        test!(example_platform_2_cycle, example_platform);
-        example_platform.run_spin_cycle();
-        test!(example_platform_3_cycle, example_platform);
+            Platform::parse(&d14.define_examples().first().unwrap().get_input());
+        for cycles in 1..=3 {
+            example_platform.run_spin_cycle();
+            let golden = format!("y2023/d14/after_{}_cycles.txt", cycles);
+            assert_matches_golden!(&golden, example_platform.grid);
+        }
     }
 }
@@ -67,7 +67,7 @@ impl Box {
             .enumerate()
             .map(|(i, l)| focusing_power_from_box * (i + 1) as Int * l.focal_length)
             .sum();
-        debug!(false, focussing_power);
+        debug!(true, focussing_power);
         focussing_power
     }
 }
@@ -116,8 +116,8 @@ impl BoxSequence {
         } else {
             panic!("Invalid step: '{:?}'.", step);
         }
-        debug!(false, "After {:?}:", step.to_string);
-        debug!(false, "{:?}\n", self);
+        debug!(true, "After {:?}:", step.to_string);
+        debug!(true, "{:?}\n", self);
     }
 
     fn remove_lens(&mut self, step: &Step) {
@@ -153,10 +153,6 @@ struct Step {
 }
 
 impl Step {
-    fn from_string_list(input: &String) -> InitializationSequence {
-        Step::from_string_slice_list(&input)
-    }
-
     fn from_string_slice_list(input: &str) -> InitializationSequence {
         input
             .replace("\n", "")
@@ -231,17 +227,16 @@ mod tests {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         15
     }
-    fn expect_part_one(&self) -> Answer {
-        507769
-    }
-    fn expect_part_two(&self) -> Answer {
-        269747
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Hashing]
     }
 
     define_examples! {
@@ -253,17 +248,17 @@ impl Solution for Problem {
         )
     }
 
-    fn solve_part_one(&self, input: Input, _is_example: bool) -> Answer {
-        let sequence = Step::from_string_list(&input);
+    fn solve_part_one(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+        let sequence = Step::from_string_slice_list(input);
         let steps_sum = sum_steps(&sequence);
-        steps_sum as Answer
+        Ok(Answer::Int(steps_sum.into()))
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
-        let sequence = Step::from_string_list(&input);
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+        let sequence = Step::from_string_slice_list(input);
         let mut box_sequence = BoxSequence::new();
         sequence.iter().for_each(|s| box_sequence.execute(&s));
         let total_focussing_power = box_sequence.get_total_focussing_power();
-        total_focussing_power as Answer
+        Ok(Answer::Int(total_focussing_power.into()))
     }
 }
@@ -7,7 +7,7 @@ use grid::*;
 type Beam = Direction;
 type Points = HashSet<Point>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Terrain {
     the_type: TerrainType,
     beams: Vec<Beam>,
@@ -53,12 +53,12 @@ impl TerrainType {
     }
 }
 
-#[derive(Debug)]
-struct ContraptionMap {
+#[derive(Debug, Clone)]
+pub struct ContraptionMap {
     rows: Int,
     columns: Int,
     grid: Grid<Terrain>,
-    visited: Points,
+    visited: GridMask,
 }
 
 impl ContraptionMap {
@@ -80,7 +80,7 @@ impl ContraptionMap {
             rows,
             columns,
             grid,
-            visited: Points::new(),
+            visited: GridMask::new(columns as usize, rows as usize),
         }
     }
 
@@ -92,24 +92,13 @@ impl ContraptionMap {
         &mut self.grid[point.y as usize][point.x as usize]
     }
 
-    fn get_energy_map(&self) -> String {
+    fn get_energy_map(&self) -> impl std::fmt::Display + '_ {
         self.grid
-            .iter()
-            .map(|v| {
-                v.iter()
-                    .map(|t| if t.energized { '#' } else { '.' })
-                    .collect::<String>()
-            })
-            .collect::<Vec<String>>()
-            .join("\n")
+            .display_with(|t| if t.energized { '#' } else { '.' })
     }
 
-    fn get_terrain_map(&self) -> String {
-        self.grid
-            .iter()
-            .map(|v| v.iter().map(|t| t.the_type.to_char()).collect::<String>())
-            .collect::<Vec<String>>()
-            .join("\n")
+    fn get_terrain_map(&self) -> impl std::fmt::Display + '_ {
+        self.grid.display_with(|t| t.the_type.to_char())
     }
 
     fn within_grid(&self, point: &Point) -> bool {
@@ -118,9 +107,9 @@ impl ContraptionMap {
 
     fn shoot_beam(&mut self, from: &Point, beam: Beam) {
         let mut points: Points = Points::new();
-        debug!(false, "shoot({:?}, {:?})", from, beam);
+        debug!(true, "shoot({:?}, {:?})", from, beam);
         if self.within_grid(from) {
-            self.visited.insert(*from);
+            self.visited.insert(from.x as usize, from.y as usize);
             let current_terrain: &Terrain = self.get_terrain(from);
             if !current_terrain.beams.contains(&beam) {
                 let current_terrain = self.get_terrain_mut(from);
@@ -137,7 +126,7 @@ impl ContraptionMap {
             }
         }
 
-        debug!(false, "shoot({:?}, {:?}) -> {:?}", from, beam, points);
+        debug!(true, "shoot({:?}, {:?}) -> {:?}", from, beam, points);
     }
 
     fn get_amount_of_energized_tiles(&mut self, point: &Point, beam: Beam) -> Int {
@@ -182,17 +171,16 @@ impl ContraptionMap {
 pub struct Problem {}
 
 impl Solution for Problem {
+    type Parsed = ContraptionMap;
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         16
     }
-    fn expect_part_one(&self) -> Answer {
-        6906
-    }
-    fn expect_part_two(&self) -> Answer {
-        7330
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Grid, Tag::Graph]
     }
 
     define_examples! {
@@ -210,42 +198,44 @@ impl Solution for Problem {
             ..//.|....
             ",
             Expect::PartsOneAndTwo(46, 51),
+            artifacts: [(
+                "energy_map",
+                "
+                ######....
+                .#...#....
+                .#...#####
+                .#...##...
+                .#...##...
+                .#...##...
+                .#..####..
+                ########..
+                .#######..
+                .#...#.#..
+                ",
+            )],
         )
     }
 
-    fn solve_part_one(&self, input: Input, is_example: bool) -> Answer {
-        let mut map = ContraptionMap::parse(input);
+    fn parse(&self, input: Input, _params: &Params) -> AocResult<ContraptionMap> {
+        Ok(ContraptionMap::parse(input))
+    }
+
+    fn solve_part_one(&self, map: &ContraptionMap, params: &Params) -> AocResult<Answer> {
+        let mut map = map.clone();
         map.shoot_beam(&Point::new(0, 0), East);
         let amount_of_energized_tiles = map.get_amount_of_energized_tiles(&Point::new(0, 0), East);
-        if is_example {
-            let terrain_map = map.get_terrain_map();
-            let energy_map = map.get_energy_map();
-            println!("Terrain map:\n{}\n", terrain_map);
-            println!("Energy map:\n{}\n", energy_map);
-            let example_expected_energized_map = vec![
-                "######....",
-                ".#...#....",
-                ".#...#####",
-                ".#...##...",
-                ".#...##...",
-                ".#...##...",
-                ".#..####..",
-                "########..",
-                ".#######..",
-                ".#...#.#..",
-            ]
-            .iter()
-            .map(|s| s.to_string())
-            .collect::<Vec<String>>()
-            .join("\n");
-            test!(example_expected_energized_map, energy_map, "energy_map");
-        }
-        amount_of_energized_tiles
+        debug!(
+            params.is_example(),
+            "Terrain map:\n{}\n",
+            map.get_terrain_map()
+        );
+        params.check_artifact("energy_map", map.get_energy_map());
+        Ok(amount_of_energized_tiles.into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
-        let mut map = ContraptionMap::parse(input);
+    fn solve_part_two(&self, map: &ContraptionMap, _params: &Params) -> AocResult<Answer> {
+        let mut map = map.clone();
         let most_amount_energized = map.get_most_amount_of_energized_tiles();
-        most_amount_energized
+        Ok(most_amount_energized.into())
     }
 }
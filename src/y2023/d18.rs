@@ -1,15 +1,11 @@
-use std::{
-    cmp::{max, min},
-    iter::once,
-};
+use std::cmp::min;
 
 use colored::{Colorize, CustomColor};
 use grid::*;
 use hex_color::HexColor;
 
 use crate::*;
-
-type Int = i64;
+use aoc::polygon;
 
 #[derive(Debug)]
 struct Point {
@@ -80,53 +76,17 @@ impl Polygon {
     }
 
     fn print(&self) {
-        let mut max_x = 0;
-        let mut max_y = 0;
-        for p in &self.points {
-            max_x = max(max_x, p.point.x);
-            max_y = max(max_y, p.point.y);
-        }
-        for y in 0..=max_y {
-            for x in 0..=max_x {
-                if let Some(p) = self
-                    .points
-                    .iter()
-                    .find(|p| p.point.x == x && p.point.y == y)
-                {
-                    print!("{}", p.terrain.to_string());
-                } else {
-                    print!("{}", Terrain::Ground.to_string())
-                }
-            }
-            println!();
-        }
+        let terrain: HashMap<grid::Point, &Terrain> =
+            self.points.iter().map(|p| (p.point, &p.terrain)).collect();
+        let (map, _) = Map::from_points(terrain.keys(), Terrain::Ground.to_string(), |p| {
+            terrain[p].to_string()
+        });
+        println!("{}", map);
     }
 
     fn calc_area(&self) -> Int {
-        //Shoelace formule to calculate area of polygon
-        if self.points.is_empty() {
-            0
-        } else {
-            let points: Vec<_> = self.points.iter().chain(once(&self.points[0])).collect();
-            let b = {
-                let mut sum = 0;
-                for i in 0..points.len() - 1 {
-                    let (p_0, p_1) = (points[i].point, points[i + 1].point);
-                    sum += p_0.distance_to(&p_1) as Int;
-                }
-                sum
-            };
-            let mut area: Int = 0;
-            for i in 0..self.points.len() {
-                let (p_0, p_1) = (points[i].point, points[i + 1].point);
-                area += p_0.x * p_1.y - p_1.x * p_0.y;
-            }
-            area = (area / 2).abs();
-            // A = i + b/2 - 1
-            // i = A-b/2+1
-            // Filled polygon = i + b
-            b + area - b / 2 + 1
-        }
+        let vertices: Vec<grid::Point> = self.points.iter().map(|p| p.point).collect();
+        polygon::lattice_points(&vertices)
     }
 
     fn from_dig_plan(dig_plan: &Vec<String>, swapped: bool) -> Polygon {
@@ -171,7 +131,7 @@ impl Polygon {
                 }
             }
         }
-        debug!(false, "{:?}", &polygon);
+        debug!(true, "{:?}", &polygon);
         polygon = polygon.translate_to_px_py();
         polygon
     }
@@ -181,17 +141,16 @@ impl Polygon {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         18
     }
-    fn expect_part_one(&self) -> Answer {
-        48652
-    }
-    fn expect_part_two(&self) -> Answer {
-        45757884535661
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Geometry]
     }
 
     define_examples! {
@@ -216,21 +175,21 @@ impl Solution for Problem {
     )
     }
 
-    fn solve_part_one(&self, input: Input, is_example: bool) -> Answer {
+    fn solve_part_one(&self, input: &String, params: &Params) -> AocResult<Answer> {
         let dig_plan = input.lines().map(|s| s.to_string()).collect();
         let polygon = Polygon::from_dig_plan(&dig_plan, false);
-        debug!(is_example, &polygon);
-        if is_example {
+        debug!(params.is_example(), "{:#?}", &polygon);
+        if params.is_example() {
             polygon.print();
         }
         let a = polygon.calc_area();
-        a
+        Ok(a.into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let dig_plan = input.lines().map(|s| s.to_string()).collect();
         let polygon = Polygon::from_dig_plan(&dig_plan, true);
         let a = polygon.calc_area();
-        a
+        Ok(a.into())
     }
 }
@@ -255,8 +255,8 @@ fn compute_distinct_combinations(paths: &Vec<Path>) -> Int {
     sum
 }
 
-fn get_workflows(input: &String) -> (&str, Workflows) {
-    let (rest, workflows) = separated_list1(tag("\n"), Workflow::parse)(&input).unwrap();
+fn get_workflows(input: &str) -> (&str, Workflows) {
+    let (rest, workflows) = separated_list1(tag("\n"), Workflow::parse)(input).unwrap();
     let workflows: Workflows = workflows.into_iter().map(|w| (w.name.clone(), w)).collect();
     (rest, workflows)
 }
@@ -265,17 +265,16 @@ fn get_workflows(input: &String) -> (&str, Workflows) {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         19
     }
-    fn expect_part_one(&self) -> Answer {
-        348378
-    }
-    fn expect_part_two(&self) -> Answer {
-        121158073425385
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Intervals, Tag::Parsing]
     }
 
     define_examples! {
@@ -303,11 +302,11 @@ impl Solution for Problem {
         )
     }
 
-    fn solve_part_one(&self, input: Input, example: bool) -> Answer {
-        let (rest, workflows) = get_workflows(&input);
-        debug!(example, &workflows);
+    fn solve_part_one(&self, input: &String, params: &Params) -> AocResult<Answer> {
+        let (rest, workflows) = get_workflows(input);
+        debug!(params.is_example(), "{:#?}", &workflows);
         let (_, parts) = separated_list1(tag("\n"), Part::parse)(rest.trim()).unwrap();
-        debug!(example, &parts);
+        debug!(params.is_example(), "{:#?}", &parts);
 
         let sum_total_ratings: Int = parts
             .iter()
@@ -315,15 +314,15 @@ impl Solution for Problem {
             .map(|p| p.total_rating())
             .sum();
 
-        sum_total_ratings
+        Ok(sum_total_ratings.into())
     }
 
-    fn solve_part_two(&self, input: Input, example: bool) -> Answer {
-        let workflows = get_workflows(&input).1;
+    fn solve_part_two(&self, input: &String, params: &Params) -> AocResult<Answer> {
+        let workflows = get_workflows(input).1;
         let mut paths = Vec::new();
         generate_accepted_paths(&mut paths, Path::new(), "in", &workflows);
-        debug!(example, paths);
+        debug!(params.is_example(), "{:#?}", paths);
         let sum = compute_distinct_combinations(&paths);
-        sum
+        Ok(sum.into())
     }
 }
@@ -1,11 +1,10 @@
 use crate::*;
+use aoc::signals::{Network, Node, Watch};
+use aoc::simulation::{self, Cycle};
 use nom::character::complete::space1;
 
 type Name = String;
-type Modules = HashMap<Name, Module>;
-type Memory = HashMap<Name, PulseKind>;
-type RxSenders = Vec<Name>;
-static DEBUG: bool = false;
+type Memory = HashMap<Symbol, PulseKind>;
 
 #[derive(Copy, Clone, Debug)]
 enum State {
@@ -32,11 +31,12 @@ impl State {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 enum ModuleKind {
     FlipFlop(State),
     Conjuction(Memory),
     Broadcaster,
+    #[default]
     Untyped,
 }
 
@@ -51,6 +51,36 @@ impl ModuleKind {
     }
 }
 
+impl Node for ModuleKind {
+    type Signal = PulseKind;
+
+    /// Initialize a conjunction by remembering a low pulse for each input
+    fn connect(&mut self, input: Symbol) {
+        if let ModuleKind::Conjuction(memory) = self {
+            memory.insert(input, PulseKind::Low);
+        }
+    }
+
+    fn receive(&mut self, input: Symbol, pulse: &PulseKind) -> Option<PulseKind> {
+        use ModuleKind::*;
+        match self {
+            FlipFlop(state) => (*pulse == PulseKind::Low).then(|| {
+                *state = state.flip();
+                (*state).into()
+            }),
+            Conjuction(memory) => {
+                memory.insert(input, *pulse);
+                match memory.values().all(|kind| *kind == PulseKind::High) {
+                    true => Some(PulseKind::Low),
+                    false => Some(PulseKind::High),
+                }
+            }
+            Broadcaster => Some(*pulse),
+            Untyped => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 struct Module {
     kind: ModuleKind,
@@ -90,41 +120,6 @@ impl Module {
 
         Ok((rest, module))
     }
-
-    fn generate_pulses(&self, kind: PulseKind) -> Vec<Pulse> {
-        self.destinations
-            .iter()
-            .map(|name| Pulse {
-                from: self.name.clone(),
-                to: name.clone(),
-                kind,
-            })
-            .collect()
-    }
-
-    fn handle_pulse(&mut self, pulse: Pulse, system: &mut System) {
-        use ModuleKind::*;
-        match &mut self.kind {
-            FlipFlop(state) => {
-                if pulse.kind == PulseKind::Low {
-                    *state = state.flip();
-                    let state = *state;
-                    system.enqueue_pulses(self.generate_pulses(state.into()));
-                }
-            }
-            Conjuction(memory) => {
-                memory.insert(pulse.from, pulse.kind);
-                let new_pulse = if memory.values().all(|kind| *kind == PulseKind::High) {
-                    PulseKind::Low
-                } else {
-                    PulseKind::High
-                };
-                system.enqueue_pulses(self.generate_pulses(new_pulse));
-            }
-            Broadcaster => system.enqueue_pulses(self.generate_pulses(pulse.kind)),
-            Untyped => (),
-        }
-    }
 }
 
 #[derive(Copy, Clone, PartialEq, Debug)]
@@ -133,156 +128,74 @@ enum PulseKind {
     Low,
 }
 
-#[derive(Clone)]
-struct Pulse {
-    from: Name,
-    to: Name,
-    kind: PulseKind,
-}
-
-impl Debug for Pulse {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} -{:?}-> {}", self.from, self.kind, self.to)
-    }
-}
-
-#[derive(Debug, Clone)]
-struct PulseRecord {
-    pulse: Pulse,
-    at_button_press: Int,
-}
-
-/// A System of wired up Modules that can send pulses to eachother
-#[derive(Debug)]
+/// A System of wired up Modules that can send pulses to eachother, and the button that sends a low pulse to the
+/// broadcaster
 struct System {
-    modules: Modules,
-    pulses: Queue<Pulse>,
-    pulse_history: Vec<PulseRecord>,
+    network: Network<ModuleKind>,
+    button: Symbol,
     low_pulses: Int,
     high_pulses: Int,
-    rx_senders: Vec<Name>,
-    times_pressed: Int,
 }
 
 impl Parse for System {
     fn parse(input: Input) -> Self {
-        let lines: Vec<String> = InputLines::from(input).into();
-        let modules: Modules = lines
-            .into_iter()
-            .map(Module::parse)
-            .map(|m| (m.name.clone(), m))
-            .collect();
+        let lines: Vec<&str> = InputLines::from(input).into();
+        let modules: Vec<Module> = lines.into_iter().map(Module::parse).collect();
+        let button = ("button", ModuleKind::Untyped, vec!["broadcaster"]);
+        let network = Network::new(std::iter::once(button).chain(modules.iter().map(|m| {
+            let destinations = m.destinations.iter().map(String::as_str).collect();
+            (m.name.as_str(), m.kind.clone(), destinations)
+        })));
         System {
-            modules,
-            pulses: Queue::new(),
-            pulse_history: Vec::new(),
+            button: network.get("button").unwrap(),
+            network,
             low_pulses: 0,
             high_pulses: 0,
-            rx_senders: RxSenders::new(),
-            times_pressed: 0,
         }
     }
 }
 
 impl System {
-    fn enqueue_pulse(&mut self, from: &str, to: &str, kind: PulseKind) {
-        self.pulses.push_back(Pulse {
-            from: from.to_string(),
-            to: to.to_string(),
-            kind,
-        });
-        if to == "rx" && !self.rx_senders.contains(&from.to_string()) {
-            self.rx_senders.push(from.to_string());
-        }
-        // Increment the correct pulse kind
-        match kind {
-            PulseKind::High => self.high_pulses += 1,
-            PulseKind::Low => self.low_pulses += 1,
-        }
-    }
-
-    fn enqueue_pulses(&mut self, pulses: Vec<Pulse>) {
-        pulses
-            .iter()
-            .for_each(|pulse| self.enqueue_pulse(&pulse.from, &pulse.to, pulse.kind))
-    }
-
     /// Press the button and run the System, until all pulses have been handled
     fn press_button(&mut self) {
-        self.enqueue_pulse("button", "broadcaster", PulseKind::Low);
-        self.run_until_all_pulses_handled();
+        let (low_pulses, high_pulses) = (&mut self.low_pulses, &mut self.high_pulses);
+        self.network
+            .tick_inspect(self.button, PulseKind::Low, |message| {
+                match message.signal {
+                    PulseKind::High => *high_pulses += 1,
+                    PulseKind::Low => *low_pulses += 1,
+                }
+            });
     }
 
     /// Press the button and run the System, until all pulses have been handled, `times` times after each other
     fn press_button_repeatedly(&mut self, times: Int) {
         for i in 0..times {
-            debug!(DEBUG, "Press button: {}", i);
+            debug!(true, "Press button: {}", i);
 
-            self.times_pressed += 1;
             self.press_button();
             debug!(
-                DEBUG,
+                true,
                 "(high: {}, low: {})\n", self.high_pulses, self.low_pulses
             );
         }
     }
-
-    fn run_until_all_pulses_handled(&mut self) {
-        while !self.pulses.is_empty() {
-            let pulse = self.pulses.pop_front().unwrap();
-            debug!(DEBUG, &pulse);
-
-            let mut destination = self
-                .modules
-                .get(&pulse.to)
-                .unwrap_or(&Module {
-                    kind: ModuleKind::Untyped,
-                    name: pulse.to.clone(),
-                    destinations: Vec::new(),
-                })
-                .clone();
-            destination.handle_pulse(pulse.clone(), self);
-            self.pulse_history.push(PulseRecord {
-                pulse,
-                at_button_press: self.times_pressed,
-            });
-
-            // Overwrite the old destination module with the possibly changed one
-            self.modules.insert(destination.name.clone(), destination);
-        }
-    }
-
-    /// Initialize all conjunctions by remembering a low pulse for each input
-    fn initialize_conjunctions(mut self) -> System {
-        let modules: Vec<Module> = self.modules.values().cloned().collect();
-        for module in modules {
-            for destination in &module.destinations {
-                if let Some(dest_module) = self.modules.get_mut(destination) {
-                    if let ModuleKind::Conjuction(memory) = &mut dest_module.kind {
-                        memory.insert(module.name.clone(), PulseKind::Low);
-                    }
-                }
-            }
-        }
-        self
-    }
 }
 
 #[derive(Default)]
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         20
     }
-    fn expect_part_one(&self) -> Answer {
-        886701120
-    }
-    fn expect_part_two(&self) -> Answer {
-        228134431501037
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Simulation, Tag::Graph, Tag::Math]
     }
 
     define_examples! {
@@ -308,60 +221,54 @@ impl Solution for Problem {
         )
     }
 
-    fn solve_part_one(&self, input: Input, is_example: bool) -> Answer {
-        debug!(is_example, InputLines::from(input.clone()));
-        let mut system = System::parse(input).initialize_conjunctions();
+    fn solve_part_one(&self, input: &String, params: &Params) -> AocResult<Answer> {
+        debug!(params.is_example(), "{:#?}", InputLines::from(input));
+        let mut system = System::parse(input);
         system.press_button_repeatedly(1000);
-        system.high_pulses * system.low_pulses
+        Ok((system.high_pulses * system.low_pulses).into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
-        let mut system = System::parse(input).initialize_conjunctions();
-        system.press_button_repeatedly(1000);
-        assert!(system.rx_senders.len() == 1);
-        let rx_sender = system.rx_senders.first().unwrap().clone();
-        let rx_sender_senders: Vec<_> = system
-            .modules
-            .clone()
-            .into_values()
-            .filter(|m| m.destinations.contains(&rx_sender))
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
+        let mut system = System::parse(input);
+        let rx = system.network.get("rx").unwrap();
+        assert!(system.network.inputs(rx).len() == 1);
+        let rx_sender = system.network.inputs(rx)[0];
+        let watches: Vec<Watch> = system
+            .network
+            .inputs(rx_sender)
+            .to_vec()
+            .into_iter()
+            .map(|sender| {
+                system.network.watch(move |message| {
+                    message.from == sender
+                        && message.to == rx_sender
+                        && message.signal == PulseKind::High
+                })
+            })
             .collect();
-        dbg!(&rx_sender_senders);
-        let mut high_pulses_to_rx_sender: Vec<PulseRecord> = Vec::new();
-        while !rx_sender_senders.iter().all(|m| {
-            high_pulses_to_rx_sender
-                .iter()
-                .any(|p| p.pulse.from == m.name)
-        }) {
-            system.times_pressed += 1;
+        while watches
+            .iter()
+            .any(|&watch| system.network.first(watch).is_none())
+        {
             system.press_button();
-            high_pulses_to_rx_sender.extend(
-                system
-                    .pulse_history
-                    .iter()
-                    .cloned()
-                    .filter(|p| p.pulse.to == *rx_sender && p.pulse.kind == PulseKind::High),
-            );
-            system.pulse_history.clear();
         }
 
-        let mut lowest_high_pulses_to_rx_sender: Vec<PulseRecord> = Vec::new();
-        for record in high_pulses_to_rx_sender {
-            if !lowest_high_pulses_to_rx_sender
-                .iter()
-                .any(|p| p.pulse.from == record.pulse.from)
-            {
-                lowest_high_pulses_to_rx_sender.push(record);
-            }
-        }
-
-        let mut solution = 1;
-        for record in &lowest_high_pulses_to_rx_sender {
-            solution = lcm(solution, record.at_button_press as u64);
-        }
-
-        dbg!(lowest_high_pulses_to_rx_sender);
+        // Every sender of the rx sender sends a high pulse again after a whole number of its first button presses
+        let cycles: Vec<Cycle> = watches
+            .iter()
+            .map(|&watch| {
+                let first = system.network.first(watch).unwrap();
+                Cycle {
+                    start: first,
+                    length: first,
+                }
+            })
+            .collect();
+        debug!(level: DEBUG, "Cycles of the senders of the rx sender: {:?}", cycles);
 
-        solution as Answer
+        let presses = simulation::align(&cycles).ok_or_else(AocError::no_answer(
+            "the senders of the rx sender never send high together",
+        ))?;
+        Ok(presses.into())
     }
 }
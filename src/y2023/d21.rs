@@ -1,5 +1,5 @@
-use crate::grid::*;
 use crate::*;
+use aoc::grid::*;
 
 type GardenMap = Map<Terrain>;
 type Visited = HashMap<Point, Distance>;
@@ -13,7 +13,6 @@ define_convertable_enum! {
     }
 }
 
-#[derive(Debug)]
 struct Garden {
     map: GardenMap,
     visited: Visited,
@@ -39,14 +38,13 @@ impl Parse for Garden {
     }
 }
 
-impl Debug for GardenMap {
+impl Debug for Garden {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let map = self
-            .iter()
-            .map(|v| v.iter().map(|t| Terrain::to_char(t)).collect::<String>())
-            .collect::<Vec<_>>()
-            .join("\n");
-        write!(f, "\n{}", map)
+        let map = self.map.display_with(Terrain::to_char);
+        f.debug_struct("Garden")
+            .field("map", &format_args!("\n{}", map))
+            .field("visited", &self.visited)
+            .finish()
     }
 }
 
@@ -85,17 +83,16 @@ impl Garden {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         21
     }
-    fn expect_part_one(&self) -> Answer {
-        3853
-    }
-    fn expect_part_two(&self) -> Answer {
-        639051580070841
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Grid, Tag::Graph, Tag::Math]
     }
 
     define_examples! {
@@ -114,17 +111,18 @@ impl Solution for Problem {
             ...........
             ",
             Expect::PartOne(16),
+            Params::example(&[("steps", 6)]),
         )
     }
 
-    fn solve_part_one(&self, input: Input, is_example: bool) -> Answer {
+    fn solve_part_one(&self, input: &String, params: &Params) -> AocResult<Answer> {
         let garden = Garden::parse(input).run_breadth_first_search();
-        let steps = if is_example { 6 } else { 64 };
-        debug!(is_example, garden);
-        garden.count_possible_locations(steps)
+        let steps = params.get("steps", 64);
+        debug!(params.is_example(), "{:#?}", garden);
+        Ok(garden.count_possible_locations(steps).into())
     }
 
-    fn solve_part_two(&self, input: Input, _is_example: bool) -> Answer {
+    fn solve_part_two(&self, input: &String, _params: &Params) -> AocResult<Answer> {
         let garden = Garden::parse(input).run_breadth_first_search();
         // First, using breadth-first search on the Garden we found all distances
         // Then, we use the explanation given at https://github.com/villuna/aoc23/wiki/A-Geometric-solution-to-advent-of-code-2023,-day-21 to solve (not mine)
@@ -146,6 +144,41 @@ impl Solution for Problem {
 
         let solution = (n + 1).pow(2) * odd_full + n.pow(2) * even_full - (n + 1) * odd_corners
             + n * even_corners;
-        solution as Answer
+        Ok((solution as Int).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Number of garden plots that can be reached in exactly `steps` steps on the infinitely repeated Garden
+    fn count_infinite_locations(map: &GardenMap, steps: Distance) -> Int {
+        let tiled = map.tiled().unwrap();
+        let root = *map.find(|t| *t == Terrain::Start).first().unwrap();
+        let mut visited: Visited = Visited::from([(root, 0)]);
+        let mut queue: Queue<Point> = Queue::from([root]);
+        while let Some(current) = queue.pop_front() {
+            let distance = visited[&current];
+            if distance == steps {
+                continue;
+            }
+            for node in tiled.get_adjacent(&current) {
+                if *tiled.point_get(&node) != Terrain::Rock && !visited.contains_key(&node) {
+                    visited.insert(node, distance + 1);
+                    queue.push_back(node);
+                }
+            }
+        }
+        visited.values().filter(|&&d| d % 2 == steps % 2).count() as Int
+    }
+
+    #[test]
+    fn test_infinite_garden() {
+        let input = Problem::default().define_examples()[0].get_input();
+        let garden = Garden::parse(&input);
+        test!(16, count_infinite_locations(&garden.map, 6));
+        test!(50, count_infinite_locations(&garden.map, 10));
+        test!(1594, count_infinite_locations(&garden.map, 50));
     }
 }
@@ -1,30 +1,18 @@
 use std::cmp::{max, min};
 
-use crate::line_segment::LineSegment;
 use crate::*;
+use aoc::frames::{Frame, Frames};
+use aoc::geometry::{Point3, Segment};
+use aoc::path_finding::Graph;
+use aoc::WriteGrid;
 
 type BrickID = Int;
 type Bricks = HashMap<BrickID, Brick>;
 
-#[derive(Debug)]
-struct Point {
-    x: Int,
-    y: Int,
-    z: Int,
-}
-
-impl Point {
-    fn translate(&mut self, other: &Point) {
-        self.x += other.x;
-        self.y += other.y;
-        self.z += other.z;
-    }
-}
-
 struct Brick {
     id: BrickID,
-    head: Point,
-    tail: Point,
+    head: Point3,
+    tail: Point3,
     supported_by: Vec<BrickID>,
     supports: Vec<BrickID>,
 }
@@ -57,12 +45,7 @@ impl Brick {
         input
             .lines()
             .enumerate()
-            .map(|(i, l)| {
-                (
-                    i as BrickID,
-                    Brick::parse(l.to_string()).assign_id(i as BrickID),
-                )
-            })
+            .map(|(i, l)| (i as BrickID, Brick::parse(l).assign_id(i as BrickID)))
             .collect()
     }
 
@@ -73,7 +56,7 @@ impl Brick {
                 terminated(parse_num, tag(",")),
                 parse_num,
             ))(input)?;
-            Ok((rest, Point { x, y, z }))
+            Ok((rest, Point3::new(x, y, z)))
         };
         let (rest, head) = parse_point(input)?;
         let (rest, _) = tag("~")(rest)?;
@@ -91,10 +74,13 @@ impl Brick {
     }
 
     fn intersects_in_xy_plane(&self, other: &Brick) -> bool {
-        line_segment::intersects(
-            &LineSegment::new(self.head.x, self.head.y, self.tail.x, self.tail.y),
-            &LineSegment::new(other.head.x, other.head.y, other.tail.x, other.tail.y),
-        )
+        let segment = |brick: &Brick| {
+            Segment::new(
+                grid::Point::new(brick.head.x, brick.head.y),
+                grid::Point::new(brick.tail.x, brick.tail.y),
+            )
+        };
+        segment(self).intersects(&segment(other))
     }
 
     fn assign_id(mut self, id: BrickID) -> Brick {
@@ -126,17 +112,13 @@ impl Brick {
         self.get_min('z') > 1 && self.supported_by.is_empty()
     }
 
-    fn translate(&mut self, point: Point) {
-        self.head.translate(&point);
-        self.tail.translate(&point);
+    fn translate(&mut self, point: Point3) {
+        self.head += point;
+        self.tail += point;
     }
 
     fn fall_distance(&mut self, distance: Int) {
-        self.translate(Point {
-            x: 0,
-            y: 0,
-            z: -distance,
-        });
+        self.translate(Point3::new(0, 0, -distance));
     }
 
     /// Fall until !self.is_falling()
@@ -167,22 +149,116 @@ impl Brick {
     }
 }
 
-/// Simulate the falling bricks until they have all found support
-fn let_fall(bricks: Bricks) -> Bricks {
+/// Side views of bricks along the x and y axis, like the ones in the puzzle description
+struct SideViews<'a>(Vec<&'a Brick>);
+
+impl SideViews<'_> {
+    /// Label of a brick in a side view, bricks that are behind each other are shown as '?'
+    fn label(id: BrickID) -> char {
+        (b'A' + id.rem_euclid(26) as u8) as char
+    }
+
+    /// Side view along `dimension` ('x', 'y'), with the ground at the bottom
+    fn write_side_view(&self, out: &mut String, dimension: char) -> std::fmt::Result {
+        use std::fmt::Write;
+        let width = self
+            .0
+            .iter()
+            .map(|b| b.get_max(dimension))
+            .max()
+            .unwrap_or(0)
+            + 1;
+        let height = self.0.iter().map(|b| b.get_max('z')).max().unwrap_or(0);
+        let mut view: Grid<Option<char>> = vec![vec![None; width as usize]; height as usize];
+        for brick in &self.0 {
+            for z in brick.get_min('z')..=brick.get_max('z') {
+                for i in brick.get_min(dimension)..=brick.get_max(dimension) {
+                    let cell = &mut view[(height - z) as usize][i as usize];
+                    *cell = match cell {
+                        None => Some(SideViews::label(brick.id)),
+                        Some(_) => Some('?'),
+                    };
+                }
+            }
+        }
+        writeln!(out, "{}", dimension)?;
+        view.write_with(out, |cell| cell.unwrap_or('.'))?;
+        write!(out, "\n{}", "-".repeat(width as usize))
+    }
+}
+
+impl Frame for SideViews<'_> {
+    fn write_frame(&self, out: &mut String) -> std::fmt::Result {
+        self.write_side_view(out, 'x')?;
+        out.push_str("\n\n");
+        self.write_side_view(out, 'y')
+    }
+}
+
+/// Simulate the falling bricks until they have all found support, and record a frame before the first brick falls
+/// and after every brick
+fn let_fall(bricks: Bricks, frames: &mut Frames) -> Bricks {
     let mut bricks = bricks;
     let mut falling_bricks: Vec<_> = bricks.values_mut().collect();
     falling_bricks.sort_by_key(|b| b.get_min('z'));
     let mut falling_bricks: Queue<&mut Brick> = falling_bricks.into();
     let mut supported_bricks: Queue<&mut Brick> = Queue::new();
+    if frames.is_enabled() {
+        frames.record(&SideViews(falling_bricks.iter().map(|b| &**b).collect()));
+    }
     while let Some(brick) = falling_bricks.pop_front() {
         if brick.is_falling() {
             brick.fall(&mut supported_bricks);
         }
         supported_bricks.push_front(brick);
+        if frames.is_enabled() {
+            let all_bricks = supported_bricks.iter().chain(falling_bricks.iter());
+            frames.record(&SideViews(all_bricks.map(|b| &**b).collect()));
+        }
     }
     bricks
 }
 
+/// Node of the support graph, the ground supports every brick that lies on it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Support {
+    Ground,
+    Brick { id: BrickID, z: (Int, Int) },
+}
+
+impl std::fmt::Display for Support {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Support::Ground => write!(f, "ground"),
+            Support::Brick { id, z: (min, max) } => write!(f, "{} z={}..{}", id, min, max),
+        }
+    }
+}
+
+impl Support {
+    fn brick(brick: &Brick) -> Support {
+        Support::Brick {
+            id: brick.id,
+            z: (brick.get_min('z'), brick.get_max('z')),
+        }
+    }
+}
+
+/// Graph of the settled bricks, with an edge from every brick to the bricks that it supports
+fn support_graph(bricks: &Bricks) -> Graph<Support> {
+    let mut graph = Graph::new(Support::Ground);
+    for brick in sort_bricks(bricks) {
+        let supported = Support::brick(brick);
+        if brick.supported_by.is_empty() {
+            graph.add_edge(Support::Ground, supported, 1);
+        }
+        for id in &brick.supported_by {
+            graph.add_edge(Support::brick(&bricks[id]), supported, 1);
+        }
+    }
+    graph
+}
+
 fn sort_bricks(bricks: &Bricks) -> Vec<&Brick> {
     let mut z_sorted_bricks: Vec<_> = bricks.values().collect();
     z_sorted_bricks.sort_by_key(|b| b.get_min('z'));
@@ -193,17 +269,16 @@ fn sort_bricks(bricks: &Bricks) -> Vec<&Brick> {
 pub struct Problem {}
 
 impl Solution for Problem {
+    no_parse_step!();
+
     fn year(&self) -> Year {
         2023
     }
     fn day(&self) -> Day {
         22
     }
-    fn expect_part_one(&self) -> Answer {
-        465
-    }
-    fn expect_part_two(&self) -> Answer {
-        79042
+    fn tags(&self) -> &'static [Tag] {
+        &[Tag::Simulation, Tag::Graph]
     }
 
     define_examples! {
@@ -221,13 +296,14 @@ impl Solution for Problem {
         )
     }
 
-    fn solve_part_one(&self, input: Input, is_example: bool) -> Answer {
-        let bricks = let_fall(Brick::parse_bricks(input));
-        if is_example {
+    fn solve_part_one(&self, input: &String, params: &Params) -> AocResult<Answer> {
+        let mut frames = Frames::new(params, "y2023-d22");
+        let bricks = let_fall(Brick::parse_bricks(input), &mut frames);
+        if params.is_example() {
             let z_sorted_bricks = sort_bricks(&bricks);
-            debug!(is_example, z_sorted_bricks);
+            debug!(params.is_example(), "{:#?}", z_sorted_bricks);
         }
-        bricks
+        let safe_to_disintegrate = bricks
             .values()
             .filter(|b| {
                 bricks
@@ -235,14 +311,18 @@ impl Solution for Problem {
                     .filter(|o| o.supported_by.contains(&b.id))
                     .all(|o| o.supported_by.len() > 1)
             })
-            .count() as Answer
+            .count();
+        Ok((safe_to_disintegrate as Int).into())
     }
 
-    fn solve_part_two(&self, input: Input, is_example: bool) -> Answer {
+    fn solve_part_two(&self, input: &String, params: &Params) -> AocResult<Answer> {
         // Breadth-first search solution where we travel upwards from any brick that we
         // remove, to see if those supported bricks have lost all of their support, which we sum,
-        // for each brick
-        let bricks = let_fall(Brick::parse_bricks(input));
+        // for each brick, the settling is only recorded in part one
+        let bricks = let_fall(Brick::parse_bricks(input), &mut Frames::default());
+        viz::write_dot(params, "y2023-d22", |dot| {
+            support_graph(&bricks).write_dot(dot, "y2023-d22", false)
+        });
         let z_sorted_bricks = sort_bricks(&bricks);
         let mut sum = 0;
         for brick in z_sorted_bricks {
@@ -264,7 +344,7 @@ impl Solution for Problem {
             }
             fallen_bricks.remove(0);
             debug!(
-                is_example,
+                params.is_example(),
                 "{}: {} fallen: {:?}",
                 brick.id,
                 fallen_bricks.len(),
@@ -272,6 +352,6 @@ impl Solution for Problem {
             );
             sum += fallen_bricks.len();
         }
-        sum as Answer
+        Ok((sum as Int).into())
     }
 }
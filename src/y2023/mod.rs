@@ -1,25 +1,21 @@
-pub mod d01;
-pub mod d02;
-pub mod d03;
-pub mod d04;
-pub mod d05;
-pub mod d06;
-pub mod d07;
-pub mod d08;
-pub mod d09;
-pub mod d10;
-pub mod d11;
-pub mod d12;
-pub mod d13;
-pub mod d14;
-pub mod d15;
-pub mod d16;
-pub mod d17;
-pub mod d18;
-pub mod d19;
-pub mod d20;
-pub mod d21;
-pub mod d22;
-pub mod d23;
-pub mod d24;
-pub mod d25;
+crate::days!(
+    d01, d02, d03, d04, d05, d06, d07, d08, d09, d10, d11, d12, d13, d14, d15, d16, d18, d19, d20,
+    d21, d22;
+    unsolved: d17, d23, d24, d25
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use aoc::solution::*;
+
+    #[test]
+    fn test_sources() {
+        let days: Vec<Day> = sources().iter().map(|&(day, _)| day).collect();
+        let registered: Vec<Day> = solutions().iter().map(|solution| solution.day()).collect();
+        test!(registered, days);
+        test!(sources()[13].1.contains("impl Solution for Problem"));
+        test!(unregistered().is_empty());
+    }
+}